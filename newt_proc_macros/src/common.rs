@@ -1,5 +1,5 @@
 //
-// Copyright (C) 2025 Robert Gill <rtgill82@gmail.com>
+// Copyright (C) 2025,2026 Robert Gill <rtgill82@gmail.com>
 //
 // This file is a part of newt-rs.
 //
@@ -21,56 +21,107 @@ extern crate proc_macro;
 extern crate syn;
 
 use proc_macro::TokenStream;
-use syn::{Generics,Ident};
+use syn::{Data,DeriveInput,Fields,Generics,Ident};
 
-pub fn impl_component_common(name: &Ident, generics: &Generics) -> TokenStream {
+//
+// Handles sharing a newt component with other handles hold a `destroyed`
+// flag which is set when the component is destroyed through any of them.
+//
+pub fn impl_component_common(name: &Ident, generics: &Generics,
+                             shared: bool) -> TokenStream {
     let (impl_, type_, where_) = generics.split_for_impl();
+    let destroyed = if shared {
+        quote!(|| self.destroyed.get())
+    } else {
+        quote!()
+    };
+
     let gen = quote! {
-        impl #impl_ crate::private::traits::ComponentPtr for #name #type_
+        impl #impl_ ::newt::__private::ComponentPtr for #name #type_
             #where_
         {
             fn is_null(&self) -> bool {
                 let ptr = self.co.get();
-                ptr.is_null()
+                ptr.is_null() #destroyed
             }
 
             fn ptr(&self) -> *mut ::std::os::raw::c_void {
                 let ptr = self.co.get();
-                if ptr.is_null() {
+                if ptr.is_null() #destroyed {
                     panic!("Component has already been destroyed!");
                 }
                 ptr as *mut ::std::os::raw::c_void
             }
 
-            fn co_ptr(&self) -> ::newt_sys::newtComponent {
-                self.ptr() as ::newt_sys::newtComponent
+            fn co_ptr(&self) -> ::newt::__private::newtComponent {
+                self.ptr() as ::newt::__private::newtComponent
             }
 
-            fn grid_ptr(&self) -> ::newt_sys::newtGrid {
-                self.ptr() as newt_sys::newtGrid
+            fn grid_ptr(&self) -> ::newt::__private::newtGrid {
+                self.ptr() as ::newt::__private::newtGrid
             }
         }
 
-        impl #impl_ crate::Component for #name #type_
+        impl #impl_ ::newt::Component for #name #type_
             #where_
         {
-            fn co(&self) -> ::newt_sys::newtComponent {
-                use crate::private::traits::ComponentPtr;
+            fn co(&self) -> ::newt::__private::newtComponent {
+                use ::newt::__private::ComponentPtr;
                 self.co_ptr()
             }
         }
+    };
+    gen.into()
+}
 
-        #[cfg(not(feature = "asm"))]
-        impl #impl_ crate::asm::AsComponent for #name #type_ #where_ { }
-
-        #[cfg(feature = "asm")]
-        impl #impl_ crate::asm::AsComponent for #name #type_
-            #where_
-        {
-            fn as_component(&self) -> Option<&crate::Component> {
-                Some(self)
-            }
+//
+// The `AsComponent` and `AsGrid` traits depend on whether newt was built
+// with the `asm` feature, so they're implemented by a macro exported from
+// newt rather than checking the feature here.
+//
+pub fn impl_as_traits(name: &Ident, generics: &Generics,
+                      as_grid: proc_macro2::TokenStream) -> TokenStream {
+    let (impl_, type_, where_) = generics.split_for_impl();
+    let gen = quote! {
+        ::newt::__newt_impl_as_traits! {
+            #name [#impl_] [#type_] [#where_] #as_grid
         }
     };
     gen.into()
 }
+
+//
+// Check that a struct contains all of the named fields.
+//
+pub fn has_fields(ast: &DeriveInput, names: &[&str]) -> bool {
+    let fields = match ast.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return false
+    };
+
+    if let Fields::Named(ref fields) = fields {
+        names.iter().all(|name| {
+            fields.named.iter().any(|f| f.ident.as_ref().unwrap() == name)
+        })
+    } else {
+        false
+    }
+}
+
+//
+// Remove the default type as specified for generics. Specifying the default
+// type for generics is not necessary for traits and I think including it
+// used to cause compilation errors, but it doesn't seem to be an issue
+// anymore. Leaving this function in place anyways.
+//
+pub fn generics_remove_defaults(generics: &Generics) -> Generics {
+    use syn::GenericParam::Type;
+
+    let mut generics = generics.clone();
+    for param in generics.params.iter_mut() {
+        if let Type(ref mut type_) = param {
+            type_.default = None;
+        }
+    }
+    generics
+}
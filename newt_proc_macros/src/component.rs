@@ -1,5 +1,5 @@
 //
-// Copyright (C) 2019,2025 Robert Gill <rtgill82@gmail.com>
+// Copyright (C) 2019,2025,2026 Robert Gill <rtgill82@gmail.com>
 //
 // This file is a part of newt-rs.
 //
@@ -21,29 +21,203 @@ extern crate proc_macro;
 extern crate syn;
 
 use proc_macro::TokenStream;
-use syn::{DeriveInput,Generics,Ident};
+use syn::{Data,DeriveInput,Fields,Generics,Ident,Index,Member};
 
 use crate::common::*;
 
 pub fn impl_component_macro(ast: &DeriveInput) -> TokenStream {
+    let manual = match manual_return_exit(ast) {
+        Ok(manual) => manual,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    match component_field(ast) {
+        Ok(Some(field)) => impl_component_delegate(ast, &field),
+        Ok(None) => impl_component_widget(ast, manual),
+        Err(err) => err.to_compile_error().into()
+    }
+}
+
+//
+// Check for the internal `#[newt(manual_return_exit)]` attribute marking
+// widgets that implement `ReturnExit` themselves.
+//
+fn manual_return_exit(ast: &DeriveInput) -> syn::Result<bool> {
+    let mut manual = false;
+    for attr in ast.attrs.iter().filter(|a| a.path().is_ident("newt")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("manual_return_exit") {
+                manual = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `newt` attribute"))
+            }
+        })?;
+    }
+    Ok(manual)
+}
+
+//
+// Find the field of a struct wrapping another `Component`.
+//
+// `Returns` `None` for widgets defining their own `co` and
+// `added_to_parent` fields.
+//
+fn component_field(ast: &DeriveInput) -> syn::Result<Option<Member>> {
+    const USAGE: &str = "`#[derive(Component)]` requires a struct with a \
+                         single field, a field marked `#[component]`, or \
+                         `co` and `added_to_parent` fields";
+
+    let fields = match ast.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return Err(syn::Error::new_spanned(&ast.ident, USAGE))
+    };
+
+    if has_fields(ast, &["co", "added_to_parent"]) {
+        return Ok(None);
+    }
+
+    let mut marked = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        if field.attrs.iter().any(|a| a.path().is_ident("component")) {
+            marked.push((i, field));
+        }
+    }
+
+    let (index, field) = match (marked.len(), fields.len()) {
+        (1, _) => marked[0],
+        (0, 1) => (0, fields.iter().next().unwrap()),
+        (0, _) => return Err(syn::Error::new_spanned(&ast.ident, USAGE)),
+        (_, _) => {
+            let msg = "only one field may be marked `#[component]`";
+            return Err(syn::Error::new_spanned(marked[1].1, msg));
+        }
+    };
+
+    match fields {
+        Fields::Named(_) => {
+            Ok(Some(Member::Named(field.ident.clone().unwrap())))
+        },
+        _ => Ok(Some(Member::Unnamed(Index::from(index))))
+    }
+}
+
+fn impl_component_widget(ast: &DeriveInput, manual_return_exit: bool)
+    -> TokenStream
+{
     let name = &ast.ident;
     let generics = generics_remove_defaults(&ast.generics);
-    let mut tokens = impl_component_common(&name, &generics);
+    let shared = has_fields(ast, &["destroyed"]);
+    let mut tokens = impl_component_common(&name, &generics, shared);
+    tokens.extend(impl_as_traits(&name, &generics, quote!(none)));
     tokens.extend(impl_component_base(&name, &generics));
     tokens.extend(impl_component_drop(&name, &generics));
+    if !manual_return_exit {
+        tokens.extend(impl_component_return_exit(&name, &generics));
+    }
     tokens.extend(impl_component_partial_eq_trait(&name, &generics));
     tokens.extend(impl_component_partial_eq(&name, &generics));
     tokens
 }
 
+fn impl_component_delegate(ast: &DeriveInput, field: &Member)
+    -> TokenStream
+{
+    let name = &ast.ident;
+    let generics = generics_remove_defaults(&ast.generics);
+    let (impl_, type_, where_) = generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_ ::newt::__private::ComponentPtr for #name #type_
+            #where_
+        {
+            fn is_null(&self) -> bool {
+                ::newt::__private::ComponentPtr::is_null(&self.#field)
+            }
+
+            fn ptr(&self) -> *mut ::std::os::raw::c_void {
+                ::newt::__private::ComponentPtr::ptr(&self.#field)
+            }
+
+            fn co_ptr(&self) -> ::newt::__private::newtComponent {
+                ::newt::__private::ComponentPtr::co_ptr(&self.#field)
+            }
+
+            fn grid_ptr(&self) -> ::newt::__private::newtGrid {
+                ::newt::__private::ComponentPtr::grid_ptr(&self.#field)
+            }
+        }
+
+        impl #impl_ ::newt::Component for #name #type_
+            #where_
+        {
+            fn co(&self) -> ::newt::__private::newtComponent {
+                ::newt::Component::co(&self.#field)
+            }
+        }
+
+        impl #impl_ ::newt::widgets::WidgetFns for #name #type_
+            #where_
+        { }
+
+        impl #impl_ ::newt::__private::Child for #name #type_
+            #where_
+        {
+            fn add_to_parent(&self)
+              -> Result<(), &'static str> {
+                ::newt::__private::Child::add_to_parent(&self.#field)
+            }
+
+            fn added_to_parent(&self) -> bool {
+                ::newt::__private::Child::added_to_parent(&self.#field)
+            }
+        }
+
+        impl #impl_ ::newt::__private::GridElementType for #name #type_
+            #where_
+        {
+            fn grid_element_type(&self) -> u32 {
+                ::newt::__private::GridElementType::grid_element_type(
+                    &self.#field
+                )
+            }
+        }
+
+        impl #impl_ ::newt::__private::Nullify for #name #type_
+            #where_
+        {
+            fn nullify(&self) {
+                ::newt::__private::Nullify::nullify(&self.#field)
+            }
+        }
+
+        impl #impl_ ::newt::__private::ReturnExit for #name #type_
+            #where_
+        {
+            fn return_exit(&self) -> bool {
+                ::newt::__private::ReturnExit::return_exit(&self.#field)
+            }
+        }
+    };
+
+    let mut tokens: TokenStream = gen.into();
+    tokens.extend(impl_as_traits(&name, &generics, quote!(field #field)));
+
+    // Outside of newt the comparisons with `Box<dyn Component>` and
+    // `ExitReason` would conflict with the generic implementation.
+    tokens.extend(impl_component_partial_eq_trait(&name, &generics));
+    tokens
+}
+
 fn impl_component_base(name: &Ident, generics: &Generics)
     -> TokenStream
 {
     let (impl_, type_, where_) = generics.split_for_impl();
     let gen = quote! {
-        impl #impl_ crate::widgets::WidgetFns for #name #type_ { }
+        impl #impl_ ::newt::widgets::WidgetFns for #name #type_
+            #where_
+        { }
 
-        impl #impl_ crate::private::traits::Child for #name #type_
+        impl #impl_ ::newt::__private::Child for #name #type_
             #where_
         {
             fn add_to_parent(&self)
@@ -60,31 +234,20 @@ fn impl_component_base(name: &Ident, generics: &Generics)
             }
         }
 
-        #[cfg(not(feature = "asm"))]
-        impl #impl_ crate::asm::AsGrid for #name #type_ #where_ { }
-
-        #[cfg(feature = "asm")]
-        impl #impl_ crate::asm::AsGrid for #name #type_
-            #where_
-        {
-            fn as_grid(&self) -> Option<&crate::grid::traits::Grid> {
-                None
-            }
-        }
-
-        impl #impl_ crate::private::traits::GridElementType for #name #type_
+        impl #impl_ ::newt::__private::GridElementType for #name #type_
             #where_
         {
             fn grid_element_type(&self) -> u32 {
-                use crate::constants::NEWT_GRID_COMPONENT;
-                NEWT_GRID_COMPONENT
+                ::newt::constants::GRID_COMPONENT
             }
         }
 
-        impl #impl_ crate::private::traits::Nullify for #name #type_
+        impl #impl_ ::newt::__private::Nullify for #name #type_
+            #where_
         {
             fn nullify(&self) {
-                self.co.replace(std::ptr::null_mut());
+                let co = self.co.replace(std::ptr::null_mut());
+                ::newt::__private::set_destroyed(co);
             }
         }
     };
@@ -104,10 +267,12 @@ fn impl_component_drop(name: &Ident, generics: &Generics)
             #where_
         {
             fn drop(&mut self) {
-                use crate::Component;
+                use ::newt::Component;
                 unsafe {
                     if !self.added_to_parent.get() {
-                        ::newt_sys::newtComponentDestroy(self.co());
+                        let co = self.co();
+                        ::newt::__private::newtComponentDestroy(co);
+                        ::newt::__private::set_destroyed(co);
                     }
                 }
             }
@@ -116,6 +281,18 @@ fn impl_component_drop(name: &Ident, generics: &Generics)
     gen.into()
 }
 
+fn impl_component_return_exit(name: &Ident, generics: &Generics)
+    -> TokenStream
+{
+    let (impl_, type_, where_) = generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_ ::newt::__private::ReturnExit for #name #type_
+            #where_
+        { }
+    };
+    gen.into()
+}
+
 fn impl_component_partial_eq_trait(name: &Ident, generics: &Generics)
     -> TokenStream
 {
@@ -127,8 +304,8 @@ fn impl_component_partial_eq_trait(name: &Ident, generics: &Generics)
             #where_
         {
             fn eq(&self, other: &Rhs) -> bool {
-                use crate::Component;
-                use crate::private::traits::ComponentPtr;
+                use ::newt::Component;
+                use ::newt::__private::ComponentPtr;
 
                 if self.is_null() {
                     return false
@@ -137,8 +314,7 @@ fn impl_component_partial_eq_trait(name: &Ident, generics: &Generics)
             }
         }
     };
-    let rv = gen.into();
-    return rv;
+    gen.into()
 }
 
 fn impl_component_partial_eq(name: &Ident, generics: &Generics)
@@ -146,12 +322,12 @@ fn impl_component_partial_eq(name: &Ident, generics: &Generics)
 {
     let (impl_, type_, where_) = generics.split_for_impl();
     let gen = quote! {
-        impl #impl_ std::cmp::PartialEq<Box<dyn (crate::Component)>> for #name #type_
+        impl #impl_ std::cmp::PartialEq<Box<dyn (::newt::Component)>> for #name #type_
             #where_
         {
-            fn eq(&self, other: &Box<dyn (crate::Component)>) -> bool {
-                use crate::Component;
-                use crate::private::traits::ComponentPtr;
+            fn eq(&self, other: &Box<dyn (::newt::Component)>) -> bool {
+                use ::newt::Component;
+                use ::newt::__private::ComponentPtr;
 
                 if self.is_null() {
                     return false
@@ -160,10 +336,10 @@ fn impl_component_partial_eq(name: &Ident, generics: &Generics)
             }
         }
 
-        impl #impl_ std::cmp::PartialEq<crate::form::ExitReason> for #name #type_
+        impl #impl_ std::cmp::PartialEq<::newt::form::ExitReason> for #name #type_
             #where_
         {
-            fn eq(&self, other: &crate::form::ExitReason) -> bool {
+            fn eq(&self, other: &::newt::form::ExitReason) -> bool {
                 other == self
             }
         }
@@ -174,25 +350,7 @@ fn impl_component_partial_eq(name: &Ident, generics: &Generics)
 fn generics_add_rhs(generics: &Generics) -> Generics {
     use syn::{GenericParam,parse_str};
     let mut generics = generics.clone();
-    let rhs: GenericParam = parse_str("Rhs: crate::Component").unwrap();
+    let rhs: GenericParam = parse_str("Rhs: ::newt::Component").unwrap();
     generics.params.push(rhs);
     generics
 }
-
-//
-// Remove the default type as specified for generics. Specifying the default
-// type for generics is not necessary for traits and I think including it
-// used to cause compilation errors, but it doesn't seem to be an issue
-// anymore. Leaving this function in place anyways.
-//
-fn generics_remove_defaults(generics: &Generics) -> Generics {
-    use syn::GenericParam::Type;
-
-    let mut generics = generics.clone();
-    for param in generics.params.iter_mut() {
-        if let Type(ref mut type_) = param {
-            type_.default = None;
-        }
-    }
-    generics
-}
@@ -0,0 +1,134 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate proc_macro;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use syn::{Data,DataEnum,DeriveInput,Fields,Ident};
+
+use crate::common::*;
+
+pub fn impl_data_macro(ast: &DeriveInput) -> TokenStream {
+    const USAGE: &str = "`#[derive(Data)]` requires an enum without fields \
+                         or a struct with a single field";
+
+    let result = match ast.data {
+        Data::Enum(ref data) => impl_data_enum(ast, data),
+        Data::Struct(ref data) if data.fields.len() == 1 => {
+            impl_data_newtype(ast, &data.fields)
+        },
+        _ => Err(syn::Error::new_spanned(&ast.ident, USAGE))
+    };
+
+    match result {
+        Ok(tokens) => tokens,
+        Err(err) => err.to_compile_error().into()
+    }
+}
+
+//
+// Variants are stored as their index plus one so that no variant is
+// converted to a null pointer, which newt treats as no value.
+//
+fn impl_data_enum(ast: &DeriveInput, data: &DataEnum)
+    -> syn::Result<TokenStream>
+{
+    let name = &ast.ident;
+    if data.variants.is_empty() {
+        let msg = "`#[derive(Data)]` requires an enum with variants";
+        return Err(syn::Error::new_spanned(name, msg));
+    }
+
+    let mut variants: Vec<&Ident> = Vec::new();
+    for variant in data.variants.iter() {
+        if !variant.fields.is_empty() {
+            let msg = "`#[derive(Data)]` requires enum variants without \
+                       fields";
+            return Err(syn::Error::new_spanned(variant, msg));
+        }
+        variants.push(&variant.ident);
+    }
+
+    let values: Vec<usize> = (1..=variants.len()).collect();
+    let generics = generics_remove_defaults(&ast.generics);
+    let (impl_, type_, where_) = generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_ ::newt::data::Data for #name #type_
+            #where_
+        {
+            fn newt_to_ptr(&self) -> *const ::std::os::raw::c_void {
+                let value: usize = match self {
+                    #(#name::#variants => #values),*
+                };
+                value as *const ::std::os::raw::c_void
+            }
+
+            unsafe fn newt_from_ptr(ptr: *const ::std::os::raw::c_void)
+                -> Self
+            {
+                match ptr as usize {
+                    #(#values => #name::#variants,)*
+                    _ => panic!("Invalid {} value.", stringify!(#name))
+                }
+            }
+        }
+    };
+    Ok(gen.into())
+}
+
+fn impl_data_newtype(ast: &DeriveInput, fields: &Fields)
+    -> syn::Result<TokenStream>
+{
+    let name = &ast.ident;
+    let field = fields.iter().next().unwrap();
+    let ty = &field.ty;
+    let (to_ptr, from_ptr) = match field.ident {
+        Some(ref ident) => (
+            quote!(self.#ident),
+            quote!(#name { #ident: unsafe { <#ty as ::newt::data::Data>
+                                                ::newt_from_ptr(ptr) } })
+        ),
+        None => (
+            quote!(self.0),
+            quote!(#name(unsafe {
+                <#ty as ::newt::data::Data>::newt_from_ptr(ptr)
+            }))
+        )
+    };
+
+    let generics = generics_remove_defaults(&ast.generics);
+    let (impl_, type_, where_) = generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_ ::newt::data::Data for #name #type_
+            #where_
+        {
+            fn newt_to_ptr(&self) -> *const ::std::os::raw::c_void {
+                ::newt::data::Data::newt_to_ptr(&#to_ptr)
+            }
+
+            unsafe fn newt_from_ptr(ptr: *const ::std::os::raw::c_void)
+                -> Self
+            {
+                #from_ptr
+            }
+        }
+    };
+    Ok(gen.into())
+}
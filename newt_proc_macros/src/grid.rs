@@ -1,5 +1,5 @@
 //
-// Copyright (C) 2019,2025 Robert Gill <rtgill82@gmail.com>
+// Copyright (C) 2019,2025,2026 Robert Gill <rtgill82@gmail.com>
 //
 // This file is a part of newt-rs.
 //
@@ -26,9 +26,18 @@ use syn::{DeriveInput,Generics,Ident};
 use crate::common::*;
 
 pub fn impl_grid_macro(ast: &DeriveInput) -> TokenStream {
+    if !has_fields(ast, &["co", "added_to_parent", "children"]) {
+        let msg = "`#[derive(Grid)]` requires a struct with `co`, \
+                   `added_to_parent`, and `children` fields";
+        return syn::Error::new_spanned(&ast.ident, msg)
+            .to_compile_error()
+            .into();
+    }
+
     let name = &ast.ident;
-    let generics = &ast.generics;
-    let mut tokens = impl_component_common(&name, &generics);
+    let generics = generics_remove_defaults(&ast.generics);
+    let mut tokens = impl_component_common(&name, &generics, false);
+    tokens.extend(impl_as_traits(&name, &generics, quote!(grid)));
     tokens.extend(impl_grid_base(&name, &generics));
     tokens.extend(impl_component_base(&name, &generics));
     tokens.extend(impl_grid_child(&name, &generics));
@@ -42,11 +51,11 @@ fn impl_grid_base(name: &Ident, generics: &Generics)
 {
     let (impl_, type_, where_) = generics.split_for_impl();
     let gen = quote! {
-        impl #impl_ crate::grid::traits::Grid for #name #type_
+        impl #impl_ ::newt::grid::traits::Grid for #name #type_
             #where_
         { }
 
-        impl #impl_ crate::grid::traits::GridFns for #name #type_
+        impl #impl_ ::newt::grid::traits::GridFns for #name #type_
             #where_
         { }
     };
@@ -58,20 +67,11 @@ fn impl_component_base(name: &Ident, generics: &Generics)
 {
     let (impl_, type_, where_) = generics.split_for_impl();
     let gen = quote! {
-        impl #impl_ crate::asm::AsGrid for #name #type_
-            #where_
-        {
-            fn as_grid(&self) -> Option<&crate::grid::traits::Grid> {
-                Some(self)
-            }
-        }
-
-        impl #impl_ crate::private::traits::GridElementType for #name #type_
+        impl #impl_ ::newt::__private::GridElementType for #name #type_
             #where_
         {
             fn grid_element_type(&self) -> u32 {
-                use crate::constants::NEWT_GRID_SUBGRID;
-                NEWT_GRID_SUBGRID
+                ::newt::constants::GRID_SUBGRID
             }
         }
     };
@@ -83,7 +83,7 @@ fn impl_grid_child(name: &Ident, generics: &Generics)
 {
     let (impl_, type_, where_) = generics.split_for_impl();
     let gen = quote! {
-        impl #impl_ crate::private::traits::Child for #name #type_
+        impl #impl_ ::newt::__private::Child for #name #type_
             #where_
         {
             fn add_to_parent(&self)
@@ -116,15 +116,13 @@ fn impl_grid_parent(name: &Ident, generics: &Generics)
 
     let (impl_, type_, where_) = generics.split_for_impl();
     let gen = quote! {
-        impl #impl_ crate::grid::Parent for #name #type_
+        impl #impl_ ::newt::grid::Parent for #name #type_
             #where_
         {
-            fn children(&self) -> Vec<&crate::Component> {
-                use crate::grid::Parent;
-                use crate::constants::NEWT_GRID_COMPONENT;
-                use crate::private::traits::GridElementType;
+            fn children(&self) -> Vec<&dyn (::newt::Component)> {
+                use ::newt::grid::Parent;
 
-                let mut vec: Vec<&crate::Component> = Vec::new();
+                let mut vec: Vec<&dyn (::newt::Component)> = Vec::new();
                 for child in self.children.iter() {
                     if let Some(grid) = child.as_grid() {
                         for child in grid.children().iter() {
@@ -150,8 +148,12 @@ fn impl_grid_drop(name: &Ident, generics: &Generics)
             #where_
         {
             fn drop(&mut self) {
-                use crate::private::traits::ComponentPtr;
-                unsafe { ::newt_sys::newtGridFree(self.grid_ptr(), 0); }
+                use ::newt::__private::ComponentPtr;
+                unsafe {
+                    ::newt::__private::newtGridFree(
+                        self.grid_ptr(), 0
+                    );
+                }
             }
         }
     };
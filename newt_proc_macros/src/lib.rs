@@ -1,5 +1,5 @@
 //
-// Copyright (C) 2019 Robert Gill <rtgill82@gmail.com>
+// Copyright (C) 2019,2026 Robert Gill <rtgill82@gmail.com>
 //
 // This file is a part of newt-rs.
 //
@@ -26,18 +26,65 @@ extern crate quote;
 
 mod common;
 mod component;
+mod data;
 mod grid;
 
 use proc_macro::TokenStream;
+use syn::{parse_macro_input,DeriveInput};
 
-#[proc_macro_derive(Component)]
+///
+/// Derive the `newt::Component` trait.
+///
+/// Structs wrapping a single existing `Component` delegate to it. The
+/// wrapped `Component` is either the only field of the struct or the
+/// field marked with `#[component]`.
+///
+/// ```rust ignore
+/// #[derive(Component)]
+/// struct Contacts(Listbox<Data<'static, Contact>>);
+///
+/// #[derive(Component)]
+/// struct Name {
+///     #[component]
+///     entry: Entry,
+///     validated: bool
+/// }
+/// ```
+///
+#[proc_macro_derive(Component, attributes(component, newt))]
 pub fn component_derive(input: TokenStream) -> TokenStream {
-    let ast = syn::parse(input).unwrap();
+    let ast = parse_macro_input!(input as DeriveInput);
     component::impl_component_macro(&ast)
 }
 
+///
+/// Derive the `newt::data::Data` trait.
+///
+/// Enums without fields and structs with a single field implementing
+/// `Data` are supported.
+///
+/// ```rust ignore
+/// #[derive(Clone, Copy, Data)]
+/// enum Action { Install, Remove }
+///
+/// #[derive(Data)]
+/// struct PackageId(u32);
+/// ```
+///
+#[proc_macro_derive(Data)]
+pub fn data_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    data::impl_data_macro(&ast)
+}
+
+///
+/// Derive the `newt::grid::traits::Grid` trait.
+///
+/// Requires the fields `co: Cell<newtGrid>`, `added_to_parent: Cell<bool>`,
+/// and `children` containing the `Grid`'s `Component`s.
+///
 #[proc_macro_derive(Grid)]
 pub fn grid_derive(input: TokenStream) -> TokenStream {
-    let ast = syn::parse(input).unwrap();
+    let ast = parse_macro_input!(input as DeriveInput);
     grid::impl_grid_macro(&ast)
 }
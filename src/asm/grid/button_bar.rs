@@ -17,9 +17,11 @@
 // Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
 //
 
-use std::cell::Cell;
+use std::cell::{Cell,RefCell};
 use newt_sys::*;
 use crate::component::Component;
+use crate::constants::{KEY_ENTER,KEY_ESCAPE};
+use crate::form::{ExitReason,Form};
 use crate::grid::Parent;
 use crate::widgets::Button;
 
@@ -29,11 +31,56 @@ use crate::private::traits::ComponentClone;
 ///
 /// Creates a row of buttons.
 ///
+/// Each button is associated with a value of type `T` which is returned
+/// by [`ButtonBar::value()`] or [`ButtonBar::run()`] when the button is
+/// activated. Buttons created with [`ButtonBar::new()`] are associated
+/// with their index number.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::grid::*;
+/// use newt::prelude::*;
+///
+/// #[derive(Clone,Debug)]
+/// enum Answer { Yes, No }
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///
+///     let text = Label::new(0, 0, "Continue?");
+///     let buttons = ButtonBar::with_values(&[("Yes", Answer::Yes),
+///                                            ("No", Answer::No)]);
+///     // `F2` activates "Yes", `Enter` the default, and `Escape` cancels.
+///     buttons.set_hot_key(0, KEY_F2);
+///     buttons.set_default(0);
+///     buttons.set_cancel(1);
+///
+///     let mut grid = Grid::new(1, 2);
+///     grid.set_field(0, 0, &text, 1, 1, 1, 1, 0, 0);
+///     grid.set_field(0, 1, &buttons, 1, 1, 1, 1, 0, 0);
+///     wrapped_window(&grid, "Question");
+///
+///     let mut form = Form::new(None, 0);
+///     grid.add_to_form(&mut form).unwrap();
+///     buttons.add_hot_keys(&form);
+///     let answer = buttons.run(&form).unwrap().cloned();
+///     newt::finished();
+///
+///     println!("Answer: {:?}", answer);
+/// }
+/// ```
+///
 #[derive(Grid)]
-pub struct ButtonBar {
+pub struct ButtonBar<T = usize> {
     co: Cell<newtGrid>,
     added_to_parent: Cell<bool>,
-    children: Vec<Button>
+    children: Vec<Button>,
+    values: Vec<T>,
+    hot_keys: RefCell<Vec<(i32, usize)>>,
+    default: Cell<Option<usize>>,
+    cancel: Cell<Option<usize>>
 }
 
 impl ButtonBar {
@@ -41,29 +88,52 @@ impl ButtonBar {
     /// Create a new grid containing a row of buttons. The buttons will
     /// be labeled with the strings provided in `buttons`.
     ///
+    /// Each button is associated with its index number in `buttons`.
+    ///
     /// * `buttons` - A list of strings to use as button labels.
     ///
     pub fn new(buttons: &[&str]) -> ButtonBar {
-        let grid: newtGrid;
+        let values = (0..buttons.len()).collect();
+        unsafe { ButtonBar::alloc(buttons, values) }
+    }
+}
 
+impl<T> ButtonBar<T> {
+    ///
+    /// Create a new grid containing a row of buttons from a list of
+    /// (`label`, `value`) pairs.
+    ///
+    /// * `buttons` - A list of button labels and their associated values.
+    ///
+    pub fn with_values(buttons: &[(&str, T)]) -> ButtonBar<T>
+        where T: Clone
+    {
+        let labels: Vec<&str> = buttons.iter().map(|b| b.0).collect();
+        let values = buttons.iter().map(|b| b.1.clone()).collect();
+        unsafe { ButtonBar::alloc(&labels, values) }
+    }
+
+    unsafe fn alloc(buttons: &[&str], values: Vec<T>) -> ButtonBar<T> {
         let len = buttons.len();
         let mut buttons_buf: Vec<newtComponent> = Vec::with_capacity(len);
 
-        unsafe {
-            let buttons_ptr: *mut newtComponent = buttons_buf.as_mut_ptr();
-            grid = asm::button_bar_new(buttons, buttons_ptr);
-            buttons_buf.set_len(len);
-
-            let mut buttons = Vec::new();
-            for co in buttons_buf {
-                buttons.push(Button::clone_co(co, false));
-            }
-
-            ButtonBar {
-                co: Cell::new(grid),
-                added_to_parent: Cell::new(false),
-                children: buttons
-            }
+        let buttons_ptr: *mut newtComponent = buttons_buf.as_mut_ptr();
+        let grid = asm::button_bar_new(buttons, buttons_ptr);
+        buttons_buf.set_len(len);
+
+        let mut buttons = Vec::new();
+        for co in buttons_buf {
+            buttons.push(Button::clone_co(co, false));
+        }
+
+        ButtonBar {
+            co: Cell::new(grid),
+            added_to_parent: Cell::new(false),
+            children: buttons,
+            values,
+            hot_keys: RefCell::new(Vec::new()),
+            default: Cell::new(None),
+            cancel: Cell::new(None)
         }
     }
 
@@ -71,11 +141,133 @@ impl ButtonBar {
     /// `Returns` the array of buttons contained by the grid.
     ///
     pub fn buttons(&self) -> &[Button] {
-        return self.children.as_slice();
+        self.children.as_slice()
+    }
+
+    ///
+    /// `Returns` the array of values associated with the buttons.
+    ///
+    pub fn values(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    ///
+    /// Set a hot key activating a button.
+    ///
+    /// The hot key is registered with a `Form` by
+    /// [`ButtonBar::add_hot_keys()`].
+    ///
+    /// * `index` - The index number of the button.
+    /// * `key` - The [key][keys] activating the button (i.e. `KEY_F2`).
+    ///
+    /// [keys]: crate::constants::keys
+    ///
+    pub fn set_hot_key(&self, index: usize, key: i32) {
+        self.check_index(index);
+        let mut hot_keys = self.hot_keys.borrow_mut();
+        hot_keys.retain(|&(k, _)| k != key);
+        hot_keys.push((key, index));
+    }
+
+    ///
+    /// Set the default button, activated when `Enter` is pressed.
+    ///
+    /// * `index` - The index number of the button.
+    ///
+    pub fn set_default(&self, index: usize) {
+        self.check_index(index);
+        self.default.set(Some(index));
+    }
+
+    ///
+    /// Set the cancel button, activated when `Escape` is pressed.
+    ///
+    /// * `index` - The index number of the button.
+    ///
+    pub fn set_cancel(&self, index: usize) {
+        self.check_index(index);
+        self.cancel.set(Some(index));
+    }
+
+    ///
+    /// Register the `ButtonBar`'s hot keys with a `Form`.
+    ///
+    /// This includes any keys set with [`ButtonBar::set_hot_key()`]. The
+    /// default and cancel buttons are set with
+    /// [`Form::set_default_button()`] and [`Form::set_cancel_button()`].
+    ///
+    /// * `form` - The `Form` to register the hot keys with.
+    ///
+    pub fn add_hot_keys(&self, form: &Form) {
+        for &(key, _) in self.hot_keys.borrow().iter() {
+            form.add_hot_key(key);
+        }
+
+        if let Some(i) = self.default.get() {
+            form.set_default_button(&self.children[i]);
+        }
+
+        if let Some(i) = self.cancel.get() {
+            form.set_cancel_button(&self.children[i]);
+        }
+    }
+
+    ///
+    /// Get the value associated with the button that caused a `Form`
+    /// to exit.
+    ///
+    /// * `reason` - The `ExitReason` returned by [`Form::run()`].
+    ///
+    /// `Returns` the value of the activated button or `None` if the
+    /// `Form` exited for another reason.
+    ///
+    pub fn value(&self, reason: &ExitReason) -> Option<&T> {
+        let index = match reason {
+            ExitReason::Component(co) => {
+                self.children.iter().position(|b| b.co() == co.co())
+            },
+            ExitReason::HotKey(key) => self.hot_key_index(*key),
+            _ => None
+        };
+        index.map(|i| &self.values[i])
+    }
+
+    ///
+    /// Run a `Form` and return the value associated with the button
+    /// activated to close it.
+    ///
+    /// * `form` - The `Form` to run.
+    ///
+    /// `Returns` the value of the activated button or `None` if the
+    /// `Form` exited for another reason.
+    ///
+    pub fn run(&self, form: &Form) -> Result<Option<&T>, ()> {
+        let reason = form.run()?;
+        Ok(self.value(&reason))
+    }
+
+    fn hot_key_index(&self, key: i32) -> Option<usize> {
+        if key == KEY_ENTER && self.default.get().is_some() {
+            return self.default.get();
+        }
+
+        if key == KEY_ESCAPE && self.cancel.get().is_some() {
+            return self.cancel.get();
+        }
+
+        self.hot_keys.borrow().iter()
+            .find(|&&(k, _)| k == key)
+            .map(|&(_, i)| i)
+    }
+
+    fn check_index(&self, index: usize) {
+        if index >= self.children.len() {
+            panic!("Invalid button index {}", index);
+        }
     }
 }
 
-impl Parent for ButtonBar {
+impl<T> Parent for ButtonBar<T> {
     fn children(&self) -> Vec<&dyn Component> {
         let mut vec: Vec<&dyn Component> = Vec::new();
         for child in self.children.iter() {
@@ -76,3 +76,49 @@ pub trait AsGrid {
 
 #[cfg(not(feature = "asm"))]
 pub trait AsGrid { }
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "asm")]
+macro_rules! __newt_impl_as_traits {
+    ($name:ident [$($impl_:tt)*] [$($type_:tt)*] [$($where_:tt)*]
+     $($as_grid:tt)+) => {
+        impl $($impl_)* $crate::asm::AsComponent for $name $($type_)*
+            $($where_)*
+        {
+            fn as_component(&self) -> Option<&dyn $crate::Component> {
+                Some(self)
+            }
+        }
+
+        impl $($impl_)* $crate::asm::AsGrid for $name $($type_)*
+            $($where_)*
+        {
+            fn as_grid(&self) -> Option<&dyn $crate::grid::traits::Grid> {
+                $crate::__newt_impl_as_traits!(@as_grid self $($as_grid)+)
+            }
+        }
+    };
+
+    (@as_grid $self_:ident none) => { None };
+    (@as_grid $self_:ident grid) => { Some($self_) };
+    (@as_grid $self_:ident field $field:tt) => {
+        $crate::asm::AsGrid::as_grid(&$self_.$field)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "asm"))]
+macro_rules! __newt_impl_as_traits {
+    ($name:ident [$($impl_:tt)*] [$($type_:tt)*] [$($where_:tt)*]
+     $($as_grid:tt)+) => {
+        impl $($impl_)* $crate::asm::AsComponent for $name $($type_)*
+            $($where_)*
+        { }
+
+        impl $($impl_)* $crate::asm::AsGrid for $name $($type_)*
+            $($where_)*
+        { }
+    };
+}
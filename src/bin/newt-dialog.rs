@@ -0,0 +1,782 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+//!
+//! A `whiptail` compatible dialog program built with newt-rs.
+//!
+//! Results are written to standard error, or the file descriptor given
+//! with `--output-fd`. The exit status is `0` when `Ok` or `Yes` is
+//! chosen, `1` when `Cancel` or `No` is chosen, and `255` when `Escape`
+//! is pressed or the arguments are invalid.
+//!
+extern crate libc;
+extern crate newt;
+
+use std::env;
+use std::fs::{self,File};
+use std::io::{self,Write};
+use std::os::unix::io::{FromRawFd,RawFd};
+use std::process;
+
+use newt::dialogs::{Gauge,Pager};
+use newt::prelude::*;
+
+const DLG_OKAY: i32 = 0;
+const DLG_CANCEL: i32 = 1;
+const DLG_ESCAPE: i32 = 255;
+const DLG_ERROR: i32 = 255;
+
+const USAGE: &str = "\
+Box options:
+	--msgbox <text> <height> <width>
+	--yesno  <text> <height> <width>
+	--inputbox <text> <height> <width> [init]
+	--passwordbox <text> <height> <width> [init]
+	--textbox <file> <height> <width>
+	--menu <text> <height> <width> <listheight> [tag item] ...
+	--checklist <text> <height> <width> <listheight> [tag item status]...
+	--radiolist <text> <height> <width> <listheight> [tag item status]...
+	--gauge <text> <height> <width> <percent>
+Options: (depend on box-option)
+	--clear				clear screen on exit
+	--defaultno			default no button
+	--default-item <string>		set default string
+	--fb, --fullbuttons		use full buttons
+	--nocancel			no cancel button
+	--yes-button <text>		set text of yes button
+	--no-button <text>		set text of no button
+	--ok-button <text>		set text of ok button
+	--cancel-button <text>		set text of cancel button
+	--noitem			don't display items
+	--notags			don't display tags
+	--separate-output		output one line at a time
+	--output-fd <fd>		output to fd, not stdout
+	--title <title>			display title
+	--backtitle <backtitle>		display backtitle
+	--scrolltext			force vertical scrollbars
+	--topleft			put window in top-left corner
+	-h, --help			print this message
+	-v, --version			print version information
+";
+
+#[derive(Clone,Copy,Debug,PartialEq)]
+enum Mode {
+    MsgBox,
+    YesNo,
+    InputBox,
+    PasswordBox,
+    TextBox,
+    Menu,
+    CheckList,
+    RadioList,
+    Gauge
+}
+
+// Reasons to stop before displaying a dialog.
+enum Exit {
+    Help,
+    Version,
+    Usage,
+    BadOption(String)
+}
+
+// The result of a dialog.
+enum Answer {
+    Okay(Option<String>),
+    Cancel,
+    Escape,
+    Failed(io::Error)
+}
+
+struct Options {
+    mode: Mode,
+    title: Option<String>,
+    backtitle: Option<String>,
+    yes_button: String,
+    no_button: String,
+    ok_button: String,
+    cancel_button: String,
+    default_item: Option<String>,
+    default_no: bool,
+    no_cancel: bool,
+    no_item: bool,
+    no_tags: bool,
+    separate_output: bool,
+    scroll_text: bool,
+    full_buttons: bool,
+    top_left: bool,
+    clear: bool,
+    output_fd: RawFd,
+    text: String,
+    height: i32,
+    width: i32,
+    list_height: i32,
+    args: Vec<String>
+}
+
+impl Options {
+    fn parse<I: Iterator<Item = String>>(mut argv: I) -> Result<Options, Exit>
+    {
+        let mut mode = None;
+        let mut opts = Options {
+            mode: Mode::MsgBox,
+            title: None,
+            backtitle: None,
+            yes_button: "Yes".to_string(),
+            no_button: "No".to_string(),
+            ok_button: "Ok".to_string(),
+            cancel_button: "Cancel".to_string(),
+            default_item: None,
+            default_no: false,
+            no_cancel: false,
+            no_item: false,
+            no_tags: false,
+            separate_output: false,
+            scroll_text: false,
+            full_buttons: false,
+            top_left: false,
+            clear: false,
+            output_fd: 2,
+            text: String::new(),
+            height: 0,
+            width: 0,
+            list_height: 0,
+            args: Vec::new()
+        };
+
+        let mut positional = Vec::new();
+        let mut help = false;
+        let mut version = false;
+        while let Some(arg) = argv.next() {
+            if arg == "--" {
+                positional.extend(&mut argv);
+                break;
+            }
+            if !arg.starts_with('-') || arg == "-" {
+                positional.push(arg);
+                continue;
+            }
+
+            let (name, mut value) = match arg.find('=') {
+                Some(i) if arg.starts_with("--") =>
+                    (arg[..i].to_string(), Some(arg[i + 1..].to_string())),
+                _ => (arg.clone(), None)
+            };
+            let mut string = || {
+                value.take().or_else(|| argv.next())
+                    .ok_or_else(|| Exit::BadOption(arg.clone()))
+            };
+
+            let selected = match name.as_str() {
+                "--msgbox" => Some(Mode::MsgBox),
+                "--yesno" => Some(Mode::YesNo),
+                "--inputbox" => Some(Mode::InputBox),
+                "--passwordbox" => Some(Mode::PasswordBox),
+                "--textbox" => Some(Mode::TextBox),
+                "--menu" => Some(Mode::Menu),
+                "--checklist" => Some(Mode::CheckList),
+                "--radiolist" => Some(Mode::RadioList),
+                "--gauge" => Some(Mode::Gauge),
+                "--title" => { opts.title = Some(string()?); None },
+                "--backtitle" => { opts.backtitle = Some(string()?); None },
+                "--yes-button" => { opts.yes_button = string()?; None },
+                "--no-button" => { opts.no_button = string()?; None },
+                "--ok-button" => { opts.ok_button = string()?; None },
+                "--cancel-button" => { opts.cancel_button = string()?; None },
+                "--default-item" => {
+                    opts.default_item = Some(string()?);
+                    None
+                },
+                "--output-fd" => {
+                    let fd = string()?;
+                    opts.output_fd = fd.parse()
+                        .map_err(|_| Exit::BadOption(fd.clone()))?;
+                    None
+                },
+                "--defaultno" => { opts.default_no = true; None },
+                "--nocancel" => { opts.no_cancel = true; None },
+                "--noitem" => { opts.no_item = true; None },
+                "--notags" => { opts.no_tags = true; None },
+                "--separate-output" => { opts.separate_output = true; None },
+                "--scrolltext" => { opts.scroll_text = true; None },
+                "--fb" | "--fullbuttons" => {
+                    opts.full_buttons = true;
+                    None
+                },
+                "--topleft" => { opts.top_left = true; None },
+                "--clear" => { opts.clear = true; None },
+                "-h" | "--help" => { help = true; None },
+                "-v" | "--version" => { version = true; None },
+                _ => return Err(Exit::BadOption(arg.clone()))
+            };
+
+            if let Some(selected) = selected {
+                if mode.is_some() {
+                    return Err(Exit::Usage);
+                }
+                mode = Some(selected);
+            }
+        }
+
+        if help {
+            return Err(Exit::Help);
+        }
+        if version {
+            return Err(Exit::Version);
+        }
+
+        opts.mode = mode.ok_or(Exit::Usage)?;
+        let mut positional = positional.into_iter();
+        opts.text = positional.next().ok_or(Exit::Usage)?;
+        opts.height = number(positional.next())?;
+        opts.width = number(positional.next())?;
+        match opts.mode {
+            Mode::Menu | Mode::CheckList | Mode::RadioList => {
+                opts.list_height = number(positional.next())?;
+            },
+            _ => ()
+        }
+        opts.args = positional.collect();
+        Ok(opts)
+    }
+
+    fn button_height(&self) -> i32 {
+        if self.full_buttons { 3 } else { 1 }
+    }
+
+    fn button(&self, left: i32, top: i32, text: &str) -> Box<dyn Component> {
+        if self.full_buttons {
+            Box::new(Button::new(left, top, text))
+        } else {
+            Box::new(CompactButton::new(left, top, text))
+        }
+    }
+
+    // Create the `Ok` and `Cancel` buttons at the bottom of the window.
+    fn buttons(&self, width: i32, height: i32)
+          -> (Box<dyn Component>, Option<Box<dyn Component>>) {
+        let top = height - self.button_height() - 1;
+        if self.no_cancel {
+            return (self.button((width - 8) / 2, top, &self.ok_button), None);
+        }
+
+        let ok = self.button((width - 18) / 3, top, &self.ok_button);
+        let cancel = self.button((width - 18) / 3 * 2 + 9, top,
+                                 &self.cancel_button);
+        (ok, Some(cancel))
+    }
+
+    fn text_flags(&self) -> i32 {
+        TEXTBOX_WRAP | if self.scroll_text { TEXTBOX_SCROLL } else { 0 }
+    }
+}
+
+fn number(arg: Option<String>) -> Result<i32, Exit> {
+    arg.ok_or(Exit::Usage)?.parse().map_err(|_| Exit::Usage)
+}
+
+// Replace `\n` escapes in `text` with newlines.
+fn clean_newlines(text: &str) -> String {
+    text.replace("\\n", "\n")
+}
+
+fn width_of(text: &str) -> i32 {
+    text.chars().count() as i32
+}
+
+// Create a `Textbox` no taller than `max_height` containing `text`.
+// `Returns` the `Textbox` and its height.
+fn text_box(opts: &Options, max_height: i32, width: i32)
+      -> (Textbox, i32) {
+    let max_height = max_height.max(1);
+    let textbox = Textbox::new(1, 0, width, max_height, opts.text_flags());
+    textbox.set_text(&opts.text);
+
+    let lines = textbox.get_num_lines();
+    if lines < max_height {
+        textbox.set_height(lines);
+        return (textbox, lines);
+    }
+    (textbox, max_height)
+}
+
+// `Returns` `true` if the exit reason is the cancel button.
+fn is_cancel(reason: &ExitReason, cancel: &Option<Box<dyn Component>>)
+      -> bool {
+    match cancel {
+        Some(cancel) => *reason == &**cancel,
+        None => false
+    }
+}
+
+fn message_box(opts: &Options, width: i32, height: i32) -> Answer {
+    let bh = opts.button_height();
+    let textbox = Textbox::new(1, 1, width - 2, height - 3 - bh,
+                               opts.text_flags());
+    textbox.set_text(&opts.text);
+
+    let top = height - 1 - bh;
+    let (yes, no) = if opts.mode == Mode::YesNo {
+        (opts.button((width - 16) / 3, top, &opts.yes_button),
+         Some(opts.button((width - 16) / 3 * 2 + 9, top, &opts.no_button)))
+    } else {
+        (opts.button((width - 8) / 2, top, &opts.ok_button), None)
+    };
+
+    let mut form = Form::new(None, 0);
+    form.add_components(&[&textbox, &*yes]).unwrap();
+    if let Some(no) = &no {
+        form.add_component(&**no).unwrap();
+        if opts.default_no {
+            form.set_current(&**no);
+        }
+    }
+    form.add_hot_key(KEY_ESCAPE);
+
+    match form.run() {
+        Ok(ExitReason::HotKey(KEY_ESCAPE)) | Err(_) => Answer::Escape,
+        _ => {
+            let current = form.get_current().map(|c| c.co());
+            match &no {
+                Some(no) if current == Some(no.co()) => Answer::Cancel,
+                _ => Answer::Okay(None)
+            }
+        }
+    }
+}
+
+fn input_box(opts: &Options, width: i32, height: i32) -> Answer {
+    let bh = opts.button_height();
+    let (textbox, top) = text_box(opts, height - 3 - bh, width - 2);
+
+    let mut flags = ENTRY_SCROLL | ENTRY_RETURNEXIT;
+    if opts.mode == Mode::PasswordBox {
+        flags |= FLAG_PASSWORD;
+    }
+    let init = opts.args.first().map(|s| s.as_str());
+    let entry = Entry::new(1, top + 1, init, width - 2, flags);
+    let (ok, cancel) = opts.buttons(width, height);
+
+    let mut form = Form::new(None, 0);
+    form.add_components(&[&textbox, &entry, &*ok]).unwrap();
+    if let Some(cancel) = &cancel {
+        form.add_component(&**cancel).unwrap();
+    }
+    form.add_hot_key(KEY_ESCAPE);
+
+    match form.run() {
+        Ok(ExitReason::HotKey(KEY_ESCAPE)) | Err(_) => Answer::Escape,
+        Ok(ref reason) if is_cancel(reason, &cancel) => Answer::Cancel,
+        Ok(_) => Answer::Okay(Some(entry.get_text()))
+    }
+}
+
+fn menu(opts: &Options, width: i32, height: i32) -> Result<Answer, Exit> {
+    let mut items = Vec::new();
+    let mut args = opts.args.iter();
+    while let Some(tag) = args.next() {
+        let item = args.next().ok_or(Exit::Usage)?;
+        let item = if opts.no_item { "" } else { item.as_str() };
+        items.push((tag.as_str(), item));
+    }
+    if items.is_empty() {
+        return Err(Exit::Usage);
+    }
+
+    let bh = opts.button_height();
+    let (textbox, top) = text_box(opts, height - 4 - bh - opts.list_height,
+                                  width - 2);
+
+    let scroll = opts.list_height < items.len() as i32;
+    let tag_width = if opts.no_tags { 0 } else {
+        items.iter().map(|(tag, _)| width_of(tag)).max().unwrap_or(0) + 1
+    };
+    let (cols, _) = newt::get_screen_size();
+    let line_width = (items.iter().map(|(_, item)| width_of(item)).max()
+        .unwrap_or(0) + tag_width + if scroll { 2 } else { 0 })
+        .min(cols - 6);
+
+    let mut flags = LISTBOX_RETURNEXIT;
+    if scroll {
+        flags |= FLAG_SCROLL;
+    }
+    let listbox: Listbox<usize> = Listbox::new((width - line_width) / 2,
+                                               top + 1, opts.list_height,
+                                               flags);
+    for (i, (tag, item)) in items.iter().enumerate() {
+        let text = if opts.no_tags {
+            item.to_string()
+        } else {
+            format!("{:w$}{}", tag, item, w = tag_width as usize)
+        };
+        listbox.append_entry(&text, i + 1).unwrap();
+        if opts.default_item.as_deref() == Some(*tag) {
+            listbox.set_current(i as i32);
+        }
+    }
+    let (ok, cancel) = opts.buttons(width, height);
+
+    let mut form = Form::new(None, 0);
+    form.add_components(&[&textbox, &listbox, &*ok]).unwrap();
+    if let Some(cancel) = &cancel {
+        form.add_component(&**cancel).unwrap();
+    }
+    form.add_hot_key(KEY_ESCAPE);
+
+    Ok(match form.run() {
+        Ok(ExitReason::HotKey(KEY_ESCAPE)) | Err(_) => Answer::Escape,
+        Ok(ref reason) if is_cancel(reason, &cancel) => Answer::Cancel,
+        Ok(_) => {
+            let tag = listbox.get_current().map(|i| items[i - 1].0);
+            Answer::Okay(tag.map(|tag| tag.to_string()))
+        }
+    })
+}
+
+fn check_list(opts: &Options, width: i32, height: i32)
+      -> Result<Answer, Exit> {
+    let radio = opts.mode == Mode::RadioList;
+    let mut items = Vec::new();
+    let mut args = opts.args.iter();
+    while let Some(tag) = args.next() {
+        let mut status = args.next().ok_or(Exit::Usage)?;
+        let mut item = "";
+        if !opts.no_item {
+            item = status;
+            status = args.next().ok_or(Exit::Usage)?;
+        }
+        let on = status == "1" || status.eq_ignore_ascii_case("on") ||
+                 status.eq_ignore_ascii_case("yes");
+        items.push((tag.as_str(), item, on));
+    }
+    if items.is_empty() {
+        return Err(Exit::Usage);
+    }
+
+    let bh = opts.button_height();
+    let (textbox, top) = text_box(opts, height - 3 - bh - opts.list_height,
+                                  width - 2);
+
+    let mut flags = 0;
+    if opts.list_height < items.len() as i32 {
+        flags |= FLAG_SCROLL;
+    }
+    let tree: CheckboxTree<usize> = CheckboxTree::new(2, top + 1,
+                                                      opts.list_height,
+                                                      None, flags);
+    tree.set_width(width - 4);
+
+    let tag_width = items.iter().map(|(tag, _, _)| width_of(tag)).max()
+        .unwrap_or(0) as usize;
+    for (i, (tag, item, on)) in items.iter().enumerate() {
+        let text = if opts.no_tags {
+            item.to_string()
+        } else {
+            format!("{:w$}  {}", tag, item, w = tag_width)
+        };
+        tree.add_item(&text, i + 1, 0, None);
+        if *on {
+            tree.set_entry_value(i + 1, '*');
+        }
+    }
+    tree.set_single_selection(radio);
+    let (ok, cancel) = opts.buttons(width, height);
+
+    let mut form = Form::new(None, 0);
+    form.add_components(&[&textbox, &tree, &*ok]).unwrap();
+    if let Some(cancel) = &cancel {
+        form.add_component(&**cancel).unwrap();
+    }
+    form.set_default_button(&*ok);
+    form.add_hot_key(KEY_ESCAPE);
+
+    Ok(match form.run() {
+        Ok(ExitReason::HotKey(KEY_ESCAPE)) | Err(_) => Answer::Escape,
+        Ok(ref reason) if is_cancel(reason, &cancel) => Answer::Cancel,
+        Ok(_) => {
+            let tags: Vec<&str> = tree.get_multi_selection('*').iter()
+                .map(|&i| items[i - 1].0)
+                .collect();
+            let output = if radio {
+                tags.first().map(|tag| tag.to_string()).unwrap_or_default()
+            } else if opts.separate_output {
+                tags.iter().map(|tag| format!("{}\n", tag)).collect()
+            } else {
+                let quoted: Vec<String> = tags.iter()
+                    .map(|tag| format!("\"{}\"", tag))
+                    .collect();
+                quoted.join(" ")
+            };
+            Answer::Okay(Some(output))
+        }
+    })
+}
+
+fn gauge(opts: &Options, fd: RawFd, width: i32, height: i32)
+      -> Result<Answer, Exit> {
+    let percent = opts.args.first().ok_or(Exit::Usage)?
+        .parse().map_err(|_| Exit::Usage)?;
+    let title = opts.title.as_deref().unwrap_or("");
+    let mut gauge = Gauge::new(title, &opts.text, percent);
+    gauge.set_size(width, height);
+    match gauge.run_fd(fd) {
+        Ok(()) => Ok(Answer::Okay(None)),
+        Err(err) => Ok(Answer::Failed(err))
+    }
+}
+
+fn text_box_pager(opts: &Options, width: i32, height: i32) -> Answer {
+    let title = opts.title.as_deref().unwrap_or("");
+    let mut pager = Pager::new(title, &opts.text);
+    pager.set_size(width, height);
+    if pager.run() { Answer::Okay(None) } else { Answer::Escape }
+}
+
+// Estimate the size of the window when the height or width is `0`.
+fn guess_size(opts: &Options, height: &mut i32, width: &mut i32,
+              list_height: &mut i32, scroll: &mut bool) {
+    let (cols, rows) = newt::get_screen_size();
+    let text_width = opts.text.lines().map(width_of).max().unwrap_or(0)
+        .min(cols - 8).max(1);
+    let mut h: i32 = opts.text.lines()
+        .map(|line| (width_of(line) + text_width - 1) / text_width)
+        .map(|lines| lines.max(1))
+        .sum();
+    let title = opts.title.as_deref().map(width_of).unwrap_or(0);
+    let mut w = text_width.max(title + 4) + 4;
+
+    let bh = opts.button_height();
+    let buttons = if opts.no_cancel { 1 } else { 2 };
+    match opts.mode {
+        Mode::Menu | Mode::CheckList | Mode::RadioList => {
+            let per_item = if opts.mode == Mode::Menu { 2 } else { 3 };
+            let step = if opts.no_item && opts.mode != Mode::Menu {
+                per_item - 1
+            } else {
+                per_item
+            };
+            let items = opts.args.chunks(step).map(|item| {
+                item.iter().take(2).map(|s| width_of(s) + 2).sum::<i32>()
+            });
+            let count = (opts.args.len() / step) as i32;
+            w = w.max(items.max().unwrap_or(0) + 10).max(buttons * 12);
+            if *list_height <= 0 {
+                *list_height = count.min(rows - h - bh - 8).max(1);
+            }
+            h += *list_height + bh + 3;
+        },
+        Mode::YesNo | Mode::MsgBox | Mode::TextBox => {
+            h += bh + 2;
+            w = w.max(buttons * 12);
+        },
+        Mode::InputBox | Mode::PasswordBox => {
+            h += bh + 3;
+            w = w.max(buttons * 12);
+        },
+        Mode::Gauge => h += 2
+    }
+
+    w += 2;
+    h += 2;
+    if h > rows - 1 {
+        h = rows - 1;
+        *scroll = true;
+        w += 2;
+    }
+    *width = (*width).max(w).min(cols);
+    *height = (*height).max(h);
+}
+
+fn output(fd: RawFd, text: &str) {
+    let result = match fd {
+        1 => io::stdout().write_all(text.as_bytes()),
+        2 => io::stderr().write_all(text.as_bytes()),
+        _ => {
+            // The file descriptor belongs to the caller, don't close it.
+            let mut file = unsafe { File::from_raw_fd(fd) };
+            let result = file.write_all(text.as_bytes());
+            std::mem::forget(file);
+            result
+        }
+    };
+    if let Err(err) = result {
+        eprintln!("Cannot write to output-fd: {}", err);
+    }
+}
+
+// Read progress from standard input while newt reads from the terminal.
+fn reopen_stdin() -> Result<RawFd, io::Error> {
+    unsafe {
+        let fd = libc::dup(0);
+        if fd < 0 || libc::close(0) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let tty = b"/dev/tty\0".as_ptr() as *const libc::c_char;
+        if libc::open(tty, libc::O_RDWR) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(fd)
+    }
+}
+
+fn run() -> i32 {
+    let mut opts = match Options::parse(env::args().skip(1)) {
+        Ok(opts) => opts,
+        Err(Exit::Help) => {
+            print!("{}", USAGE);
+            return DLG_OKAY;
+        },
+        Err(Exit::Version) => {
+            println!("newt-dialog (newt-rs): {}", env!("CARGO_PKG_VERSION"));
+            return DLG_OKAY;
+        },
+        Err(Exit::Usage) => {
+            eprint!("{}", USAGE);
+            return DLG_ERROR;
+        },
+        Err(Exit::BadOption(option)) => {
+            eprintln!("{}: bad argument", option);
+            return 1;
+        }
+    };
+
+    if opts.mode == Mode::TextBox {
+        opts.text = match fs::read_to_string(&opts.text) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("{}: {}", opts.text, err);
+                return DLG_ERROR;
+            }
+        };
+    }
+    opts.text = clean_newlines(&opts.text);
+
+    let mut fd = -1;
+    if opts.mode == Mode::Gauge {
+        fd = match reopen_stdin() {
+            Ok(fd) => fd,
+            Err(err) => {
+                eprintln!("dup/close stdin: {}", err);
+                return DLG_ERROR;
+            }
+        };
+    }
+
+    if newt::init().is_err() {
+        eprintln!("Unable to initialize the terminal.");
+        return DLG_ERROR;
+    }
+    newt::cls();
+
+    let (mut height, mut width) = (opts.height, opts.width);
+    let mut list_height = opts.list_height;
+    if height <= 0 || width <= 0 || list_height < 0 ||
+       (list_height == 0 && opts.list_height == 0 &&
+        opts.mode == Mode::Menu) {
+        let mut scroll = opts.scroll_text;
+        guess_size(&opts, &mut height, &mut width, &mut list_height,
+                   &mut scroll);
+        opts.scroll_text = scroll;
+    }
+    opts.list_height = list_height.max(1);
+    let (width, height) = (width - 2, height - 2);
+
+    if let Some(backtitle) = &opts.backtitle {
+        newt::draw_root_text(0, 0, backtitle);
+    }
+
+    let answer = if opts.mode == Mode::Gauge {
+        gauge(&opts, fd, width, height)
+    } else if opts.mode == Mode::TextBox {
+        Ok(text_box_pager(&opts, width, height))
+    } else {
+        let (cols, rows) = newt::get_screen_size();
+        let (left, top) = if opts.top_left { (1, 1) }
+                          else { ((cols - width) / 2, (rows - height) / 2) };
+        let title = opts.title.as_deref();
+        newt::open_window(left, top, width.max(1) as u32,
+                          height.max(1) as u32, title).ok();
+
+        let answer = match opts.mode {
+            Mode::MsgBox | Mode::YesNo =>
+                Ok(message_box(&opts, width, height)),
+            Mode::InputBox | Mode::PasswordBox =>
+                Ok(input_box(&opts, width, height)),
+            Mode::Menu => menu(&opts, width, height),
+            _ => check_list(&opts, width, height)
+        };
+        if opts.clear {
+            newt::pop_window();
+        }
+        answer
+    };
+    newt::finished();
+
+    match answer {
+        Ok(Answer::Okay(text)) => {
+            if let Some(text) = text {
+                output(opts.output_fd, &text);
+            }
+            DLG_OKAY
+        },
+        Ok(Answer::Cancel) => DLG_CANCEL,
+        Ok(Answer::Escape) => DLG_ESCAPE,
+        Ok(Answer::Failed(err)) => {
+            eprintln!("newt-dialog: {}", err);
+            DLG_ERROR
+        },
+        Err(_) => {
+            eprint!("{}", USAGE);
+            DLG_ERROR
+        }
+    }
+}
+
+fn main() {
+    process::exit(run());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, Exit> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_menu() {
+        let opts = parse(&["--title=Menu", "--menu", "Pick", "10", "40",
+                           "2", "a", "Apple", "b", "Banana"]).ok().unwrap();
+        assert_eq!(opts.mode, Mode::Menu);
+        assert_eq!(opts.title.as_deref(), Some("Menu"));
+        assert_eq!((opts.height, opts.width, opts.list_height), (10, 40, 2));
+        assert_eq!(opts.args, ["a", "Apple", "b", "Banana"]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(parse(&["--msgbox", "text", "10"]),
+                         Err(Exit::Usage)));
+        assert!(matches!(parse(&["--msgbox", "a", "1", "1", "--yesno"]),
+                         Err(Exit::Usage)));
+        assert!(matches!(parse(&["--bogus"]), Err(Exit::BadOption(_))));
+        assert!(matches!(parse(&["--help"]), Err(Exit::Help)));
+    }
+}
@@ -20,21 +20,74 @@
 //!
 //! Trait implemented by [widget][widget] and [`Grid`][grid] types.
 //!
+//! Types wrapping an existing widget can implement `Component` using
+//! `#[derive(Component)]`. The wrapped widget is either the only field of
+//! the type or the field marked with `#[component]`.
+//!
 //! [grid]: crate::grid
 //! [widget]: crate::widgets
 //!
+//! ## Example
+//! ```rust no_run
+//! extern crate newt;
+//! use newt::component::{Component,Data};
+//! use newt::prelude::*;
+//!
+//! struct Contact {
+//!     name: &'static str
+//! }
+//!
+//! #[derive(Component)]
+//! struct Contacts<'a>(Listbox<Data<'a, Contact>>);
+//!
+//! impl<'a> Contacts<'a> {
+//!     fn new(contacts: &'a [Contact]) -> Contacts<'a> {
+//!         let listbox = Listbox::new(1, 1, 5, 0);
+//!         for contact in contacts.iter() {
+//!             listbox.append_entry(contact.name, Data(contact)).unwrap();
+//!         }
+//!         Contacts(listbox)
+//!     }
+//!
+//!     fn selected(&self) -> Option<&'a Contact> {
+//!         self.0.get_current().map(|data| data.0)
+//!     }
+//! }
+//!
+//! pub fn main() {
+//!     let contacts = [Contact { name: "Alice" }, Contact { name: "Bob" }];
+//!
+//!     newt::init().unwrap();
+//!     newt::cls();
+//!     newt::centered_window(20, 7, Some("Contacts")).unwrap();
+//!
+//!     let list = Contacts::new(&contacts);
+//!     let mut form = Form::new(None, 0);
+//!     form.add_component(&list).unwrap();
+//!     form.run().unwrap();
+//!     newt::finished();
+//!
+//!     if let Some(contact) = list.selected() {
+//!         println!("Selected: {}", contact.name);
+//!     }
+//! }
+//! ```
+//!
 use std::cmp::PartialEq;
 use std::fmt::Debug;
 use std::ops::Deref;
 use std::os::raw::c_void;
 
 use newt_sys::*;
+use crate::error::Error;
 use crate::form::ExitReason;
 use crate::widgets::WidgetFns;
 
 use crate::asm::*;
 use crate::private::traits::*;
-use crate::private;
+
+#[doc(inline)]
+pub use newt_proc_macros::Component;
 
 ///
 /// A wrapper for passing complex data to [CheckboxTree][checkbox_tree] and
@@ -45,14 +98,13 @@ use crate::private;
 ///
 pub struct Data<'a, T: 'a>(pub &'a T);
 
-impl<'a, T: 'a> private::data::Data for Data<'a, T> {
+impl<'a, T: 'a> crate::data::Data for Data<'a, T> {
     fn newt_to_ptr(&self) -> *const c_void {
         self.0 as *const _ as *const c_void
     }
 
-    fn newt_from_ptr(ptr: *const c_void) -> Self {
-        let data = unsafe { &*(ptr as *const T) };
-        Data(data)
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        Data(&*(ptr as *const T))
     }
 }
 
@@ -66,11 +118,60 @@ impl<'a, T> Deref for Data<'a, T> {
 ///
 /// Trait implemented by `Widget` types and `Grid` types.
 ///
-pub trait Component: AsComponent + AsGrid + Child + GridElementType + Nullify
+pub trait Component: AsComponent + AsGrid + Child + ComponentPtr
+                                 + GridElementType + Nullify + ReturnExit
                                  + WidgetFns
 {
     /// Return `newtComponent` pointer.
     fn co(&self) -> newtComponent;
+
+    ///
+    /// `Returns` `true` if the `Component` has been destroyed along with
+    /// the [`Form`][form] it was added to.
+    ///
+    /// A `Grid` is destroyed once the `Component`s it arranges have been
+    /// destroyed.
+    ///
+    /// [form]: crate::form::Form
+    ///
+    fn is_destroyed(&self) -> bool {
+        if self.is_null() {
+            return true;
+        }
+
+        #[cfg(feature = "asm")]
+        if let Some(grid) = self.as_grid() {
+            return grid.children().iter().any(|child| child.is_destroyed());
+        }
+        false
+    }
+
+    ///
+    /// Check that the `Component` has not been destroyed before using it.
+    ///
+    /// Calling the functions of a destroyed `Component` panics. `check()`
+    /// allows the error to be handled instead. Widgets also provide
+    /// `try_*` variants of their functions performing the check.
+    ///
+    /// ```rust no_run
+    /// # extern crate newt;
+    /// # use newt::prelude::*;
+    /// # fn text(entry: &Entry) -> Result<String, newt::Error> {
+    /// let text = entry.check()?.get_text();
+    /// # Ok(text)
+    /// # }
+    /// ```
+    ///
+    /// `Returns` the `Component` or [`Error::Destroyed`].
+    ///
+    fn check(&self) -> Result<&Self, Error>
+        where Self: Sized
+    {
+        if self.is_destroyed() {
+            return Err(Error::Destroyed);
+        }
+        Ok(self)
+    }
 }
 
 impl Debug for dyn Component {
@@ -296,13 +296,13 @@ pub mod grid {
     pub const GRID_FLAG_GROWY: c_int = NEWT_GRID_FLAG_GROWY;
 
     /// Used to terminate a list of elements when adding them to a
-    /// [`Grid`](crate::grid::Grid).
+    /// [`Grid`](struct@crate::grid::Grid).
     pub const GRID_EMPTY: newtGridElement     = newtGridElement_NEWT_GRID_EMPTY;
-    /// Specify that the element being added to a [`Grid`](crate::grid::Grid)
-    /// is a Component.
+    /// Specify that the element being added to a
+    /// [`Grid`](struct@crate::grid::Grid) is a Component.
     pub const GRID_COMPONENT: newtGridElement = newtGridElement_NEWT_GRID_COMPONENT;
-    /// Specify that the element being added to a [`Grid`](crate::grid::Grid)
-    /// is a sub-Grid.
+    /// Specify that the element being added to a
+    /// [`Grid`](struct@crate::grid::Grid) is a sub-Grid.
     pub const GRID_SUBGRID: newtGridElement   = newtGridElement_NEWT_GRID_SUBGRID;
 
     #[deprecated(since="0.6.11", note="please use `GRID_ANCHOR_LEFT`")]
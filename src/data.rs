@@ -0,0 +1,268 @@
+//
+// Copyright (C) 2019 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+//!
+//! Values associated with [`Listbox`][listbox] and
+//! [`CheckboxTree`][checkbox_tree] items.
+//!
+//! `Data` can be derived for enums without fields and for structs with a
+//! single field implementing `Data`.
+//!
+//! [checkbox_tree]: crate::widgets::CheckboxTree
+//! [listbox]: crate::widgets::Listbox
+//!
+//! ## Example
+//! ```rust no_run
+//! extern crate newt;
+//! use newt::data::Data;
+//! use newt::prelude::*;
+//!
+//! #[derive(Clone, Copy, Data, Debug)]
+//! enum Action {
+//!     Install,
+//!     Remove
+//! }
+//!
+//! pub fn main() {
+//!     newt::init().unwrap();
+//!     newt::cls();
+//!     newt::centered_window(20, 4, Some("Action")).unwrap();
+//!
+//!     let listbox = Listbox::new(1, 1, 2, LISTBOX_RETURNEXIT);
+//!     listbox.append_entry("Install", Action::Install).unwrap();
+//!     listbox.append_entry("Remove", Action::Remove).unwrap();
+//!
+//!     let mut form = Form::new(None, 0);
+//!     form.add_component(&listbox).unwrap();
+//!     form.run().unwrap();
+//!     newt::finished();
+//!
+//!     if let Some(action) = listbox.get_current() {
+//!         println!("Selected: {:?}", action);
+//!     }
+//! }
+//! ```
+//!
+use std::num::*;
+use std::os::raw::c_void;
+use std::ptr;
+
+#[doc(inline)]
+pub use newt_proc_macros::Data;
+
+///
+/// Trait implemented by values that can be associated with `Listbox` and
+/// `CheckboxTree` items.
+///
+/// Values are stored by the C library as pointers. A value converted to
+/// a null pointer, such as `0` or `false`, cannot be distinguished from
+/// no value by functions such as
+/// [`Listbox::get_current()`][get_current]. Values wider than a pointer
+/// are truncated.
+///
+/// [get_current]: crate::widgets::Listbox::get_current
+///
+pub trait Data {
+    /// Convert the value to a pointer stored by the C library.
+    fn newt_to_ptr(&self) -> *const c_void;
+
+    ///
+    /// Convert a pointer stored by the C library back to a value.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`newt_to_ptr()`][to_ptr] for a
+    /// value of the same type. Values referring to other data, such as
+    /// [`component::Data`][data], require that data to still be alive.
+    ///
+    /// [data]: crate::component::Data
+    /// [to_ptr]: Data::newt_to_ptr
+    ///
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self;
+}
+
+macro_rules! impl_nonzero_data {
+    ($($nonzero:ty => $int:ty),*) => {
+        $(
+            impl Data for $nonzero {
+                fn newt_to_ptr(&self) -> *const c_void {
+                    self.get() as usize as *const c_void
+                }
+
+                unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+                    <$nonzero>::new(ptr as usize as $int)
+                        .expect("Invalid non-zero value.")
+                }
+            }
+        )*
+    }
+}
+
+impl Data for () {
+    fn newt_to_ptr(&self) -> *const c_void {
+        ptr::null()
+    }
+
+    unsafe fn newt_from_ptr(_ptr: *const c_void) -> Self { }
+}
+
+impl Data for bool {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        !ptr.is_null()
+    }
+}
+
+impl Data for char {
+    fn newt_to_ptr(&self) -> *const c_void {
+        if !self.is_ascii() {
+            panic!("UTF-8 characters are not supported.");
+        }
+
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as u8 as char
+    }
+}
+
+impl Data for i8 {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize as i8
+    }
+}
+
+impl Data for i16 {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize as i16
+    }
+}
+
+impl Data for i32 {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize as i32
+    }
+}
+
+impl Data for i64 {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize as i64
+    }
+}
+
+impl Data for isize {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize as isize
+    }
+}
+
+impl Data for u8 {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize as u8
+    }
+}
+
+impl Data for u16 {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize as u16
+    }
+}
+
+impl Data for u32 {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize as u32
+    }
+}
+
+impl Data for u64 {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as usize as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize as u64
+    }
+}
+
+impl Data for usize {
+    fn newt_to_ptr(&self) -> *const c_void {
+        *self as *const c_void
+    }
+
+    unsafe fn newt_from_ptr(ptr: *const c_void) -> Self {
+        ptr as usize
+    }
+}
+
+impl_nonzero_data! {
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32,
+    NonZeroI64 => i64, NonZeroIsize => isize,
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32,
+    NonZeroU64 => u64, NonZeroUsize => usize
+}
+
+#[test]
+#[should_panic(expected = "UTF-8 characters are not supported.")]
+fn char_data_should_not_accept_utf8() {
+    let s = "\u{1F603}";
+    let c = s.chars().next().unwrap();
+    let _ptr = c.newt_to_ptr();
+}
+
+#[test]
+fn char_data_should_accept_ascii() {
+    let c = '0';
+    let _ptr = c.newt_to_ptr();
+}
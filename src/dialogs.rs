@@ -0,0 +1,34 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+//!
+//! Dialog windows composed of newt components.
+//!
+#[cfg(feature = "chrono")]
+mod date_picker;
+#[cfg(feature = "chrono")]
+pub use self::date_picker::DatePicker;
+mod file_chooser;
+pub use self::file_chooser::FileChooser;
+mod gauge;
+pub use self::gauge::Gauge;
+mod pager;
+pub use self::pager::Pager;
+mod progress_dialog;
+pub use self::progress_dialog::{CancelToken,ProgressDialog,ProgressReporter};
@@ -0,0 +1,242 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use chrono::{Datelike,NaiveDate,NaiveDateTime};
+
+use crate::callbacks::EntryFilter;
+use crate::constants::{KEY_PGDN,KEY_PGUP};
+use crate::form::{ExitReason,Form};
+use crate::private::date::{self,DateFormat};
+use crate::widgets::{Button,Entry,Label,Textbox};
+use crate::windows::win_message;
+
+const WIDTH: i32 = 30;
+const HEIGHT: i32 = 15;
+const MONTHS: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June", "July",
+    "August", "September", "October", "November", "December"
+];
+
+///
+/// A dialog for choosing a date, or a date and time, from a calendar.
+///
+/// The calendar displays the month of the date being edited, with the
+/// chosen day in brackets. The date is edited in a [`DateEntry`][entry]
+/// style `Entry` below the calendar, and `Page Up` and `Page Down`
+/// display the previous and next months.
+///
+/// Requires the `chrono` feature.
+///
+/// [entry]: crate::widgets::DateEntry
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate chrono;
+/// extern crate newt;
+/// use chrono::NaiveDate;
+/// use newt::dialogs::DatePicker;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///
+///     let today = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+///     let date = DatePicker::new("Due Date", today).run_date();
+///     newt::finished();
+///
+///     println!("Due: {:?}", date);
+/// }
+/// ```
+///
+#[derive(Clone,Debug)]
+pub struct DatePicker {
+    title: String,
+    datetime: NaiveDateTime,
+    format: DateFormat
+}
+
+impl DatePicker {
+    ///
+    /// Create a new `DatePicker` for choosing a date.
+    ///
+    /// * `title` - The title of the dialog window.
+    /// * `date` - The date initially chosen.
+    ///
+    pub fn new(title: &str, date: NaiveDate) -> DatePicker {
+        DatePicker {
+            title: title.to_string(),
+            datetime: date.and_hms_opt(0, 0, 0).unwrap(),
+            format: DateFormat::Date
+        }
+    }
+
+    ///
+    /// Create a new `DatePicker` for choosing a date and time.
+    ///
+    /// * `title` - The title of the dialog window.
+    /// * `datetime` - The date and time initially chosen.
+    ///
+    pub fn new_datetime(title: &str, datetime: NaiveDateTime) -> DatePicker {
+        DatePicker {
+            title: title.to_string(),
+            datetime,
+            format: DateFormat::DateTime
+        }
+    }
+
+    ///
+    /// Display the `DatePicker` and wait for the user to choose a date.
+    ///
+    /// `Returns` the chosen date and time, or `None` if the dialog was
+    /// cancelled. The time is midnight unless the `DatePicker` was
+    /// created with [`DatePicker::new_datetime()`].
+    ///
+    pub fn run(&self) -> Option<NaiveDateTime> {
+        let width = WIDTH as u32;
+        let height = HEIGHT as u32;
+        crate::centered_window(width, height, Some(&self.title)).ok()?;
+        let rv = self.run_window();
+        crate::pop_window();
+        rv
+    }
+
+    ///
+    /// Display the `DatePicker` and wait for the user to choose a date.
+    ///
+    /// `Returns` the chosen date, or `None` if the dialog was cancelled.
+    ///
+    pub fn run_date(&self) -> Option<NaiveDate> {
+        self.run().map(|datetime| datetime.date())
+    }
+
+    fn run_window(&self) -> Option<NaiveDateTime> {
+        let format = self.format;
+        let month = Label::new(1, 0, "");
+        let days = Textbox::new(1, 1, WIDTH - 2, 7, 0);
+        let label = Label::new(1, 9, "Date:");
+        let entry = Entry::new(7, 9, Some(&format.format(&self.datetime)),
+                               format.width() as i32, 0);
+        entry.set_cursor_position(0);
+        let ok = Button::new(WIDTH / 4 - 3, 11, "OK");
+        let cancel = Button::new(WIDTH * 3 / 4 - 5, 11, "Cancel");
+
+        let mut form = Form::new(None, 0);
+        form.add_components(&[&month, &days, &label, &entry, &ok, &cancel])
+            .ok()?;
+        form.set_default_button(&ok);
+        form.set_cancel_button(&cancel);
+        form.add_hot_key(KEY_PGUP);
+        form.add_hot_key(KEY_PGDN);
+        form.set_current(&entry);
+
+        let update = |datetime: &NaiveDateTime| {
+            let title = format!("{} {}", MONTHS[datetime.month0() as usize],
+                                datetime.year());
+            let width = (WIDTH - 2) as usize;
+            month.set_text(&format!("{:^width$}", title, width = width));
+            days.set_text(&calendar(&datetime.date()));
+        };
+        update(&self.datetime);
+
+        let _filter = EntryFilter::new(&entry, Some(format),
+            |entry: &Entry, data: Option<&DateFormat>, ch, cursor| {
+                let ch = date::filter(entry, data, ch, cursor);
+                if let Some(datetime) = format.parse(&entry.get_text()) {
+                    update(&datetime);
+                }
+                ch
+            });
+
+        let mut datetime = self.datetime;
+        loop {
+            let reason = form.run().ok()?;
+            if let Some(current) = format.parse(&entry.get_text()) {
+                datetime = current;
+            }
+
+            let months = match reason {
+                ExitReason::HotKey(KEY_PGUP) => -1,
+                ExitReason::HotKey(KEY_PGDN) => 1,
+                _ if reason == ok => {
+                    match format.parse(&entry.get_text()) {
+                        Some(datetime) => return Some(datetime),
+                        None => {
+                            let text = format!("{}\nis not a valid date.",
+                                               entry.get_text());
+                            win_message("Error", "OK", &text);
+                            continue;
+                        }
+                    }
+                },
+                _ => return None
+            };
+
+            datetime = date::add_months(&datetime, months);
+            entry.set_text(&format.format(&datetime), false);
+            update(&datetime);
+        }
+    }
+}
+
+//
+// The days of the month of `date` as a table of weeks starting on
+// Monday, with `date` in brackets.
+//
+fn calendar(date: &NaiveDate) -> String {
+    let mut text = String::from(" Mo  Tu  We  Th  Fr  Sa  Su");
+    let first = date.with_day(1).unwrap();
+    let offset = first.weekday().num_days_from_monday() as usize;
+    let days = date::days_in_month(date.year(), date.month() as i32) as u32;
+
+    for cell in 0..(offset + days as usize) {
+        if cell % 7 == 0 {
+            text.push('\n');
+        }
+        if cell < offset {
+            text.push_str("    ");
+            continue;
+        }
+
+        let day = (cell - offset) as u32 + 1;
+        if day == date.day() {
+            text.push_str(&format!("[{:2}]", day));
+        } else {
+            text.push_str(&format!(" {:2} ", day));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_picker_calendar() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        let expected = concat!(
+            " Mo  Tu  We  Th  Fr  Sa  Su\n",
+            "              1   2   3   4 \n",
+            "  5   6   7   8   9  10  11 \n",
+            " 12  13  14  15  16  17  18 \n",
+            "[19] 20  21  22  23  24  25 \n",
+            " 26  27  28  29  30  31 ");
+        assert_eq!(calendar(&date), expected);
+    }
+}
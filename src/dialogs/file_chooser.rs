@@ -0,0 +1,591 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::fs;
+use std::io;
+use std::path::{Path,PathBuf};
+
+use crate::constants::{FLAG_BORDER,FLAG_MULTIPLE,FLAG_SCROLL};
+use crate::constants::{ENTRY_RETURNEXIT,ENTRY_SCROLL,LISTBOX_RETURNEXIT};
+use crate::form::{ExitReason,Form};
+use crate::widgets::{Button,Entry,Label,OwnedListbox};
+use crate::windows::{win_choice,win_message};
+
+const WIDTH: i32 = 60;
+const HEIGHT: i32 = 18;
+
+#[derive(Clone)]
+enum Item {
+    Parent(PathBuf),
+    Directory(PathBuf),
+    File(PathBuf)
+}
+
+///
+/// A dialog for choosing files or directories.
+///
+/// The dialog displays the entries of a directory in a `Listbox` along
+/// with an `Entry` for typing a file name. Directories are entered by
+/// activating them in the list, or by typing their name into the `Entry`.
+/// Typing a pattern containing `*` or `?` displays the files matching the
+/// pattern in place of the filters until the dialog is closed.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::dialogs::FileChooser;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///
+///     let mut chooser = FileChooser::new("Open", "/etc");
+///     chooser.set_filters(&["*.conf"]);
+///     chooser.set_multiple(true);
+///     let paths = chooser.run();
+///     newt::finished();
+///
+///     if let Some(paths) = paths {
+///         for path in paths.iter() {
+///             println!("Selected: {}", path.display());
+///         }
+///     }
+/// }
+/// ```
+///
+#[derive(Clone,Debug)]
+pub struct FileChooser {
+    title: String,
+    directory: PathBuf,
+    file_name: String,
+    filters: Vec<String>,
+    show_hidden: bool,
+    multiple: bool,
+    save: bool,
+    directories: bool
+}
+
+impl FileChooser {
+    ///
+    /// Create a new `FileChooser`.
+    ///
+    /// * `title` - The title of the dialog window.
+    /// * `directory` - The directory initially displayed.
+    ///
+    pub fn new<P: AsRef<Path>>(title: &str, directory: P) -> FileChooser {
+        FileChooser {
+            title: title.to_string(),
+            directory: directory.as_ref().to_path_buf(),
+            file_name: String::new(),
+            filters: Vec::new(),
+            show_hidden: false,
+            multiple: false,
+            save: false,
+            directories: false
+        }
+    }
+
+    ///
+    /// Get the directory initially displayed by the `FileChooser`.
+    ///
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    ///
+    /// Set the directory initially displayed by the `FileChooser`.
+    ///
+    /// * `directory` - The directory to display.
+    ///
+    pub fn set_directory<P: AsRef<Path>>(&mut self, directory: P) {
+        self.directory = directory.as_ref().to_path_buf();
+    }
+
+    ///
+    /// Set the file name initially displayed in the `FileChooser`'s
+    /// `Entry`.
+    ///
+    /// * `name` - The initial file name.
+    ///
+    pub fn set_file_name(&mut self, name: &str) {
+        self.file_name = name.to_string();
+    }
+
+    ///
+    /// Set the glob patterns used to filter displayed files. Files
+    /// matching any of the patterns are displayed. Directories are
+    /// always displayed.
+    ///
+    /// Patterns may contain `*` matching any number of characters, `?`
+    /// matching a single character, and `[...]` matching a set of
+    /// characters.
+    ///
+    /// * `filters` - The glob patterns. An empty slice displays all files.
+    ///
+    pub fn set_filters(&mut self, filters: &[&str]) {
+        self.filters = filters.iter().map(|f| f.to_string()).collect();
+    }
+
+    ///
+    /// Display hidden files, whose names begin with a `.`.
+    ///
+    pub fn set_show_hidden(&mut self, show_hidden: bool) {
+        self.show_hidden = show_hidden;
+    }
+
+    ///
+    /// Allow multiple entries to be selected with the `Space` key.
+    ///
+    pub fn set_multiple(&mut self, multiple: bool) {
+        self.multiple = multiple;
+    }
+
+    ///
+    /// Choose a file to be saved. Files that don't exist may be chosen,
+    /// and choosing an existing file asks for confirmation before
+    /// it's overwritten. Multiple selection is disabled in save mode.
+    ///
+    pub fn set_save(&mut self, save: bool) {
+        self.save = save;
+    }
+
+    ///
+    /// Choose directories rather than files. Only directories are
+    /// displayed. Pressing `OK` chooses the selected directory, or the
+    /// displayed directory when no directory is selected.
+    ///
+    pub fn set_directories(&mut self, directories: bool) {
+        self.directories = directories;
+    }
+
+    ///
+    /// `Returns` `true` if the file name `name` is matched by the
+    /// `FileChooser`'s filters.
+    ///
+    /// * `name` - The file name to match.
+    ///
+    pub fn matches(&self, name: &str) -> bool {
+        self.filters.is_empty() ||
+            self.filters.iter().any(|f| glob_match(f, name))
+    }
+
+    ///
+    /// List the entries of a directory as they'd be displayed by the
+    /// `FileChooser`. Directories are listed before files, each sorted by
+    /// name.
+    ///
+    /// * `directory` - The directory to list.
+    ///
+    pub fn entries<P: AsRef<Path>>(&self, directory: P)
+          -> io::Result<Vec<PathBuf>> {
+        self.list(directory.as_ref(), None)
+    }
+
+    //
+    // List the entries of `directory`, matching files against `pattern`
+    // rather than the filters when it's provided.
+    //
+    fn list(&self, directory: &Path, pattern: Option<&str>)
+          -> io::Result<Vec<PathBuf>> {
+        let mut dirs = Vec::new();
+        let mut files = Vec::new();
+
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+            let name = match path.file_name() {
+                Some(name) => name.to_string_lossy().into_owned(),
+                None => continue
+            };
+
+            if !self.show_hidden && name.starts_with('.') {
+                continue;
+            }
+
+            if path.is_dir() {
+                dirs.push(path);
+            } else if !self.directories {
+                let matched = match pattern {
+                    Some(pattern) => glob_match(pattern, &name),
+                    None => self.matches(&name)
+                };
+                if matched {
+                    files.push(path);
+                }
+            }
+        }
+
+        dirs.sort();
+        files.sort();
+        dirs.append(&mut files);
+        Ok(dirs)
+    }
+
+    ///
+    /// Display the `FileChooser` and wait for the user to choose.
+    ///
+    /// `Returns` the chosen paths, or `None` if the dialog was cancelled.
+    ///
+    pub fn run(&self) -> Option<Vec<PathBuf>> {
+        let mut chooser = self.clone();
+        chooser.multiple = self.multiple && !self.save;
+
+        let width = WIDTH as u32;
+        let height = HEIGHT as u32;
+        crate::centered_window(width, height, Some(&self.title)).ok()?;
+        let rv = chooser.run_window();
+        crate::pop_window();
+        rv
+    }
+
+    fn run_window(&self) -> Option<Vec<PathBuf>> {
+        let mut flags = FLAG_SCROLL | FLAG_BORDER | LISTBOX_RETURNEXIT;
+        if self.multiple {
+            flags |= FLAG_MULTIPLE;
+        }
+
+        let directory = Label::new(1, 0, "");
+        let listbox: OwnedListbox<Item> = OwnedListbox::new(1, 1, 12, flags);
+        listbox.set_width(WIDTH - 2);
+        let name_label = Label::new(1, 13, "Name:");
+        let entry = Entry::new(7, 13, Some(&self.file_name), WIDTH - 8,
+                               ENTRY_SCROLL | ENTRY_RETURNEXIT);
+        let ok = Button::new(WIDTH / 4 - 3, 14, "OK");
+        let cancel = Button::new(WIDTH * 3 / 4 - 5, 14, "Cancel");
+
+        let mut form = Form::new(None, 0);
+        form.add_components(&[&directory, &listbox, &name_label, &entry,
+                              &ok, &cancel]).ok()?;
+        form.set_default_button(&ok);
+        form.set_cancel_button(&cancel);
+
+        let mut current = canonical(&self.directory);
+        let mut pattern: Option<String> = None;
+        if !self.populate(&listbox, &directory, &current, None) {
+            return None;
+        }
+
+        loop {
+            let reason = form.run().ok()?;
+            if reason == cancel || !matches!(reason, ExitReason::Component(_))
+            {
+                return None;
+            }
+
+            let text = entry.get_text();
+            let target = if text.is_empty() {
+                self.chosen_item(&listbox, reason == ok)
+            } else if text.contains(&['*', '?'][..]) {
+                pattern = Some(text);
+                Choice::Navigate(current.clone())
+            } else {
+                let path = current.join(&text);
+                let open_dir = reason != ok || !self.directories;
+                if path.is_dir() && open_dir {
+                    Choice::Navigate(path)
+                } else {
+                    Choice::Accept(vec![path])
+                }
+            };
+
+            match target {
+                Choice::Navigate(path) => {
+                    let path = canonical(&path);
+                    if self.populate(&listbox, &directory, &path,
+                                     pattern.as_deref()) {
+                        current = path;
+                        entry.set_text("", false);
+                        form.set_current(&listbox);
+                    }
+                },
+                Choice::Accept(paths) => {
+                    if self.confirm(&paths) {
+                        return Some(paths);
+                    }
+                },
+                Choice::Current => {
+                    return Some(vec![current]);
+                },
+                Choice::Nothing => ()
+            }
+        }
+    }
+
+    fn chosen_item(&self, listbox: &OwnedListbox<Item>, ok: bool)
+          -> Choice {
+        let current = listbox.get_current();
+        if let (false, Some(Item::Parent(path)))
+             | (false, Some(Item::Directory(path))) = (ok, &current) {
+            return Choice::Navigate(path.clone());
+        }
+
+        if self.multiple {
+            let paths: Vec<PathBuf> = listbox.get_selection().iter()
+                .filter_map(|item| match item {
+                    Item::Directory(path) if self.directories =>
+                        Some(path.clone()),
+                    Item::File(path) => Some(path.clone()),
+                    _ => None
+                })
+                .collect();
+            if !paths.is_empty() {
+                return Choice::Accept(paths);
+            }
+        }
+
+        match current {
+            Some(Item::Parent(path)) => Choice::Navigate(path),
+            Some(Item::Directory(path)) => {
+                if self.directories && ok {
+                    Choice::Accept(vec![path])
+                } else {
+                    Choice::Navigate(path)
+                }
+            },
+            Some(Item::File(path)) => Choice::Accept(vec![path]),
+            None if self.directories && ok => Choice::Current,
+            None => Choice::Nothing
+        }
+    }
+
+    fn confirm(&self, paths: &[PathBuf]) -> bool {
+        if let Err(text) = self.validate(paths) {
+            win_message("Error", "OK", &text);
+            return false;
+        }
+
+        if self.save && !self.directories {
+            for path in paths.iter().filter(|path| path.exists()) {
+                let text = format!("{}\nalready exists. Overwrite it?",
+                                   path.display());
+                if win_choice("Confirm", "Yes", "No", &text) != 1 {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    //
+    // Check that every chosen path can be accepted.
+    //
+    // `Returns` the error message to display for the first path that
+    // can't be.
+    //
+    fn validate(&self, paths: &[PathBuf]) -> Result<(), String> {
+        for path in paths.iter() {
+            let error = if self.directories {
+                if path.is_dir() { continue; }
+                "is not a directory."
+            } else if path.is_dir() {
+                "is a directory."
+            } else if !self.save && !path.exists() {
+                "does not exist."
+            } else {
+                continue;
+            };
+            return Err(format!("{}\n{}", path.display(), error));
+        }
+        Ok(())
+    }
+
+    fn populate(&self, listbox: &OwnedListbox<Item>, label: &Label,
+                directory: &Path, pattern: Option<&str>) -> bool {
+        let entries = match self.list(directory, pattern) {
+            Ok(entries) => entries,
+            Err(err) => {
+                let text = format!("{}\n{}", directory.display(), err);
+                win_message("Error", "OK", &text);
+                return false;
+            }
+        };
+
+        listbox.clear();
+        if let Some(parent) = directory.parent() {
+            let item = Item::Parent(parent.to_path_buf());
+            listbox.append_entry("../", item).ok();
+        }
+
+        for path in entries.into_iter() {
+            let name = path.file_name().unwrap().to_string_lossy()
+                .into_owned();
+            if path.is_dir() {
+                let text = format!("{}/", name);
+                listbox.append_entry(&text, Item::Directory(path)).ok();
+            } else {
+                listbox.append_entry(&name, Item::File(path)).ok();
+            }
+        }
+
+        let width = WIDTH as usize - 2;
+        let mut text = directory.to_string_lossy().into_owned();
+        while text.chars().count() > width {
+            text.remove(0);
+        }
+        label.set_text(&format!("{:width$}", text, width = width));
+        true
+    }
+}
+
+enum Choice {
+    Navigate(PathBuf),
+    Accept(Vec<PathBuf>),
+    Current,
+    Nothing
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+//
+// Match `name` against a glob `pattern` supporting `*`, `?` and `[...]`.
+//
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    glob_match_chars(&pattern, &name)
+}
+
+//
+// On a mismatch only the most recent `*` is retried, consuming one more
+// character of `name`, so matching takes at most O(pattern * name) steps.
+//
+fn glob_match_chars(pattern: &[char], name: &[char]) -> bool {
+    let mut p = 0;
+    let mut n = 0;
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if pattern.get(p) == Some(&'*') {
+            star = Some((p + 1, n));
+            p += 1;
+        } else if let Some(next) = glob_match_one(pattern, p, name[n]) {
+            p = next;
+            n += 1;
+        } else if let Some((star_p, star_n)) = star {
+            star = Some((star_p, star_n + 1));
+            p = star_p;
+            n = star_n + 1;
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+//
+// Match the single character `c` against the pattern element at `p`.
+// `Returns` the index of the next pattern element on a match.
+//
+fn glob_match_one(pattern: &[char], p: usize, c: char) -> Option<usize> {
+    match pattern.get(p)? {
+        '?' => Some(p + 1),
+        '[' => {
+            match pattern[p..].iter().position(|&x| x == ']') {
+                Some(end) if end > 1 => {
+                    if set_contains(&pattern[p + 1..p + end], c) {
+                        Some(p + end + 1)
+                    } else {
+                        None
+                    }
+                },
+                _ => if c == '[' { Some(p + 1) } else { None }
+            }
+        },
+        &pc => if pc == c { Some(p + 1) } else { None }
+    }
+}
+
+fn set_contains(set: &[char], c: char) -> bool {
+    let (negate, set) = match set.first() {
+        Some('!') | Some('^') => (true, &set[1..]),
+        _ => (false, set)
+    };
+
+    let mut found = false;
+    let mut i = 0;
+    while i < set.len() {
+        if i + 2 < set.len() && set[i + 1] == '-' {
+            if set[i] <= c && c <= set[i + 2] {
+                found = true;
+            }
+            i += 3;
+        } else {
+            if set[i] == c {
+                found = true;
+            }
+            i += 1;
+        }
+    }
+    found != negate
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self,File};
+    use std::slice;
+    use super::{glob_match,FileChooser};
+
+    #[test]
+    fn glob() {
+        assert!(glob_match("*.rs", "lib.rs"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("*.rs", "lib.rsx"));
+        assert!(glob_match("lib.?s", "lib.rs"));
+        assert!(!glob_match("lib.?s", "lib.s"));
+        assert!(glob_match("[a-c]*", "brown"));
+        assert!(!glob_match("[!a-c]*", "brown"));
+        assert!(glob_match("*[0-9].txt", "file9.txt"));
+        assert!(glob_match("[.txt", "[.txt"));
+        assert!(glob_match("a*b*c", "aXbYc"));
+        assert!(!glob_match("a*b*c", "aXbY"));
+        assert!(glob_match("*?", "x"));
+        assert!(!glob_match("*?", ""));
+        assert!(glob_match("**a", "a"));
+
+        let name = "a".repeat(64);
+        assert!(!glob_match("*a*a*a*a*a*a*a*a*a*a*b", &name));
+        assert!(glob_match("*a*a*a*a*a*a*a*a*a*a*", &name));
+    }
+
+    #[test]
+    fn validate() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("file");
+        let missing = dir.path().join("missing");
+        let subdir = dir.path().join("subdir");
+        File::create(&file).unwrap();
+        fs::create_dir(&subdir).unwrap();
+
+        let mut chooser = FileChooser::new("Open", dir.path());
+        chooser.set_multiple(true);
+        assert!(chooser.validate(slice::from_ref(&file)).is_ok());
+        let err = chooser.validate(&[file.clone(), missing.clone()]);
+        assert_eq!(err, Err(format!("{}\ndoes not exist.",
+                                    missing.display())));
+        assert!(chooser.validate(&[file.clone(), subdir.clone()]).is_err());
+
+        chooser.set_save(true);
+        assert!(chooser.validate(&[file.clone(), missing.clone()]).is_ok());
+
+        chooser.set_directories(true);
+        assert!(chooser.validate(slice::from_ref(&subdir)).is_ok());
+        assert!(chooser.validate(&[subdir, file]).is_err());
+    }
+}
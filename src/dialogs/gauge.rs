@@ -0,0 +1,291 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::io::{self,Read};
+use std::os::raw::c_void;
+use std::os::unix::io::{AsRawFd,RawFd};
+use std::os::unix::net::UnixStream;
+use std::thread;
+
+use crate::constants::TEXTBOX_WRAP;
+use crate::form::{ExitReason,FDFlags,Form};
+use crate::widgets::{Scale,Textbox};
+
+//
+// An update read from the gauge's input.
+//
+#[derive(Debug,PartialEq)]
+enum Update {
+    Percent(u64),
+    Message(String)
+}
+
+//
+// Splits input into lines and interprets them as whiptail does. A line
+// containing a number sets the percentage. A line containing `XXX`
+// starts a block whose first line is the percentage and whose remaining
+// lines, up to the next `XXX`, are the message.
+//
+#[derive(Default)]
+struct Parser {
+    buf: Vec<u8>,
+    block: Option<Vec<String>>
+}
+
+impl Parser {
+    fn feed(&mut self, data: &[u8]) -> Vec<Update> {
+        self.buf.extend_from_slice(data);
+
+        let mut updates = Vec::new();
+        while let Some(end) = self.buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buf.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line[..end]).into_owned();
+            self.line(line, &mut updates);
+        }
+        updates
+    }
+
+    fn finish(&mut self) -> Vec<Update> {
+        let mut updates = Vec::new();
+        if !self.buf.is_empty() {
+            let line = String::from_utf8_lossy(&self.buf).into_owned();
+            self.buf.clear();
+            self.line(line, &mut updates);
+        }
+        updates
+    }
+
+    fn line(&mut self, line: String, updates: &mut Vec<Update>) {
+        let line = line.trim_end_matches('\r');
+        if let Some(mut block) = self.block.take() {
+            if line.trim() != "XXX" {
+                block.push(line.to_string());
+                self.block = Some(block);
+                return;
+            }
+
+            if block.is_empty() {
+                return;
+            }
+            if let Some(percent) = percent(&block[0]) {
+                updates.push(Update::Percent(percent));
+            }
+            updates.push(Update::Message(block[1..].join("\n")));
+        } else if line.trim() == "XXX" {
+            self.block = Some(Vec::new());
+        } else if let Some(percent) = percent(line) {
+            updates.push(Update::Percent(percent));
+        }
+    }
+}
+
+fn percent(line: &str) -> Option<u64> {
+    line.trim().parse::<u64>().ok().map(|percent| percent.min(100))
+}
+
+///
+/// A dialog displaying a progress bar updated from a file descriptor,
+/// like `whiptail --gauge`.
+///
+/// Each line read containing a number sets the percentage displayed.
+/// A line containing `XXX` starts a block whose first line sets the
+/// percentage and whose following lines, up to the next `XXX`, replace
+/// the message displayed. The dialog closes at the end of file.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use std::process::{Command,Stdio};
+/// use newt::dialogs::Gauge;
+///
+/// pub fn main() {
+///     let child = Command::new("sh")
+///         .args(&["-c", "for i in 0 25 50 75 100; do \
+///                            echo $i; sleep 1; done"])
+///         .stdout(Stdio::piped())
+///         .spawn()
+///         .unwrap();
+///
+///     newt::init().unwrap();
+///     newt::cls();
+///
+///     let gauge = Gauge::new("Progress", "Please wait...", 0);
+///     gauge.run(child.stdout.unwrap()).unwrap();
+///     newt::finished();
+/// }
+/// ```
+///
+#[derive(Clone,Debug)]
+pub struct Gauge {
+    title: String,
+    text: String,
+    percent: u64,
+    width: i32,
+    height: i32
+}
+
+impl Gauge {
+    ///
+    /// Create a new `Gauge`.
+    ///
+    /// * `title` - The title of the dialog window.
+    /// * `text` - The message initially displayed.
+    /// * `percent` - The percentage initially displayed.
+    ///
+    pub fn new(title: &str, text: &str, percent: u64) -> Gauge {
+        Gauge {
+            title: title.to_string(),
+            text: text.to_string(),
+            percent: percent.min(100),
+            width: 60,
+            height: 6
+        }
+    }
+
+    ///
+    /// Set the size of the `Gauge` window. The message is displayed
+    /// above the progress bar in the lines remaining.
+    ///
+    /// * `width` - The width of the window.
+    /// * `height` - The height of the window, at least `3`.
+    ///
+    pub fn set_size(&mut self, width: i32, height: i32) {
+        self.width = width.max(4);
+        self.height = height.max(3);
+    }
+
+    ///
+    /// Display the `Gauge`, reading updates from the file descriptor
+    /// `fd` until the end of file. The file descriptor isn't closed.
+    ///
+    /// newt reads the keyboard from standard input. To read updates from
+    /// a pipe on standard input, as `whiptail` does, duplicate it and
+    /// reopen `/dev/tty` as standard input before calling
+    /// [`newt::init()`][init].
+    ///
+    /// * `fd` - The file descriptor to read updates from.
+    ///
+    /// `Returns` an `io::Error` if the window can't be opened or reading
+    /// from `fd` fails.
+    ///
+    /// [init]: crate::init
+    ///
+    pub fn run_fd(&self, fd: RawFd) -> io::Result<()> {
+        let width = self.width as u32;
+        let height = self.height as u32;
+        crate::centered_window(width, height, Some(&self.title))
+            .map_err(|_| io::Error::other("unable to open the window"))?;
+        let rv = self.run_window(fd);
+        crate::pop_window();
+        rv
+    }
+
+    ///
+    /// Display the `Gauge`, reading updates from `reader` until the end
+    /// of file.
+    ///
+    /// Readers that aren't backed by a file descriptor, such as
+    /// `std::io::Cursor`, are read by a separate thread.
+    ///
+    /// * `reader` - The source of updates.
+    ///
+    /// `Returns` an `io::Error` if the window can't be opened or the
+    /// updates can't be read.
+    ///
+    pub fn run<R>(&self, reader: R) -> io::Result<()>
+      where R: Read + Send + 'static
+    {
+        let (stream, mut writer) = UnixStream::pair()?;
+        thread::spawn(move || {
+            let mut reader = reader;
+            let _ = io::copy(&mut reader, &mut writer);
+        });
+        self.run_fd(stream.as_raw_fd())
+    }
+
+    fn run_window(&self, fd: RawFd) -> io::Result<()> {
+        let text = Textbox::new(1, 0, self.width - 2, self.height - 2,
+                                TEXTBOX_WRAP);
+        let scale = Scale::new(1, self.height - 1, self.width - 2, 100);
+        text.set_text(&self.text);
+        scale.set(self.percent);
+
+        let mut form = Form::new(None, 0);
+        form.add_components(&[&text, &scale])
+            .map_err(io::Error::other)?;
+        form.watch_fd(fd, FDFlags::Read);
+
+        let mut parser = Parser::default();
+        let mut buf = [0u8; 4096];
+        loop {
+            let reason = form.run()
+                .map_err(|_| io::Error::other("unable to run the form"))?;
+            match reason {
+                ExitReason::FDReady(_) => (),
+                _ => continue
+            }
+
+            let count = unsafe {
+                libc::read(fd, buf.as_mut_ptr() as *mut c_void, buf.len())
+            };
+            let updates = match count {
+                0 => parser.finish(),
+                n if n > 0 => parser.feed(&buf[..n as usize]),
+                _ => {
+                    let err = io::Error::last_os_error();
+                    if err.kind() == io::ErrorKind::Interrupted ||
+                       err.kind() == io::ErrorKind::WouldBlock {
+                        continue;
+                    }
+                    return Err(err);
+                }
+            };
+
+            for update in updates.into_iter() {
+                match update {
+                    Update::Percent(percent) => scale.set(percent),
+                    Update::Message(message) => text.set_text(&message)
+                }
+            }
+
+            if count == 0 {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gauge_parser() {
+        let mut parser = Parser::default();
+        assert_eq!(parser.feed(b"10\n2"), [Update::Percent(10)]);
+        assert_eq!(parser.feed(b"0\nfoo\n150\n"),
+                   [Update::Percent(20), Update::Percent(100)]);
+        assert_eq!(parser.feed(b"XXX\n42\nCopying\nfiles\n"), []);
+        assert_eq!(parser.feed(b"XXX\n"),
+                   [Update::Percent(42),
+                    Update::Message("Copying\nfiles".to_string())]);
+        assert_eq!(parser.feed(b"99"), []);
+        assert_eq!(parser.finish(), [Update::Percent(99)]);
+    }
+}
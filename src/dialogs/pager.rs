@@ -0,0 +1,360 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::callbacks::{Callback,EntryFilter};
+use crate::component::Component;
+use crate::constants::{ENTRY_RETURNEXIT,ENTRY_SCROLL,FLAG_SCROLL};
+use crate::constants::{KEY_ENTER,KEY_ESCAPE};
+use crate::form::{ExitReason,Form};
+use crate::private::funcs::entry_edit;
+use crate::widgets::{Entry,Label,Listbox};
+
+const HELP: &str = "/:Search  n/N:Next/Previous  q:Close";
+const NOT_FOUND: &str = "Pattern not found";
+
+///
+/// A dialog for viewing text, such as logs or licenses, one screen at a
+/// time.
+///
+/// The text is scrolled with the arrow keys, `PgUp`, `PgDn`, `Home` and
+/// `End`. Pressing `/` searches the text as a pattern is typed, moving
+/// the highlighted line to the first line containing it. `n` and `N`
+/// move to the next and previous matching lines. Searches are case
+/// insensitive. The current line number is displayed on the bottom line
+/// of the window. `q` or `Enter` closes the dialog.
+///
+/// The text is displayed in a `Listbox` rather than a scrolling `Textbox`.
+/// newt provides no means of reading or setting the scroll position of a
+/// `Textbox`, so it can't move to a matching line or highlight it.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::dialogs::Pager;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///
+///     let mut pager = Pager::open("License", "COPYING").unwrap();
+///     pager.set_line_numbers(true);
+///     pager.run();
+///     newt::finished();
+/// }
+/// ```
+///
+#[derive(Clone,Debug)]
+pub struct Pager {
+    title: String,
+    lines: Vec<String>,
+    line_numbers: bool,
+    width: i32,
+    height: i32
+}
+
+impl Pager {
+    ///
+    /// Create a new `Pager` displaying `text`.
+    ///
+    /// * `title` - The title of the dialog window.
+    /// * `text` - The text to display.
+    ///
+    pub fn new(title: &str, text: &str) -> Pager {
+        let mut lines: Vec<String> = text.lines().map(expand_tabs).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+
+        Pager {
+            title: title.to_string(),
+            lines,
+            line_numbers: false,
+            width: 0,
+            height: 0
+        }
+    }
+
+    ///
+    /// Create a new `Pager` displaying the contents of a file.
+    ///
+    /// * `title` - The title of the dialog window.
+    /// * `path` - The path of the file to display.
+    ///
+    /// `Returns` an error if the file can't be read.
+    ///
+    pub fn open<P: AsRef<Path>>(title: &str, path: P) -> io::Result<Pager> {
+        let data = fs::read(path)?;
+        Ok(Pager::new(title, &String::from_utf8_lossy(&data)))
+    }
+
+    ///
+    /// Display line numbers before each line.
+    ///
+    /// * `line_numbers` - Whether line numbers are displayed.
+    ///
+    pub fn set_line_numbers(&mut self, line_numbers: bool) {
+        self.line_numbers = line_numbers;
+    }
+
+    ///
+    /// Set the size of the `Pager` window. By default the window fills
+    /// most of the screen.
+    ///
+    /// * `width` - The width of the window.
+    /// * `height` - The height of the window, at least `2`.
+    ///
+    pub fn set_size(&mut self, width: i32, height: i32) {
+        self.width = width.max(10);
+        self.height = height.max(2);
+    }
+
+    ///
+    /// Get the number of lines of text.
+    ///
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    ///
+    /// `Returns` `true` if there is no text to display.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    ///
+    /// Find a line containing `pattern`, ignoring case. The search wraps
+    /// around the end or beginning of the text.
+    ///
+    /// * `pattern` - The text to search for.
+    /// * `from` - The index of the line to start searching from.
+    /// * `forward` - Search forward through the text if `true`, otherwise
+    ///   backward.
+    ///
+    /// `Returns` the index of the matching line.
+    ///
+    pub fn find(&self, pattern: &str, from: usize, forward: bool)
+          -> Option<usize> {
+        if pattern.is_empty() {
+            return None;
+        }
+
+        let pattern = pattern.to_lowercase();
+        let count = self.lines.len();
+        let from = from % count;
+        (0..count)
+            .map(|i| if forward { (from + i) % count }
+                     else { (from + count - i) % count })
+            .find(|&i| self.lines[i].to_lowercase().contains(&pattern))
+    }
+
+    ///
+    /// Display the `Pager`.
+    ///
+    /// `Returns` `true` if the `Pager` was closed with `q` or `Enter`,
+    /// or `false` if it was closed with `Escape`.
+    ///
+    pub fn run(&self) -> bool {
+        let (cols, rows) = crate::get_screen_size();
+        let width = if self.width > 0 { self.width } else { cols - 10 };
+        let height = if self.height > 0 { self.height } else { rows - 6 };
+        let width = width.min(cols - 2).max(10);
+        let height = height.min(rows - 2).max(2);
+
+        let title = Some(self.title.as_str());
+        if crate::centered_window(width as u32, height as u32, title).is_err()
+        {
+            return false;
+        }
+        let rv = self.run_window(width, height);
+        crate::pop_window();
+        rv
+    }
+
+    fn run_window(&self, width: i32, height: i32) -> bool {
+        let listbox: Listbox<usize> = Listbox::new(0, 0, height - 1,
+                                                   FLAG_SCROLL);
+        listbox.set_width(width);
+        let digits = self.lines.len().to_string().len();
+        for (i, line) in self.lines.iter().enumerate() {
+            let text = if self.line_numbers {
+                format!("{:>w$} {}", i + 1, line, w = digits)
+            } else {
+                line.clone()
+            };
+            listbox.append_entry(&text, i + 1).unwrap();
+        }
+
+        let message = RefCell::new(HELP);
+        let label = Label::new(0, height - 1, "");
+        let update = || {
+            let line = listbox.get_current().unwrap_or(1);
+            let text = status(&message.borrow(), line, self.lines.len(),
+                              width as usize);
+            label.set_text(&text);
+        };
+        update();
+
+        let mut form = Form::new(None, 0);
+        form.add_components(&[&listbox, &label]).unwrap();
+        for key in ['/', 'n', 'N', 'q'].iter() {
+            form.add_hot_key(*key as i32);
+        }
+        form.add_hot_key(KEY_ENTER);
+        form.add_hot_key(KEY_ESCAPE);
+
+        let mut f = |_c: &dyn Component, _data: Option<&()>| update();
+        let _callback = Callback::new(&listbox, None, &mut f);
+
+        let mut pattern = String::new();
+        loop {
+            let key = match form.run() {
+                Ok(ExitReason::HotKey(key)) => key,
+                _ => return false
+            };
+
+            let current = listbox.get_current().unwrap_or(1) - 1;
+            let count = self.lines.len();
+            let found = match key {
+                KEY_ESCAPE => return false,
+                KEY_ENTER => return true,
+                _ if key == 'q' as i32 => return true,
+                _ if key == '/' as i32 => {
+                    match self.search(&listbox, &label, width, height) {
+                        Some(text) => pattern = text,
+                        None => {
+                            // Return to the line the search started from.
+                            listbox.set_current(current as i32);
+                            *message.borrow_mut() = HELP;
+                            update();
+                            continue;
+                        }
+                    }
+                    self.find(&pattern, current, true)
+                },
+                _ if key == 'n' as i32 =>
+                    self.find(&pattern, current + 1, true),
+                _ if key == 'N' as i32 =>
+                    self.find(&pattern, current + count - 1, false),
+                _ => continue
+            };
+
+            match found {
+                Some(line) => {
+                    listbox.set_current(line as i32);
+                    *message.borrow_mut() = HELP;
+                },
+                None if pattern.is_empty() => {
+                    listbox.set_current(current as i32);
+                    *message.borrow_mut() = HELP;
+                },
+                None => {
+                    listbox.set_current(current as i32);
+                    *message.borrow_mut() = NOT_FOUND;
+                }
+            }
+            update();
+        }
+    }
+
+    //
+    // Read a search pattern on the bottom line of the window, moving to
+    // the first matching line as it's typed. `Returns` `None` if the
+    // search is cancelled with `Escape`.
+    //
+    fn search(&self, listbox: &Listbox<usize>, label: &Label, width: i32,
+              height: i32) -> Option<String> {
+        let origin = listbox.get_current().unwrap_or(1) - 1;
+        label.set_text(&format!("{:w$}", "/", w = width as usize));
+
+        let entry = Entry::new(1, height - 1, None, width - 1,
+                               ENTRY_SCROLL | ENTRY_RETURNEXIT);
+        let mut form = Form::new(None, 0);
+        form.add_component(&entry).unwrap();
+        form.add_hot_key(KEY_ESCAPE);
+
+        let _filter = EntryFilter::new(&entry, None,
+            |entry: &Entry, _data: Option<&()>, ch, cursor| {
+                if let Some(text) = entry_edit(&entry.get_text(), ch, cursor)
+                {
+                    let line = self.find(&text, origin, true)
+                        .unwrap_or(origin);
+                    listbox.set_current(line as i32);
+                }
+                ch
+            });
+
+        match form.run() {
+            Ok(ExitReason::HotKey(KEY_ESCAPE)) | Err(_) => None,
+            Ok(_) => Some(entry.get_text())
+        }
+    }
+}
+
+//
+// Replace tabs with spaces up to the next multiple of eight columns.
+//
+fn expand_tabs(line: &str) -> String {
+    let mut expanded = String::with_capacity(line.len());
+    for ch in line.chars() {
+        match ch {
+            '\t' => {
+                let column = expanded.chars().count();
+                expanded.push_str(&" ".repeat(8 - column % 8));
+            },
+            '\r' => (),
+            _ => expanded.push(ch)
+        }
+    }
+    expanded
+}
+
+//
+// Format the bottom line of the window, showing `message` on the left and
+// the position within the text on the right.
+//
+fn status(message: &str, line: usize, count: usize, width: usize)
+      -> String {
+    let position = format!("{}/{} {:>3}%", line, count, line * 100 / count);
+    let space = width.saturating_sub(position.len() + 1);
+    let message: String = message.chars().take(space).collect();
+    format!("{:<space$} {}", message, position, space = space)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pager_expand_tabs() {
+        assert_eq!(expand_tabs("a\tb"), "a       b");
+        assert_eq!(expand_tabs("\t\r"), "        ");
+    }
+
+    #[test]
+    fn pager_status() {
+        assert_eq!(status("help", 1, 4, 20), "help        1/4  25%");
+        assert_eq!(status("help", 4, 4, 10), "h 4/4 100%");
+    }
+}
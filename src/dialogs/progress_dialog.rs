@@ -0,0 +1,364 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::cell::RefCell;
+use std::io::{ErrorKind,Read,Write};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixStream;
+use std::sync::{Arc,Mutex};
+use std::sync::atomic::{AtomicBool,Ordering};
+use std::time::{Duration,Instant};
+
+use crate::form::{ExitReason,FDFlags,Form};
+use crate::widgets::{Button,Label,Scale};
+
+const WIDTH: i32 = 50;
+const HEIGHT: i32 = 9;
+const TIMER: i32 = 500;
+
+///
+/// A flag shared between a [`ProgressDialog`] and the threads reporting
+/// to it. The flag is set when the user presses the dialog's `Cancel`
+/// button.
+///
+#[derive(Clone,Debug,Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    ///
+    /// Create a new `CancelToken`.
+    ///
+    pub fn new() -> CancelToken {
+        CancelToken(Arc::new(AtomicBool::new(false)))
+    }
+
+    ///
+    /// Set the cancellation flag.
+    ///
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    ///
+    /// `Returns` `true` if the cancellation flag has been set.
+    ///
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    done: u64,
+    total: u64,
+    message: String
+}
+
+struct Shared {
+    state: Mutex<State>,
+    cancel: CancelToken
+}
+
+///
+/// A handle for reporting progress to a [`ProgressDialog`] from any
+/// thread.
+///
+/// The dialog closes once every `ProgressReporter` created by it has
+/// been dropped.
+///
+#[derive(Clone)]
+pub struct ProgressReporter {
+    shared: Arc<Shared>,
+    writer: Arc<UnixStream>
+}
+
+impl ProgressReporter {
+    ///
+    /// Report progress to the `ProgressDialog`.
+    ///
+    /// * `done` - The amount of work done.
+    /// * `total` - The total amount of work.
+    /// * `message` - The message displayed above the progress bar.
+    ///
+    pub fn set(&self, done: u64, total: u64, message: &str) {
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            state.done = done;
+            state.total = total;
+            if state.message != message {
+                state.message = message.to_string();
+            }
+        }
+
+        // Wake the dialog. A full socket already has a wake up pending.
+        let _ = (&*self.writer).write(&[0]);
+    }
+
+    ///
+    /// `Returns` `true` if the user has cancelled the `ProgressDialog`.
+    ///
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancel.is_cancelled()
+    }
+
+    ///
+    /// Get the `ProgressDialog`'s [`CancelToken`].
+    ///
+    pub fn cancel_token(&self) -> CancelToken {
+        self.shared.cancel.clone()
+    }
+}
+
+///
+/// A dialog displaying the progress of work done by other threads.
+///
+/// The dialog contains a message, a progress bar, the percentage done
+/// with an estimate of the time remaining, and a `Cancel` button.
+/// Progress is reported through [`ProgressReporter`]s which may be sent
+/// to other threads. The dialog is redrawn when progress is reported
+/// and closes when every `ProgressReporter` has been dropped, or when
+/// the user presses `Cancel`.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use std::thread;
+/// use std::time::Duration;
+/// use newt::dialogs::ProgressDialog;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///
+///     let dialog = ProgressDialog::new("Copying", "Starting...");
+///     let reporter = dialog.reporter();
+///     let worker = thread::spawn(move || {
+///         for i in 0..=100 {
+///             if reporter.is_cancelled() {
+///                 break;
+///             }
+///             reporter.set(i, 100, &format!("Copying file {}", i));
+///             thread::sleep(Duration::from_millis(50));
+///         }
+///     });
+///
+///     let completed = dialog.run();
+///     worker.join().unwrap();
+///     newt::finished();
+///
+///     println!("Completed: {}", completed);
+/// }
+/// ```
+///
+pub struct ProgressDialog {
+    title: String,
+    shared: Arc<Shared>,
+    reader: UnixStream,
+    writer: RefCell<Option<Arc<UnixStream>>>
+}
+
+impl ProgressDialog {
+    ///
+    /// Create a new `ProgressDialog`.
+    ///
+    /// * `title` - The title of the dialog window.
+    /// * `message` - The message initially displayed.
+    ///
+    pub fn new(title: &str, message: &str) -> ProgressDialog {
+        let (reader, writer) = UnixStream::pair()
+            .expect("Unable to create progress socket.");
+        reader.set_nonblocking(true).unwrap();
+        writer.set_nonblocking(true).unwrap();
+
+        let state = State {
+            message: message.to_string(),
+            ..Default::default()
+        };
+
+        ProgressDialog {
+            title: title.to_string(),
+            shared: Arc::new(Shared {
+                state: Mutex::new(state),
+                cancel: CancelToken::new()
+            }),
+            reader,
+            writer: RefCell::new(Some(Arc::new(writer)))
+        }
+    }
+
+    ///
+    /// Create a new [`ProgressReporter`] for the `ProgressDialog`.
+    ///
+    /// Reporters must be created before the dialog is run.
+    ///
+    pub fn reporter(&self) -> ProgressReporter {
+        let writer = self.writer.borrow().clone()
+            .expect("ProgressDialog has already been run.");
+        ProgressReporter { shared: self.shared.clone(), writer }
+    }
+
+    ///
+    /// Get the `ProgressDialog`'s [`CancelToken`].
+    ///
+    pub fn cancel_token(&self) -> CancelToken {
+        self.shared.cancel.clone()
+    }
+
+    ///
+    /// Get the progress last reported.
+    ///
+    /// `Returns` a tuple in the form of `(done, total)`.
+    ///
+    pub fn progress(&self) -> (u64, u64) {
+        let state = self.shared.state.lock().unwrap();
+        (state.done, state.total)
+    }
+
+    ///
+    /// Get the message last reported.
+    ///
+    pub fn message(&self) -> String {
+        self.shared.state.lock().unwrap().message.clone()
+    }
+
+    ///
+    /// Display the `ProgressDialog` until every [`ProgressReporter`] has
+    /// been dropped or the user presses `Cancel`.
+    ///
+    /// `Returns` `false` if the dialog was cancelled.
+    ///
+    pub fn run(&self) -> bool {
+        // Drop the dialog's own writer so the socket reaches the end of
+        // file once the last reporter is dropped.
+        self.writer.borrow_mut().take();
+
+        let width = WIDTH as u32;
+        let height = HEIGHT as u32;
+        if crate::centered_window(width, height, Some(&self.title)).is_err()
+        {
+            return false;
+        }
+        let completed = self.run_window();
+        crate::pop_window();
+        completed
+    }
+
+    fn run_window(&self) -> bool {
+        let message = Label::new(1, 0, "");
+        let scale = Scale::new(1, 2, WIDTH - 2, 100);
+        let status = Label::new(1, 3, "");
+        let cancel = Button::new(WIDTH / 2 - 5, 5, "Cancel");
+
+        let mut form = Form::new(None, 0);
+        if form.add_components(&[&message, &scale, &status, &cancel])
+            .is_err() {
+            return false;
+        }
+        form.set_cancel_button(&cancel);
+        form.watch_fd(self.reader.as_raw_fd(), FDFlags::Read);
+        form.set_timer(TIMER);
+
+        let start = Instant::now();
+        loop {
+            if self.shared.cancel.is_cancelled() {
+                return false;
+            }
+
+            let (done, total, text) = {
+                let state = self.shared.state.lock().unwrap();
+                (state.done, state.total, state.message.clone())
+            };
+            let width = (WIDTH - 2) as usize;
+            message.set_text(&fit(&text, width));
+            scale.set(percent(done, total));
+            status.set_text(&fit(&status_text(done, total, start.elapsed()),
+                                 width));
+
+            match form.run() {
+                Ok(ExitReason::FDReady(_)) => {
+                    if !self.drain() {
+                        return true;
+                    }
+                },
+                Ok(ExitReason::Timer) => (),
+                _ => {
+                    self.shared.cancel.cancel();
+                    return false;
+                }
+            }
+        }
+    }
+
+    // Read pending wake ups. `Returns` `false` at the end of file.
+    fn drain(&self) -> bool {
+        let mut buf = [0; 64];
+        loop {
+            match (&self.reader).read(&mut buf) {
+                Ok(0) => return false,
+                Ok(_) => (),
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => (),
+                Err(_) => return true
+            }
+        }
+    }
+}
+
+fn percent(done: u64, total: u64) -> u64 {
+    if total == 0 {
+        return 0;
+    }
+    (done.min(total) as u128 * 100 / total as u128) as u64
+}
+
+fn fit(text: &str, width: usize) -> String {
+    let text: String = text.chars().take(width).collect();
+    format!("{:width$}", text, width = width)
+}
+
+//
+// The percentage done followed by the estimated time remaining, based on
+// the rate of progress since the dialog was displayed.
+//
+fn status_text(done: u64, total: u64, elapsed: Duration) -> String {
+    let percent = percent(done, total);
+    if done == 0 || done >= total {
+        return format!("{:3}%", percent);
+    }
+
+    let remaining = elapsed.as_secs_f64() * (total - done) as f64
+        / done as f64;
+    let secs = remaining.round() as u64;
+    format!("{:3}%  ETA {}:{:02}:{:02}", percent, secs / 3600,
+            secs / 60 % 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_status_text() {
+        let elapsed = Duration::from_secs(30);
+        assert_eq!(status_text(0, 100, elapsed), "  0%");
+        assert_eq!(status_text(25, 100, elapsed), " 25%  ETA 0:01:30");
+        assert_eq!(status_text(100, 100, elapsed), "100%");
+        assert_eq!(status_text(5, 0, elapsed), "  0%");
+        assert_eq!(status_text(1, 1000, elapsed), "  0%  ETA 8:19:30");
+    }
+}
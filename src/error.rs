@@ -0,0 +1,70 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+//!
+//! Errors returned by newt `Component`s.
+//!
+use std::error;
+use std::fmt;
+
+///
+/// Errors returned by newt `Component`s.
+///
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum Error {
+    ///
+    /// The `Component` has been destroyed along with the
+    /// [`Form`][form] it was added to.
+    ///
+    /// [form]: crate::form::Form
+    ///
+    Destroyed,
+
+    ///
+    /// An index number, key or [`TreeHandle`][handle] doesn't refer to an
+    /// item of the `Component`.
+    ///
+    /// [handle]: crate::widgets::TreeHandle
+    ///
+    InvalidItem,
+
+    ///
+    /// The number of cells in a row doesn't match the number of columns
+    /// of a [`Table`][table].
+    ///
+    /// [table]: crate::widgets::Table
+    ///
+    CellCount
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Destroyed => {
+                write!(f, "Component has already been destroyed")
+            },
+            Error::InvalidItem => write!(f, "No such item"),
+            Error::CellCount => {
+                write!(f, "Number of cells doesn't match the columns")
+            }
+        }
+    }
+}
+
+impl error::Error for Error { }
@@ -20,14 +20,18 @@
 //!
 //! Displays `Component`s and accepts user input.
 //!
+use std::any::Any;
 use std::cell::Cell;
 use std::ops::Drop;
 use std::os::unix::io::RawFd;
 use std::ptr;
+use std::rc::Rc;
 
 use newt_sys::*;
 use crate::component::Component;
 use crate::callbacks::HelpCallback;
+use crate::constants::{KEY_ENTER,KEY_ESCAPE};
+use crate::private::destroyed;
 use crate::widgets::VerticalScrollbar;
 
 mod exit_reason;
@@ -59,10 +63,40 @@ pub enum FDFlags {
     Except = NEWT_FD_EXCEPT as isize
 }
 
+///
+/// A handle to a `Component` owned by a `Form`.
+/// Returned by [`Form.take_component()`][take_component].
+///
+/// [take_component]: crate::form::Form::take_component
+///
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub struct ComponentHandle(usize);
+
+trait OwnedComponent: Any + Component {
+    fn as_any(&self) -> &dyn Any;
+    fn as_dyn_component(&self) -> &dyn Component;
+}
+
+impl<T: Component + 'static> OwnedComponent for T {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_dyn_component(&self) -> &dyn Component { self }
+}
+
 #[derive(Component)]
 struct BaseComponent {
     co: Cell<newtComponent>,
-    added_to_parent: Cell<bool>
+    added_to_parent: Cell<bool>,
+    destroyed: Rc<Cell<bool>>
+}
+
+impl BaseComponent {
+    fn new(co: newtComponent) -> BaseComponent {
+        BaseComponent {
+            co: Cell::new(co),
+            added_to_parent: Cell::new(true),
+            destroyed: destroyed::flag(co)
+        }
+    }
 }
 
 ///
@@ -71,7 +105,10 @@ struct BaseComponent {
 pub struct Form<'a>
 {
     pub(crate) co: newtComponent,
-    components: Vec<&'a dyn Component>
+    components: Vec<&'a dyn Component>,
+    owned: Vec<Box<dyn OwnedComponent>>,
+    default_button: Cell<newtComponent>,
+    cancel_button: Cell<newtComponent>
 }
 
 impl<'a> Drop for Form<'a>
@@ -83,6 +120,10 @@ impl<'a> Drop for Form<'a>
         for component in self.components.iter() {
             component.nullify();
         }
+
+        for component in self.owned.iter() {
+            component.nullify();
+        }
     }
 }
 
@@ -100,7 +141,10 @@ impl<'a> Form<'a>
 
         Form {
             co: unsafe { newtForm(scrollbar, ptr::null_mut(), flags) },
-            components: Vec::new()
+            components: Vec::new(),
+            owned: Vec::new(),
+            default_button: Cell::new(ptr::null_mut()),
+            cancel_button: Cell::new(ptr::null_mut())
         }
     }
 
@@ -120,7 +164,10 @@ impl<'a> Form<'a>
     pub(crate) fn new_co(co: newtComponent) -> Form<'a> {
         Form {
             co: co,
-            components: Vec::new()
+            components: Vec::new(),
+            owned: Vec::new(),
+            default_button: Cell::new(ptr::null_mut()),
+            cancel_button: Cell::new(ptr::null_mut())
         }
     }
 
@@ -134,9 +181,19 @@ impl<'a> Form<'a>
     ///
     /// Add a `Component` to the `Form` to be displayed when the `Form` is run.
     ///
+    /// `Grid`s, including [composite][composite] components, have all of
+    /// their children added to the `Form`.
+    ///
+    /// [composite]: crate::grid::Composite
+    ///
     pub fn add_component(&mut self, component: &'a dyn Component)
         -> Result<(), &'static str>
     {
+        #[cfg(feature = "asm")]
+        if let Some(grid) = component.as_grid() {
+            return grid.add_to_form(self);
+        }
+
         component.add_to_parent()?;
         self.components.push(component);
         unsafe { newtFormAddComponent(self.co, component.co()); }
@@ -158,13 +215,39 @@ impl<'a> Form<'a>
     ///
     /// Add a `Component` to the `Form`, taking ownership.
     ///
+    /// The `Component` can be accessed afterwards using
+    /// [`Form.component()`][component] with the returned handle.
+    ///
+    /// [component]: crate::form::Form::component
+    ///
     pub fn take_component<T>(&mut self, component: T)
-        -> Result<(), &'static str>
-        where T: Component
+        -> Result<ComponentHandle, &'static str>
+        where T: Component + 'static
     {
+        #[cfg(feature = "asm")]
+        if component.as_grid().is_some() {
+            return Err("A Grid can not be owned by a Form.");
+        }
+
         component.add_to_parent()?;
         unsafe { newtFormAddComponent(self.co, component.co()); }
-        Ok(())
+        self.owned.push(Box::new(component));
+        Ok(ComponentHandle(self.owned.len() - 1))
+    }
+
+    ///
+    /// Get a `Component` owned by the `Form`.
+    ///
+    /// * `handle` - The handle returned by `take_component()`.
+    ///
+    /// `Returns` the `Component` or `None` if the handle does not refer to
+    /// a `Component` of type `T`.
+    ///
+    pub fn component<T>(&self, handle: ComponentHandle) -> Option<&T>
+        where T: Component + 'static
+    {
+        self.owned.get(handle.0)
+            .and_then(|component| component.as_any().downcast_ref::<T>())
     }
 
     ///
@@ -199,6 +282,40 @@ impl<'a> Form<'a>
         unsafe { newtFormAddHotKey(self.co, key); }
     }
 
+    ///
+    /// Set the default button of the `Form`. The default button is
+    /// reported as the exit `Component` when `Enter` is pressed.
+    ///
+    /// Pressing `Enter` while a button, an `Entry` with the
+    /// [ENTRY_RETURNEXIT][entry_returnexit] flag, or a non-empty `Listbox`
+    /// with the [LISTBOX_RETURNEXIT][listbox_returnexit] flag has focus
+    /// still reports that component instead.
+    ///
+    /// * `button` - The button to activate when `Enter` is pressed.
+    ///
+    /// [entry_returnexit]: crate::constants::ENTRY_RETURNEXIT
+    /// [listbox_returnexit]: crate::constants::LISTBOX_RETURNEXIT
+    ///
+    pub fn set_default_button(&self, button: &dyn Component) {
+        if self.default_button.get().is_null() {
+            self.add_hot_key(KEY_ENTER);
+        }
+        self.default_button.set(button.co());
+    }
+
+    ///
+    /// Set the cancel button of the `Form`. The cancel button is
+    /// reported as the exit `Component` when `Escape` is pressed.
+    ///
+    /// * `button` - The button to activate when `Escape` is pressed.
+    ///
+    pub fn set_cancel_button(&self, button: &dyn Component) {
+        if self.cancel_button.get().is_null() {
+            self.add_hot_key(KEY_ESCAPE);
+        }
+        self.cancel_button.set(button.co());
+    }
+
     ///
     /// Add an exit timer to the `Form`. The `Form` will stop running
     /// when the timer times out.
@@ -230,12 +347,7 @@ impl<'a> Form<'a>
                 return None;
             }
 
-            let component = Box::new(BaseComponent {
-                co: Cell::new(co),
-                added_to_parent: Cell::new(true)
-            });
-
-            Some(component)
+            Some(Box::new(BaseComponent::new(co)))
         }
     }
 
@@ -266,7 +378,7 @@ impl<'a> Form<'a>
     /// the user.
     ///
     pub fn run(&self) -> Result<ExitReason, ()> {
-        use self::ExitReason::{HotKey,Component,FDReady,Timer};
+        use self::ExitReason::{Component,FDReady,Timer};
 
         let mut es = newtExitStruct {
             reason: NEWT_EXIT_HOTKEY,
@@ -276,12 +388,9 @@ impl<'a> Form<'a>
         unsafe {
             newtFormRun(self.co, &mut es);
             match es.reason {
-                NEWT_EXIT_HOTKEY => Ok(HotKey(es.u.key)),
+                NEWT_EXIT_HOTKEY => Ok(self.hot_key_exit(es.u.key)),
                 NEWT_EXIT_COMPONENT => Ok(
-                    Component(Box::new(BaseComponent {
-                                       co: Cell::new(es.u.co),
-                                       added_to_parent: Cell::new(true)
-                    }))
+                    Component(Box::new(BaseComponent::new(es.u.co)))
                 ),
                 NEWT_EXIT_FDREADY => Ok(FDReady(es.u.watch)),
                 NEWT_EXIT_TIMER => Ok(Timer),
@@ -291,6 +400,19 @@ impl<'a> Form<'a>
         }
     }
 
+    //
+    // Report the default or cancel button as the exit component when
+    // `Enter` or `Escape` is pressed.
+    //
+    fn hot_key_exit(&self, key: i32) -> ExitReason {
+        let current = unsafe { newtFormGetCurrent(self.co) };
+        let current = self.components.iter().copied()
+            .chain(self.owned.iter().map(|c| c.as_dyn_component()))
+            .find(|c| c.co() == current);
+        hot_key_reason(key, current, self.default_button.get(),
+                       self.cancel_button.get())
+    }
+
     ///
     /// Redraw the `Form`.
     ///
@@ -298,3 +420,102 @@ impl<'a> Form<'a>
         unsafe { newtDrawForm(self.co); }
     }
 }
+
+//
+// Decide the exit reason for the hot key `key`. Newt checks hot keys
+// before the focused component sees the key, so when `Enter` is pressed
+// a focused component `current` that would exit the form on `Enter` is
+// reported instead of the default button. `Escape` reports the cancel
+// button. Otherwise the hot key itself is reported.
+//
+fn hot_key_reason(key: i32, current: Option<&dyn Component>,
+                  default: newtComponent, cancel: newtComponent)
+      -> ExitReason {
+    let co = match key {
+        KEY_ENTER if !default.is_null() => match current {
+            Some(current) if current.return_exit() => current.co(),
+            _ => default
+        },
+        KEY_ESCAPE if !cancel.is_null() => cancel,
+        _ => return ExitReason::HotKey(key)
+    };
+
+    ExitReason::Component(Box::new(BaseComponent::new(co)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{ENTRY_RETURNEXIT,KEY_F12,LISTBOX_RETURNEXIT};
+    use crate::widgets::{Button,Entry,Listbox};
+
+    #[test]
+    fn hot_key_reason_entry() {
+        let ok = Button::new(-1, -1, "Ok");
+        let entry = Entry::new(-1, -1, None, 10, ENTRY_RETURNEXIT);
+        let plain = Entry::new(-1, -1, None, 10, 0);
+
+        let reason = hot_key_reason(KEY_ENTER, Some(&entry), ok.co(),
+                                    ptr::null_mut());
+        assert!(reason == entry);
+        let reason = hot_key_reason(KEY_ENTER, Some(&plain), ok.co(),
+                                    ptr::null_mut());
+        assert!(reason == ok);
+        let reason = hot_key_reason(KEY_ENTER, None, ok.co(),
+                                    ptr::null_mut());
+        assert!(reason == ok);
+    }
+
+    #[test]
+    fn hot_key_reason_listbox() {
+        let ok = Button::new(-1, -1, "Ok");
+        let listbox: Listbox = Listbox::new(-1, -1, 5, LISTBOX_RETURNEXIT);
+
+        let reason = hot_key_reason(KEY_ENTER, Some(&listbox), ok.co(),
+                                    ptr::null_mut());
+        assert!(reason == ok);
+        listbox.append_entry("item", 1).unwrap();
+        let reason = hot_key_reason(KEY_ENTER, Some(&listbox), ok.co(),
+                                    ptr::null_mut());
+        assert!(reason == listbox);
+    }
+
+    #[test]
+    fn hot_key_reason_buttons() {
+        let ok = Button::new(-1, -1, "Ok");
+        let cancel = Button::new(-1, -1, "Cancel");
+
+        let reason = hot_key_reason(KEY_ENTER, Some(&cancel), ok.co(),
+                                    cancel.co());
+        assert!(reason == cancel);
+        let reason = hot_key_reason(KEY_ESCAPE, Some(&ok), ok.co(),
+                                    cancel.co());
+        assert!(reason == cancel);
+        let reason = hot_key_reason(KEY_ESCAPE, None, ok.co(),
+                                    ptr::null_mut());
+        assert!(reason == KEY_ESCAPE);
+        let reason = hot_key_reason(KEY_ENTER, None, ptr::null_mut(),
+                                    cancel.co());
+        assert!(reason == KEY_ENTER);
+        let reason = hot_key_reason(KEY_F12, None, ok.co(), cancel.co());
+        assert!(reason == KEY_F12);
+    }
+
+    #[test]
+    fn hot_key_reason_destroyed() {
+        let ok = Button::new(-1, -1, "Ok");
+        let reason = hot_key_reason(KEY_ENTER, None, ok.co(),
+                                    ptr::null_mut());
+        {
+            let mut form = Form::new(None, 0);
+            form.add_component(&ok).unwrap();
+        }
+
+        match reason {
+            ExitReason::Component(component) => {
+                assert!(component.is_destroyed())
+            },
+            _ => panic!("Unexpected exit reason.")
+        }
+    }
+}
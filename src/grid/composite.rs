@@ -0,0 +1,246 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+//!
+//! User-defined widgets composed of multiple `Component`s.
+//!
+//! A type implementing [`Composite`] groups several widgets together
+//! behind a [`Layout`] arranging them. Composites implement [`Component`]
+//! and [`Grid`] so they can be added to a [`Form`][form] or placed
+//! within another `Grid` as a single unit.
+//!
+//! [form]: crate::form::Form
+//!
+//! ## Example
+//! ```rust no_run
+//! extern crate newt;
+//! use newt::grid::*;
+//! use newt::prelude::*;
+//!
+//! struct LabeledEntry {
+//!     label: Label,
+//!     entry: Entry,
+//!     layout: Layout
+//! }
+//!
+//! impl LabeledEntry {
+//!     fn new(text: &str, width: i32) -> LabeledEntry {
+//!         let label = Label::new(0, 0, text);
+//!         let entry = Entry::new(0, 0, None, width, 0);
+//!
+//!         let mut layout = Layout::new(2, 1);
+//!         layout.set_field(0, 0, &label, 0, 0, 1, 0, 0, 0);
+//!         layout.set_field(1, 0, &entry, 0, 0, 0, 0, 0, 0);
+//!         LabeledEntry { label, entry, layout }
+//!     }
+//! }
+//!
+//! impl Parent for LabeledEntry {
+//!     fn children(&self) -> Vec<&dyn Component> {
+//!         vec![&self.label, &self.entry]
+//!     }
+//! }
+//!
+//! impl Composite for LabeledEntry {
+//!     fn layout(&self) -> &Layout {
+//!         &self.layout
+//!     }
+//! }
+//!
+//! pub fn main() {
+//!     newt::init().unwrap();
+//!     newt::cls();
+//!
+//!     let name = LabeledEntry::new("Name:", 20);
+//!     let email = LabeledEntry::new("Email:", 20);
+//!     let ok = Button::new(0, 0, "Ok");
+//!
+//!     let mut grid = Grid::new(1, 3);
+//!     grid.set_field(0, 0, &name, 0, 0, 0, 0, 0, 0);
+//!     grid.set_field(0, 1, &email, 0, 1, 0, 0, 0, 0);
+//!     grid.set_field(0, 2, &ok, 0, 1, 0, 0, 0, 0);
+//!     wrapped_window(&grid, "Contact");
+//!
+//!     let mut form = Form::new(None, 0);
+//!     grid.add_to_form(&mut form).unwrap();
+//!     form.run().unwrap();
+//!     newt::finished();
+//!
+//!     println!("Name: {}", name.entry.get_text());
+//!     println!("Email: {}", email.entry.get_text());
+//! }
+//! ```
+//!
+use std::cell::Cell;
+use std::os::raw::c_void;
+use newt_sys::*;
+
+use crate::component::Component;
+use crate::constants::GRID_SUBGRID;
+use crate::grid::traits::{Grid,GridFns,Parent};
+
+use crate::asm::{AsComponent,AsGrid};
+use crate::private::traits::{Child,ComponentPtr,GridElementType};
+
+///
+/// Arranges the `Component`s of a [`Composite`].
+///
+/// Unlike a [`Grid`][grid] a `Layout` does not borrow the `Component`s
+/// placed within it, allowing it to be stored alongside them. The
+/// `Composite` reports its `Component`s via the [`Parent`] trait instead.
+///
+/// [grid]: struct@crate::grid::Grid
+///
+pub struct Layout {
+    co: Cell<newtGrid>,
+    added_to_parent: Cell<bool>,
+    cols: i32,
+    rows: i32
+}
+
+impl Layout {
+    ///
+    /// Create a new `Layout` with the specified columns and rows.
+    ///
+    /// * `cols` - The number of columns the `Layout` should have.
+    /// * `rows` - The number of rows the `Layout` should have.
+    ///
+    pub fn new(cols: i32, rows: i32) -> Layout {
+        assert!(cols > 0, "`cols` must be greater than 0");
+        assert!(rows > 0, "`rows` must be greater than 0");
+
+        Layout {
+            co: unsafe { Cell::new(newtCreateGrid(cols, rows)) },
+            added_to_parent: Cell::new(false),
+            cols, rows
+        }
+    }
+
+    ///
+    /// Add a component or sub-grid to the positon (`col`, `row`) in the
+    /// `Layout`. See [`Grid::set_field()`][set_field].
+    ///
+    /// The `Component` should be owned by the `Composite` and returned
+    /// by its [`Parent::children()`] function.
+    ///
+    /// [set_field]: crate::grid::Grid::set_field
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_field(&mut self, col: i32, row: i32, val: &dyn Component,
+                     pad_left: i32, pad_top: i32, pad_right: i32,
+                     pad_bottom: i32, anchor: i32, flags: i32) {
+
+        if col >= self.cols || row >= self.rows {
+            panic!("Attempting to set a field at an invalid position ({}, {})", col, row);
+        }
+
+        unsafe {
+            newtGridSetField(self.co.get(), col, row,
+                             val.grid_element_type(),
+                             val.co() as *mut c_void, pad_left, pad_top,
+                             pad_right, pad_bottom, anchor, flags);
+        }
+    }
+}
+
+impl Drop for Layout {
+    fn drop(&mut self) {
+        unsafe { newtGridFree(self.co.get(), 0); }
+    }
+}
+
+///
+/// Trait implemented by user-defined widgets composed of multiple
+/// `Component`s.
+///
+/// Implementing `Composite` along with [`Parent`] provides the
+/// [`Component`] and [`Grid`] traits.
+///
+pub trait Composite: Parent {
+    /// Get the `Layout` arranging the composite's `Component`s.
+    fn layout(&self) -> &Layout;
+}
+
+impl<T: Composite> ComponentPtr for T {
+    fn is_null(&self) -> bool {
+        self.layout().co.get().is_null()
+    }
+
+    fn ptr(&self) -> *mut c_void {
+        let ptr = self.layout().co.get();
+        if ptr.is_null() {
+            panic!("Component has already been destroyed!");
+        }
+        ptr as *mut c_void
+    }
+
+    fn co_ptr(&self) -> newtComponent {
+        self.ptr() as newtComponent
+    }
+
+    fn grid_ptr(&self) -> newtGrid {
+        self.ptr() as newtGrid
+    }
+}
+
+impl<T: Composite> Component for T {
+    fn co(&self) -> newtComponent {
+        self.co_ptr()
+    }
+}
+
+impl<T: Composite> AsComponent for T {
+    fn as_component(&self) -> Option<&dyn Component> {
+        Some(self)
+    }
+}
+
+impl<T: Composite> AsGrid for T {
+    fn as_grid(&self) -> Option<&dyn Grid> {
+        Some(self)
+    }
+}
+
+impl<T: Composite> Child for T {
+    fn add_to_parent(&self) -> Result<(), &'static str> {
+        let layout = self.layout();
+        if layout.added_to_parent.get() {
+            return Err("Grid already belongs to a parent.");
+        }
+
+        for child in self.children().iter() {
+            child.add_to_parent()?;
+        }
+        layout.added_to_parent.set(true);
+        Ok(())
+    }
+
+    fn added_to_parent(&self) -> bool {
+        self.layout().added_to_parent.get()
+    }
+}
+
+impl<T: Composite> GridElementType for T {
+    fn grid_element_type(&self) -> u32 {
+        GRID_SUBGRID
+    }
+}
+
+impl<T: Composite> Grid for T { }
+impl<T: Composite> GridFns for T { }
@@ -87,11 +87,18 @@
 //!
 
 #![cfg(feature = "asm")]
+pub mod composite;
 pub mod traits;
 pub use crate::asm::grid::*;
 
+#[doc(inline)]
+pub use self::composite::{Composite,Layout};
+
 #[doc(inline)]
 pub use self::traits::Parent;
 
 #[doc(inline)]
 pub use self::traits::GridFns;
+
+#[doc(inline)]
+pub use newt_proc_macros::Grid;
@@ -18,10 +18,11 @@
 //
 
 use crate::component::Component;
+use crate::error::Error;
 use crate::form::Form;
 use crate::widgets::WidgetFns;
 
-use crate::private::traits::{Child,ComponentPtr,Nullify};
+use crate::private::traits::{Child,ComponentPtr,Nullify,ReturnExit};
 use crate::asm::*;
 use newt_sys::*;
 
@@ -48,6 +49,8 @@ impl <T: Grid> Nullify for T {
     fn nullify(&self) { }
 }
 
+impl <T: Grid> ReturnExit for T { }
+
 ///
 /// Trait implemented by `Grid`s that returns all child components from
 /// themselves and sub-Grids.
@@ -83,6 +86,7 @@ pub trait GridFns: AsComponent + Child + ComponentPtr + Parent {
     fn get_size(&self) -> (i32, i32) {
         let mut width: i32 = 0;
         let mut height: i32 = 0;
+        check(self);
         unsafe {
             newtGridGetSize(self.grid_ptr(), &mut width, &mut height);
         }
@@ -96,8 +100,47 @@ pub trait GridFns: AsComponent + Child + ComponentPtr + Parent {
     /// * `top` - The top-most position of the `Grid`.
     ///
     fn place(&self, left: i32, top: i32) {
+        check(self);
         unsafe {
             newtGridPlace(self.grid_ptr(), left, top);
         }
     }
+
+    ///
+    /// Checked version of [`get_size()`][GridFns::get_size].
+    ///
+    /// `Returns` [`Error::Destroyed`] if the `Grid` has been destroyed.
+    ///
+    fn try_get_size(&self) -> Result<(i32, i32), Error> {
+        if is_destroyed(self) {
+            return Err(Error::Destroyed);
+        }
+        Ok(GridFns::get_size(self))
+    }
+
+    ///
+    /// Checked version of [`place()`][GridFns::place].
+    ///
+    /// `Returns` [`Error::Destroyed`] if the `Grid` has been destroyed.
+    ///
+    fn try_place(&self, left: i32, top: i32) -> Result<(), Error> {
+        if is_destroyed(self) {
+            return Err(Error::Destroyed);
+        }
+        self.place(left, top);
+        Ok(())
+    }
+}
+
+//
+// A `Grid` is destroyed along with the `Component`s it arranges.
+//
+fn is_destroyed<T: Parent + ?Sized>(grid: &T) -> bool {
+    grid.children().iter().any(|child| child.is_destroyed())
+}
+
+fn check<T: Parent + ?Sized>(grid: &T) {
+    if is_destroyed(grid) {
+        panic!("Component has already been destroyed!");
+    }
 }
@@ -67,13 +67,20 @@
 //!           architectures include _x86_, _x86_64_, _arm_, _aarch64_,
 //!           _riscv32_, and _riscv64_.
 //!
+//! - `chrono` - Builds the [`widgets::DateEntry`] widget and the
+//!   [`dialogs::DatePicker`] dialog for editing
+//!   [`chrono`](https://docs.rs/chrono) dates and times.
+//!
+//! - `dialog` - Builds the `newt-dialog` program, a replacement for `whiptail`
+//!   accepting the same command line options.
+//!
 //! - `static` - Builds and links [`newt-sys`][newt_sys] statically against
 //!              its included libraries rather than linking dynamically
 //!              against available system libraries. This is done
 //!              automatically if the required system libraries are
 //!              unavailable.
 //!
-//! [grid]: crate::grid::Grid
+//! [grid]: struct@crate::grid::Grid
 //! [newt_sys]: https://crates.io/crates/newt-sys
 //!
 //! ## License
@@ -96,6 +103,7 @@
 #[macro_use]
 extern crate newt_proc_macros;
 extern crate newt_sys;
+extern crate self as newt;
 
 use std::ffi::{CStr,CString};
 use std::os::raw::{c_char,c_int};
@@ -107,6 +115,9 @@ pub mod asm;
 pub mod callbacks;
 pub mod component;
 pub mod constants;
+pub mod data;
+pub mod dialogs;
+pub mod error;
 pub mod form;
 pub mod grid;
 pub mod prelude;
@@ -119,16 +130,31 @@ pub use self::component::Component;
 #[doc(hidden)]
 pub use self::callbacks::Callback;
 
+#[doc(inline)]
+pub use self::error::Error;
+
 #[doc(hidden)]
 pub use self::windows::win_message;
 #[doc(hidden)]
 pub use self::windows::win_choice;
 #[doc(hidden)]
 pub use self::windows::win_ternary;
+#[doc(hidden)]
+pub use self::windows::win_checklist;
+#[doc(hidden)]
+pub use self::windows::win_radiolist;
 
 #[doc(hidden)]
 pub use self::asm::*;
 
+#[doc(hidden)]
+pub mod __private {
+    pub use newt_sys::{newtComponent,newtComponentDestroy,newtGrid,newtGridFree};
+    pub use crate::private::traits::{Child,ComponentPtr,GridElementType};
+    pub use crate::private::traits::{Nullify,ReturnExit};
+    pub use crate::private::destroyed::set_destroyed;
+}
+
 #[doc(hidden)]
 #[cfg(feature = "asm")]
 pub use self::grid::traits::Grid;
@@ -0,0 +1,280 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::convert::TryFrom;
+use chrono::{Datelike,NaiveDate,NaiveDateTime,Timelike};
+
+use crate::constants::{KEY_BKSPC,KEY_DOWN,KEY_END,KEY_ENTER,KEY_HOME};
+use crate::constants::{KEY_LEFT,KEY_RIGHT,KEY_TAB,KEY_UP};
+use crate::widgets::Entry;
+
+//
+// The format of text edited by a date `Entry`. Each field is a
+// fixed-width run of digits followed by a single separator.
+//
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum DateFormat {
+    Date,
+    DateTime
+}
+
+// (start, width, minimum, maximum) of each field.
+const FIELDS: [(usize, usize, i32, i32); 5] = [
+    (0, 4, 1, 9999),
+    (5, 2, 1, 12),
+    (8, 2, 1, 31),
+    (11, 2, 0, 23),
+    (14, 2, 0, 59)
+];
+
+impl DateFormat {
+    pub fn width(self) -> usize {
+        match self {
+            DateFormat::Date => 10,
+            DateFormat::DateTime => 16
+        }
+    }
+
+    pub fn format(self, datetime: &NaiveDateTime) -> String {
+        match self {
+            DateFormat::Date => datetime.format("%Y-%m-%d").to_string(),
+            DateFormat::DateTime =>
+                datetime.format("%Y-%m-%d %H:%M").to_string()
+        }
+    }
+
+    pub fn parse(self, text: &str) -> Option<NaiveDateTime> {
+        match self {
+            DateFormat::Date => NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()?.and_hms_opt(0, 0, 0),
+            DateFormat::DateTime =>
+                NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M").ok()
+        }
+    }
+
+    fn fields(self) -> &'static [(usize, usize, i32, i32)] {
+        match self {
+            DateFormat::Date => &FIELDS[..3],
+            DateFormat::DateTime => &FIELDS[..]
+        }
+    }
+
+    // Index of the field containing or ending at `cursor`.
+    fn field_at(self, cursor: usize) -> usize {
+        self.fields().iter()
+            .position(|&(start, width, _, _)| cursor <= start + width)
+            .unwrap_or(self.fields().len() - 1)
+    }
+
+    fn is_digit(self, pos: usize) -> bool {
+        self.fields().iter()
+            .any(|&(start, width, _, _)| pos >= start && pos < start + width)
+    }
+
+    // Read the value of each field, using the field's minimum for
+    // fields that aren't numbers.
+    fn values(self, text: &str) -> Vec<i32> {
+        self.fields().iter()
+            .map(|&(start, width, min, _)| {
+                text.get(start..start + width)
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(min)
+            })
+            .collect()
+    }
+
+    fn compose(self, values: &[i32]) -> String {
+        let mut text = format!("{:04}-{:02}-{:02}",
+                               values[0], values[1], values[2]);
+        if self == DateFormat::DateTime {
+            text.push_str(&format!(" {:02}:{:02}", values[3], values[4]));
+        }
+        text
+    }
+}
+
+///
+/// Filter keys entered into a date `Entry`.
+///
+/// Digits overwrite the digit under the cursor, skipping separators.
+/// `Up` and `+` increment the field under the cursor, `Down` and `-`
+/// decrement it. Other printable characters are ignored.
+///
+pub fn filter(entry: &Entry, format: Option<&DateFormat>, ch: char,
+              cursor: i32) -> char {
+    let format = match format {
+        Some(format) => *format,
+        None => return ch
+    };
+    let cursor = usize::try_from(cursor).unwrap_or(0);
+
+    match ch as i32 {
+        KEY_UP => step(entry, format, cursor, 1),
+        KEY_DOWN => step(entry, format, cursor, -1),
+        KEY_BKSPC => key(KEY_LEFT),
+        KEY_LEFT | KEY_RIGHT | KEY_HOME | KEY_END | KEY_ENTER | KEY_TAB =>
+            ch,
+        _ => match ch {
+            '+' => step(entry, format, cursor, 1),
+            '-' => step(entry, format, cursor, -1),
+            '0'..='9' => overwrite(entry, format, cursor, ch),
+            _ => '\0'
+        }
+    }
+}
+
+///
+/// Step the field of `text` containing `cursor` by `delta`, wrapping
+/// months, days, hours and minutes within their range.
+///
+pub fn step_text(format: DateFormat, text: &str, cursor: usize,
+                 delta: i32) -> String {
+    let fields = format.fields();
+    let mut values = format.values(text);
+    let field = format.field_at(cursor);
+    let (_, _, min, max) = fields[field];
+    let max = if field == 2 { days_in_month(values[0], values[1]) }
+              else { max };
+
+    values[field] = if field == 0 {
+        (values[0] + delta).clamp(min, max)
+    } else {
+        (values[field] - min + delta).rem_euclid(max - min + 1) + min
+    };
+    values[2] = values[2].min(days_in_month(values[0], values[1]));
+    format.compose(&values)
+}
+
+///
+/// `Returns` the number of days in `month` of `year`.
+///
+pub fn days_in_month(year: i32, month: i32) -> i32 {
+    let month = month.clamp(1, 12) as u32;
+    let (year, next) = if month == 12 { (year + 1, 1) }
+                       else { (year, month + 1) };
+    NaiveDate::from_ymd_opt(year, next, 1)
+        .and_then(|date| date.pred_opt())
+        .map(|date| date.day() as i32)
+        .unwrap_or(31)
+}
+
+///
+/// `Returns` `datetime` moved by `months`, keeping the day within the
+/// resulting month.
+///
+pub fn add_months(datetime: &NaiveDateTime, months: i32) -> NaiveDateTime {
+    let index = datetime.year() * 12 + datetime.month0() as i32 + months;
+    let year = index.div_euclid(12).clamp(1, 9999);
+    let month = index.rem_euclid(12) + 1;
+    let day = (datetime.day() as i32).min(days_in_month(year, month));
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+        .and_then(|date| date.and_hms_opt(datetime.hour(),
+                                          datetime.minute(), 0))
+        .unwrap_or(*datetime)
+}
+
+fn step(entry: &Entry, format: DateFormat, cursor: usize, delta: i32)
+      -> char {
+    let text = step_text(format, &entry.get_text(), cursor, delta);
+    entry.set_text(&text, false);
+    swallow(entry, cursor)
+}
+
+fn overwrite(entry: &Entry, format: DateFormat, cursor: usize, ch: char)
+      -> char {
+    let text = entry.get_text();
+    let mut chars: Vec<char> = if text.len() == format.width() {
+        text.chars().collect()
+    } else {
+        format.compose(&format.values(&text)).chars().collect()
+    };
+
+    let mut pos = cursor;
+    if pos < chars.len() && !format.is_digit(pos) {
+        pos += 1;
+    }
+    if pos >= chars.len() {
+        return '\0';
+    }
+
+    chars[pos] = ch;
+    pos += 1;
+    if pos < chars.len() && !format.is_digit(pos) {
+        pos += 1;
+    }
+
+    let text: String = chars.into_iter().collect();
+    entry.set_text(&text, false);
+    swallow(entry, pos)
+}
+
+//
+// The `Entry` has already been modified. Return a cursor movement key
+// for the `Entry` to swallow, leaving the cursor at `cursor`. Returning
+// `'\0'` would let the `Form` handle the key instead.
+//
+fn swallow(entry: &Entry, cursor: usize) -> char {
+    if cursor > 0 {
+        entry.set_cursor_position(cursor as i32 - 1);
+        key(KEY_RIGHT)
+    } else {
+        entry.set_cursor_position(1);
+        key(KEY_LEFT)
+    }
+}
+
+fn key(key: i32) -> char {
+    std::char::from_u32(key as u32).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_step_text() {
+        let date = DateFormat::Date;
+        assert_eq!(step_text(date, "2026-12-31", 6, 1), "2026-01-31");
+        assert_eq!(step_text(date, "2026-01-31", 6, 1), "2026-02-28");
+        assert_eq!(step_text(date, "2024-02-29", 2, 1), "2025-02-28");
+        assert_eq!(step_text(date, "2026-02-28", 9, 1), "2026-02-01");
+        assert_eq!(step_text(date, "2026-03-01", 10, -1), "2026-03-31");
+        assert_eq!(step_text(date, "garbage", 0, 1), "0002-01-01");
+
+        let datetime = DateFormat::DateTime;
+        assert_eq!(step_text(datetime, "2026-10-19 23:59", 12, 1),
+                   "2026-10-19 00:59");
+        assert_eq!(step_text(datetime, "2026-10-19 23:00", 16, -1),
+                   "2026-10-19 23:59");
+    }
+
+    #[test]
+    fn date_add_months() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()
+            .and_hms_opt(12, 30, 0).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2026, 2, 28).unwrap()
+            .and_hms_opt(12, 30, 0).unwrap();
+        assert_eq!(add_months(&date, 1), expected);
+
+        let expected = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()
+            .and_hms_opt(12, 30, 0).unwrap();
+        assert_eq!(add_months(&date, -1), expected);
+    }
+}
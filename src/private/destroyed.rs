@@ -0,0 +1,64 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::cell::{Cell,RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use newt_sys::newtComponent;
+
+//
+// Flags shared by every handle to the same newt component, such as the
+// clones returned by `Form::get_current()` or `Radiobutton::get_current()`.
+// Nullifying or destroying a component through one handle sets the flag
+// seen by the others.
+//
+thread_local! {
+    static FLAGS: RefCell<HashMap<usize, Rc<Cell<bool>>>> =
+        RefCell::new(HashMap::new());
+}
+
+//
+// Get the flag shared by the handles to `co`.
+//
+pub fn flag(co: newtComponent) -> Rc<Cell<bool>> {
+    FLAGS.with(|flags| {
+        let mut flags = flags.borrow_mut();
+        flags.entry(co as usize)
+            .or_insert_with(|| Rc::new(Cell::new(false)))
+            .clone()
+    })
+}
+
+//
+// Mark the handles to `co` as destroyed. The entry is removed so that a
+// component later allocated at the same address gets a new flag.
+//
+pub fn set_destroyed(co: newtComponent) {
+    if co.is_null() {
+        return;
+    }
+
+    // Components dropped during thread exit may outlive the map.
+    let _ = FLAGS.try_with(|flags| {
+        if let Some(flag) = flags.borrow_mut().remove(&(co as usize)) {
+            flag.set(true);
+        }
+    });
+}
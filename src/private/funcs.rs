@@ -24,6 +24,7 @@ use std::{char,ptr};
 
 use newt_sys::*;
 use crate::component::Component;
+use crate::constants::{FlagsSense,KEY_BKSPC,KEY_DELETE,KEY_EXTRA_BASE};
 use crate::form::Form;
 use crate::widgets::Entry;
 
@@ -40,6 +41,14 @@ pub fn char_to_c_char(ch: char) -> c_char {
     }
 }
 
+pub fn flags_set(flags: i32, new_flags: i32, sense: FlagsSense) -> i32 {
+    match sense {
+        FlagsSense::Set    => flags | new_flags,
+        FlagsSense::Reset  => flags & !new_flags,
+        FlagsSense::Toggle => flags ^ new_flags
+    }
+}
+
 pub fn char_slice_to_cstring(slice: &[char]) -> CString {
     let mut vec: Vec<u8> = Vec::new();
     for ch in slice.iter() {
@@ -50,6 +59,38 @@ pub fn char_slice_to_cstring(slice: &[char]) -> CString {
     CString::new(string.into_owned()).unwrap()
 }
 
+//
+// Predict the text of the `Entry` after the key `key` is handled.
+//
+// The filter is called before the `Entry` handles a key, so the edit is
+// repeated here. `Returns` `None` if the text is unchanged.
+//
+pub fn entry_edit(text: &str, key: char, cursor: i32) -> Option<String> {
+    let mut buf = text.as_bytes().to_vec();
+    let cursor = (cursor.max(0) as usize).min(buf.len());
+    let boundary = |i: &usize| text.is_char_boundary(*i);
+
+    match key as i32 {
+        KEY_BKSPC | 0x08 | 0x7f => {
+            let prev = (0..cursor).rev().find(boundary)?;
+            buf.drain(prev..cursor);
+        },
+        KEY_DELETE | 0x04 => {
+            let next = (cursor + 1..=buf.len()).find(boundary)?;
+            buf.drain(cursor..next);
+        },
+        0x0b => buf.truncate(cursor),
+        0x15 => { buf.drain(..cursor); },
+        k if k < KEY_EXTRA_BASE && !key.is_control() => {
+            let mut bytes = [0; 4];
+            let bytes = key.encode_utf8(&mut bytes).as_bytes();
+            buf.splice(cursor..cursor, bytes.iter().copied());
+        },
+        _ => return None
+    }
+    Some(String::from_utf8_lossy(&buf).into_owned())
+}
+
 unsafe extern "C"
 fn callback<'a, FN: 'a, T: 'a>(co: newtComponent, data: *mut c_void)
 where FN: FnMut(&dyn Component, Option<&T>)
@@ -148,3 +189,8 @@ where FN: FnMut(&Entry, Option<&T>, char, i32) -> char
     let c_ptr = cb as *const _ as *mut c_void;
     newtEntrySetFilter(co, Some(entry_filter::<FN, T>), c_ptr)
 }
+
+pub unsafe fn newt_entry_unset_filter(co: newtComponent)
+{
+    newtEntrySetFilter(co, None, ptr::null_mut())
+}
@@ -0,0 +1,126 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+//
+// Mirrors of structures private to newt (newt_pr.h) for the rare cases
+// where newt provides no public function for an action. A key press can be
+// passed directly to a component's event handler, as if the key had been
+// pressed while the component had focus.
+//
+// The structures match newt 0.52.25, the oldest version accepted by
+// newt-sys. Only the leading fields used here are declared. Before the
+// event handler is called, the mirrored position and size are compared
+// with those reported by newt's public functions, so a newt with a
+// different layout is detected rather than trusted.
+//
+use std::os::raw::c_int;
+
+use newt_sys::*;
+
+// enum eventTypes
+const EV_KEYPRESS: c_int = 2;
+// enum eventSequence
+const EV_NORMAL: c_int = 1;
+
+// struct newtComponent_struct
+#[repr(C)]
+struct Component {
+    height: c_int,
+    width: c_int,
+    top: c_int,
+    left: c_int,
+    _takes_focus: c_int,
+    _is_mapped: c_int,
+    ops: *const ComponentOps
+}
+
+// struct componentOps
+#[repr(C)]
+struct ComponentOps {
+    _draw: Option<unsafe extern "C" fn(newtComponent)>,
+    event: Option<unsafe extern "C" fn(newtComponent, Event) -> EventResult>
+}
+
+// struct event
+#[repr(C)]
+struct Event {
+    event: c_int,
+    when: c_int,
+    u: EventData
+}
+
+#[repr(C)]
+union EventData {
+    key: c_int,
+    _mouse: Mouse
+}
+
+#[repr(C)]
+#[derive(Clone,Copy)]
+struct Mouse {
+    _type: c_int,
+    _x: c_int,
+    _y: c_int
+}
+
+// struct eventResult
+#[repr(C)]
+struct EventResult {
+    _result: c_int
+}
+
+//
+// Check that the mirrored `Component` agrees with newt's public functions.
+//
+unsafe fn is_component(co: newtComponent) -> bool {
+    let component = &*(co as *const Component);
+    let (mut left, mut top) = (0, 0);
+    let (mut width, mut height) = (0, 0);
+    newtComponentGetPosition(co, &mut left, &mut top);
+    newtComponentGetSize(co, &mut width, &mut height);
+
+    !component.ops.is_null()
+        && (component.left, component.top) == (left, top)
+        && (component.width, component.height) == (width, height)
+}
+
+//
+// Send key press `key` to component `co`.
+//
+// `Returns` `false` if the key press couldn't be sent.
+//
+pub unsafe fn newt_component_key(co: newtComponent, key: c_int) -> bool {
+    if !is_component(co) {
+        return false;
+    }
+
+    let component = &*(co as *const Component);
+    match (*component.ops).event {
+        Some(event) => {
+            let ev = Event {
+                event: EV_KEYPRESS,
+                when: EV_NORMAL,
+                u: EventData { key }
+            };
+            event(co, ev);
+            true
+        },
+        None => false
+    }
+}
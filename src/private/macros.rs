@@ -36,3 +36,52 @@ macro_rules! c_ptr_array_to_boxed_slice {
         vec.into_boxed_slice()
     }};
 }
+
+//
+// Generate `try_*` variants of widget functions which return
+// `Error::Destroyed` rather than panicking when the widget has been
+// destroyed.
+//
+// Widgets which aren't a `Component` themselves name a method checking
+// the widgets they're built from with a leading `check = method;`.
+//
+macro_rules! try_fns {
+    (check = $check:ident; $($fns:tt)+) => {
+        try_fns!(@fns [$check] $($fns)+);
+    };
+
+    (@fns $check:tt $(fn $try_fn:ident = $fn_:ident(
+        $($arg:ident: $type:ty),*) $(-> $ret:ty)?;)+) => {
+        $(
+            #[doc = ""]
+            #[doc = concat!("Checked version of [`", stringify!($fn_),
+                            "()`][Self::", stringify!($fn_), "].")]
+            #[doc = ""]
+            #[doc = "`Returns` [`Error::Destroyed`][destroyed] if the \
+                     widget has been destroyed."]
+            #[doc = ""]
+            #[doc = "[destroyed]: crate::error::Error::Destroyed"]
+            #[doc = ""]
+            pub fn $try_fn(&self, $($arg: $type),*)
+              -> Result<try_fns!(@ret $($ret)?), $crate::error::Error>
+            {
+                try_fns!(@check self $check);
+                Ok(self.$fn_($($arg),*))
+            }
+        )+
+    };
+
+    (@check $self_:ident []) => {
+        $crate::component::Component::check($self_)?
+    };
+    (@check $self_:ident [$check:ident]) => {
+        $self_.$check()?
+    };
+
+    (@ret) => { () };
+    (@ret $ret:ty) => { $ret };
+
+    ($($fns:tt)+) => {
+        try_fns!(@fns [] $($fns)+);
+    };
+}
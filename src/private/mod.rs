@@ -20,6 +20,11 @@
 #[macro_use]
 pub mod macros;
 
-pub mod data;
+#[cfg(feature = "chrono")]
+pub mod date;
+pub mod destroyed;
 pub mod funcs;
+pub mod internals;
+pub mod slab;
 pub mod traits;
+pub mod tree;
@@ -0,0 +1,97 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+//
+// Storage for values owned by widgets such as `OwnedListbox`. Values are
+// identified by keys starting at `1` so that they can be passed to newt as
+// non-null data pointers.
+//
+pub struct Slab<T> {
+    entries: Vec<Option<T>>,
+    free: Vec<usize>
+}
+
+impl<T> Slab<T> {
+    pub fn new() -> Slab<T> {
+        Slab { entries: Vec::new(), free: Vec::new() }
+    }
+
+    pub fn insert(&mut self, value: T) -> usize {
+        match self.free.pop() {
+            Some(index) => {
+                self.entries[index] = Some(value);
+                index + 1
+            },
+            None => {
+                self.entries.push(Some(value));
+                self.entries.len()
+            }
+        }
+    }
+
+    pub fn get(&self, key: usize) -> Option<&T> {
+        if key == 0 { return None; }
+        self.entries.get(key - 1).and_then(|e| e.as_ref())
+    }
+
+    pub fn set(&mut self, key: usize, value: T) {
+        if let Some(entry) = self.entry_mut(key) {
+            *entry = Some(value);
+        }
+    }
+
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let value = self.entry_mut(key)?.take();
+        if value.is_some() {
+            self.free.push(key - 1);
+        }
+        value
+    }
+
+    pub fn position<P>(&self, predicate: P) -> Option<usize>
+        where P: Fn(&T) -> bool
+    {
+        self.entries.iter()
+            .position(|e| e.as_ref().is_some_and(&predicate))
+            .map(|index| index + 1)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.free.clear();
+    }
+
+    fn entry_mut(&mut self, key: usize) -> Option<&mut Option<T>> {
+        if key == 0 { return None; }
+        self.entries.get_mut(key - 1)
+    }
+}
+
+#[test]
+fn slab_reuses_keys() {
+    let mut slab = Slab::new();
+    assert_eq!(slab.insert("one"), 1);
+    assert_eq!(slab.insert("two"), 2);
+    assert_eq!(slab.remove(1), Some("one"));
+    assert_eq!(slab.get(1), None);
+    assert_eq!(slab.insert("three"), 1);
+    assert_eq!(slab.get(1), Some(&"three"));
+    assert_eq!(slab.position(|v| *v == "two"), Some(2));
+    assert_eq!(slab.get(0), None);
+}
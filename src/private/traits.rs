@@ -43,3 +43,7 @@ pub trait GridElementType {
 pub trait Nullify {
     fn nullify(&self);
 }
+
+pub trait ReturnExit {
+    fn return_exit(&self) -> bool { false }
+}
@@ -0,0 +1,182 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+//
+// The structure of the items in a `CheckboxTree`. newt provides no means
+// of listing the children of an item, so items are recorded here as they
+// are added.
+//
+use std::convert::TryFrom;
+use std::os::raw::{c_char,c_void};
+
+use crate::constants::ARG_APPEND;
+
+pub struct Node {
+    pub data: *const c_void,
+    pub text: String,
+    pub mark: Option<c_char>,
+    pub children: Vec<Node>
+}
+
+pub struct Tree {
+    roots: Vec<Node>,
+    pub partial: Option<c_char>,
+    pub single: Option<Single>
+}
+
+//
+// The checkbox values and the selected item of a `CheckboxTree` allowing
+// a single item to be selected.
+//
+pub struct Single {
+    pub unset: c_char,
+    pub set: c_char,
+    pub selected: *const c_void
+}
+
+impl Tree {
+    pub fn new() -> Tree {
+        Tree { roots: Vec::new(), partial: None, single: None }
+    }
+
+    //
+    // Record an item added at `indexes` in the same way
+    // newtCheckboxTreeAddArray() places it. `Returns` the path of the new
+    // item or `None` if the indexes are invalid.
+    //
+    pub fn insert(&mut self, indexes: &[i32], data: *const c_void,
+                  text: &str) -> Option<Vec<i32>> {
+        let (last, parents) = indexes.split_last()?;
+        let mut list = &mut self.roots;
+        let mut path = Vec::with_capacity(indexes.len());
+        for index in parents {
+            if *index < 0 {
+                return None;
+            }
+            list = &mut list.get_mut(*index as usize)?.children;
+            path.push(*index);
+        }
+
+        let position = match *last {
+            ARG_APPEND => list.len(),
+            index if index < 0 => return None,
+            index => (index as usize).min(list.len())
+        };
+        let node = Node {
+            data,
+            text: text.to_string(),
+            mark: None,
+            children: Vec::new()
+        };
+        list.insert(position, node);
+        path.push(position as i32);
+        Some(path)
+    }
+
+    pub fn roots(&self) -> &[Node] {
+        &self.roots
+    }
+
+    pub fn roots_mut(&mut self) -> &mut [Node] {
+        &mut self.roots
+    }
+
+    pub fn get_mut(&mut self, path: &[i32]) -> Option<&mut Node> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.get_mut(usize::try_from(*first).ok()?)?;
+        for index in rest {
+            node = node.children.get_mut(usize::try_from(*index).ok()?)?;
+        }
+        Some(node)
+    }
+
+    pub fn get(&self, path: &[i32]) -> Option<&Node> {
+        let (last, parents) = path.split_last()?;
+        self.children(parents)?.get(usize::try_from(*last).ok()?)
+    }
+
+    //
+    // `Returns` the children of the item at `path`, or the root items if
+    // `path` is empty.
+    //
+    pub fn children(&self, path: &[i32]) -> Option<&[Node]> {
+        if path.is_empty() {
+            return Some(&self.roots);
+        }
+        self.get(path).map(|node| node.children.as_slice())
+    }
+
+    //
+    // `Returns` the paths of all items with children.
+    //
+    pub fn branches(&self) -> Vec<Vec<i32>> {
+        let mut branches = Vec::new();
+        let mut path = Vec::new();
+        collect_branches(&self.roots, &mut path, &mut branches);
+        branches
+    }
+
+    //
+    // `Returns` the data of all items without children.
+    //
+    pub fn leaves(&self) -> Vec<*const c_void> {
+        let mut leaves = Vec::new();
+        collect_leaves(&self.roots, &mut leaves);
+        leaves
+    }
+}
+
+fn collect_leaves(nodes: &[Node], leaves: &mut Vec<*const c_void>) {
+    for node in nodes.iter() {
+        if node.children.is_empty() {
+            leaves.push(node.data);
+        }
+        collect_leaves(&node.children, leaves);
+    }
+}
+
+fn collect_branches(nodes: &[Node], path: &mut Vec<i32>,
+                    branches: &mut Vec<Vec<i32>>) {
+    for (index, node) in nodes.iter().enumerate() {
+        if node.children.is_empty() {
+            continue;
+        }
+        path.push(index as i32);
+        branches.push(path.clone());
+        collect_branches(&node.children, path, branches);
+        path.pop();
+    }
+}
+
+#[test]
+fn tree_insert() {
+    let mut tree = Tree::new();
+    let data = std::ptr::null();
+    assert_eq!(tree.insert(&[ARG_APPEND], data, "a"), Some(vec![0]));
+    assert_eq!(tree.insert(&[0], data, "b"), Some(vec![0]));
+    assert_eq!(tree.insert(&[0, ARG_APPEND], data, "c"), Some(vec![0, 0]));
+    assert_eq!(tree.insert(&[0, 5], data, "d"), Some(vec![0, 1]));
+    assert_eq!(tree.insert(&[2, ARG_APPEND], data, "e"), None);
+    assert_eq!(tree.insert(&[1, 0, ARG_APPEND], data, "f"), None);
+    assert_eq!(tree.get(&[0, 1]).unwrap().text, "d");
+    assert_eq!(tree.get_mut(&[1]).unwrap().text, "a");
+    assert_eq!(tree.children(&[]).unwrap().len(), 2);
+    assert_eq!(tree.children(&[0]).unwrap().len(), 2);
+    assert_eq!(tree.branches(), vec![vec![0]]);
+}
@@ -22,6 +22,7 @@
 //!
 use std::os::raw::c_int;
 
+use crate::error::Error;
 use crate::private::traits::ComponentPtr;
 use newt_sys::*;
 
@@ -33,23 +34,41 @@ pub use self::button::Button;
 mod checkbox;
 pub use self::checkbox::Checkbox;
 mod checkbox_tree;
-pub use self::checkbox_tree::CheckboxTree;
+pub use self::checkbox_tree::{CheckboxTree,TreeHandle,TreeItem};
 mod compact_button;
 pub use self::compact_button::CompactButton;
+#[cfg(feature = "chrono")]
+mod date_entry;
+#[cfg(feature = "chrono")]
+pub use self::date_entry::DateEntry;
 mod entry;
 pub use self::entry::Entry;
 mod label;
 pub use self::label::Label;
+mod lazy_tree;
+pub use self::lazy_tree::{LazyNode,LazyTree,TreeSource};
 mod listbox;
-pub use self::listbox::Listbox;
+pub use self::listbox::{Listbox,ListboxIter};
+mod listbox_search;
+pub use self::listbox_search::{ListboxSearch,SearchMode};
+mod owned_checkbox_tree;
+pub use self::owned_checkbox_tree::OwnedCheckboxTree;
+mod owned_listbox;
+pub use self::owned_listbox::OwnedListbox;
 mod radiobutton;
 pub use self::radiobutton::Radiobutton;
 mod radiobutton_set;
 pub use self::radiobutton_set::RadiobuttonSet;
 mod scale;
 pub use self::scale::Scale;
+mod sectioned;
+pub use self::sectioned::{SectionedCheckboxTree,SectionedListbox};
+mod table;
+pub use self::table::{Align,Column,Table};
 mod textbox;
 pub use self::textbox::Textbox;
+mod virtual_listbox;
+pub use self::virtual_listbox::{ListSource,VirtualListbox};
 
 ///
 /// Implement shared functions for newt component widgets.
@@ -93,4 +112,41 @@ pub trait WidgetFns: ComponentPtr {
         };
         (width, height)
     }
+
+    ///
+    /// Checked version of [`takes_focus()`][WidgetFns::takes_focus].
+    ///
+    /// `Returns` [`Error::Destroyed`] if the widget has been destroyed.
+    ///
+    fn try_takes_focus(&self, value: bool) -> Result<(), Error> {
+        if self.is_null() {
+            return Err(Error::Destroyed);
+        }
+        self.takes_focus(value);
+        Ok(())
+    }
+
+    ///
+    /// Checked version of [`get_position()`][WidgetFns::get_position].
+    ///
+    /// `Returns` [`Error::Destroyed`] if the widget has been destroyed.
+    ///
+    fn try_get_position(&self) -> Result<(i32, i32), Error> {
+        if self.is_null() {
+            return Err(Error::Destroyed);
+        }
+        Ok(self.get_position())
+    }
+
+    ///
+    /// Checked version of [`get_size()`][WidgetFns::get_size].
+    ///
+    /// `Returns` [`Error::Destroyed`] if the widget has been destroyed.
+    ///
+    fn try_get_size(&self) -> Result<(i32, i32), Error> {
+        if self.is_null() {
+            return Err(Error::Destroyed);
+        }
+        Ok(self.get_size())
+    }
 }
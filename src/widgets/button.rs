@@ -19,9 +19,11 @@
 
 use std::cell::Cell;
 use std::ffi::CString;
+use std::rc::Rc;
 
 use newt_sys::*;
-use crate::private::traits::ComponentClone;
+use crate::private::destroyed;
+use crate::private::traits::{ComponentClone,ReturnExit};
 
 ///
 /// A widget that when activated causes the currently running
@@ -30,9 +32,11 @@ use crate::private::traits::ComponentClone;
 /// [form]: crate::form::Form
 ///
 #[derive(Component)]
+#[newt(manual_return_exit)]
 pub struct Button {
     co: Cell<newtComponent>,
-    added_to_parent: Cell<bool>
+    added_to_parent: Cell<bool>,
+    destroyed: Rc<Cell<bool>>
 }
 
 impl Button {
@@ -45,11 +49,11 @@ impl Button {
     ///
     pub fn new(left: i32, top: i32, text: &str) -> Button {
         let c_str = CString::new(text).unwrap();
+        let co = unsafe { newtButton(left, top, c_str.as_ptr()) };
         Button {
-            co: unsafe {
-                Cell::new(newtButton(left, top, c_str.as_ptr()))
-            },
-            added_to_parent: Cell::new(false)
+            co: Cell::new(co),
+            added_to_parent: Cell::new(false),
+            destroyed: destroyed::flag(co)
         }
     }
 }
@@ -58,7 +62,12 @@ impl ComponentClone for Button {
     unsafe fn clone_co(co: newtComponent, added_to_parent: bool) -> Button {
         Button {
             co: Cell::new(co),
-            added_to_parent: Cell::new(added_to_parent)
+            added_to_parent: Cell::new(added_to_parent),
+            destroyed: destroyed::flag(co)
         }
     }
 }
+
+impl ReturnExit for Button {
+    fn return_exit(&self) -> bool { true }
+}
@@ -24,17 +24,20 @@ use std::ptr;
 
 use newt_sys::*;
 use crate::component::Component;
-use crate::constants::FlagsSense;
+use crate::constants::{FlagsSense,FLAG_RETURNEXIT};
 use crate::private::funcs::*;
+use crate::private::traits::ReturnExit;
 
 ///
 /// A widget displaying a box which can be cycled through various
 /// checked states.
 ///
 #[derive(Component)]
+#[newt(manual_return_exit)]
 pub struct Checkbox {
     co: Cell<newtComponent>,
-    added_to_parent: Cell<bool>
+    added_to_parent: Cell<bool>,
+    flags: Cell<i32>
 }
 
 impl Checkbox {
@@ -74,7 +77,8 @@ impl Checkbox {
                                       c_seq, ptr::null_mut());
                 Cell::new(co)
             },
-            added_to_parent: Cell::new(false)
+            added_to_parent: Cell::new(false),
+            flags: Cell::new(0)
         }
     }
 
@@ -109,5 +113,18 @@ impl Checkbox {
     ///
     pub fn set_flags(&self, flags: i32, sense: FlagsSense) {
         unsafe { newtCheckboxSetFlags(self.co(), flags, sense as u32); }
+        self.flags.set(flags_set(self.flags.get(), flags, sense));
+    }
+
+    try_fns! {
+        fn try_get_value = get_value() -> char;
+        fn try_set_value = set_value(value: char);
+        fn try_set_flags = set_flags(flags: i32, sense: FlagsSense);
+    }
+}
+
+impl ReturnExit for Checkbox {
+    fn return_exit(&self) -> bool {
+        self.flags.get() & FLAG_RETURNEXIT != 0
     }
 }
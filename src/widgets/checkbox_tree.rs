@@ -17,17 +17,80 @@
 // Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
 //
 
-use std::cell::Cell;
+use std::cell::{Cell,RefCell};
 use std::ffi::CString;
 use std::marker::PhantomData;
-use std::os::raw::{c_char,c_void};
+use std::ptr;
+use std::os::raw::{c_char,c_int,c_void};
 
 use newt_sys::*;
 use crate::component::Component;
-use crate::private::data::Data;
+use crate::data::Data;
+use crate::error::Error;
 use crate::private::funcs::*;
+use crate::private::internals::newt_component_key;
+use crate::private::tree::{Node,Single,Tree};
 use crate::constants;
 
+///
+/// The location of an item in a [`CheckboxTree`].
+///
+/// A `TreeHandle` holds the path of indexes locating an item in the tree,
+/// as returned by [`CheckboxTree::find_item()`]. Inserting an item in
+/// front of others using [`CheckboxTree::add_item()`] changes the paths
+/// of the items following it.
+///
+#[derive(Clone,Debug,PartialEq,Eq,Hash)]
+pub struct TreeHandle(Box<[i32]>);
+
+impl TreeHandle {
+    ///
+    /// Create a `TreeHandle` from a path of indexes.
+    ///
+    /// * `path` - The index of the item in each level of the tree.
+    ///
+    pub fn from_path(path: &[i32]) -> TreeHandle {
+        TreeHandle(path.into())
+    }
+
+    ///
+    /// `Returns` the path of indexes locating the item in the tree.
+    ///
+    pub fn path(&self) -> &[i32] {
+        &self.0
+    }
+}
+
+impl From<Box<[i32]>> for TreeHandle {
+    fn from(path: Box<[i32]>) -> TreeHandle {
+        TreeHandle(path)
+    }
+}
+
+///
+/// An item and its children to be added to a [`CheckboxTree`] with
+/// [`CheckboxTree::add_tree()`].
+///
+pub struct TreeItem<D: Data> {
+    text: String,
+    data: D,
+    children: Vec<TreeItem<D>>
+}
+
+impl<D: Data> TreeItem<D> {
+    ///
+    /// Create a new `TreeItem`.
+    ///
+    /// * `text` - The label to be displayed with the item.
+    /// * `data` - User `Data` to be associated with the item.
+    /// * `children` - The children of the item.
+    ///
+    pub fn new(text: &str, data: D, children: Vec<TreeItem<D>>)
+      -> TreeItem<D> {
+        TreeItem { text: text.to_string(), data, children }
+    }
+}
+
 ///
 /// [Checkboxes][checkbox] arranged in a collapsible tree.
 ///
@@ -89,6 +152,9 @@ use crate::constants;
 pub struct CheckboxTree<D: Data = isize> {
     co: Cell<newtComponent>,
     added_to_parent: Cell<bool>,
+    tree: Box<RefCell<Tree>>,
+    unset: c_char,
+    set: c_char,
     data: PhantomData<D>
 }
 
@@ -131,6 +197,15 @@ impl<D: Data> CheckboxTree<D> {
         CheckboxTree {
             co: Cell::new(component),
             added_to_parent: Cell::new(false),
+            tree: Box::new(RefCell::new(Tree::new())),
+            unset: match sequence {
+                Some(seq) if !seq.is_empty() => char_to_c_char(seq[0]),
+                _ => b' ' as c_char
+            },
+            set: match sequence {
+                Some(seq) if seq.len() > 1 => char_to_c_char(seq[1]),
+                _ => b'*' as c_char
+            },
             data: PhantomData
         }
     }
@@ -188,11 +263,253 @@ impl<D: Data> CheckboxTree<D> {
         }
 
         let c_str = CString::new(text).unwrap();
-        unsafe {
+        let rv = unsafe {
             newtCheckboxTreeAddArray(self.co(), c_str.as_ptr(),
                                      data.newt_to_ptr(), flags,
                                      c_array.as_ptr() as *mut i32)
+        };
+        if rv == 0 {
+            let indexes = &c_array[..c_array.len() - 1];
+            let mut tree = self.tree.borrow_mut();
+            tree.insert(indexes, data.newt_to_ptr(), text);
+            if tree.partial.is_some() {
+                unsafe { update_marks(self.co(), &mut tree); }
+            }
+        }
+        rv
+    }
+
+    ///
+    /// Add an item to the end of the root of the `CheckboxTree`.
+    ///
+    /// * `text` - The label to be displayed with the item.
+    /// * `data` - User `Data` to be associated with the item.
+    ///
+    /// `Returns` a handle to the new item.
+    ///
+    pub fn add_root(&self, text: &str, data: D)
+      -> Result<TreeHandle, Error> {
+        self.add(text, data, &[])
+    }
+
+    ///
+    /// Add an item to the end of the children of another item.
+    ///
+    /// * `parent` - The handle of the parent item.
+    /// * `text` - The label to be displayed with the item.
+    /// * `data` - User `Data` to be associated with the item.
+    ///
+    /// `Returns` a handle to the new item, or [`Error::InvalidItem`] if
+    /// `parent` doesn't refer to an item.
+    ///
+    pub fn add_child(&self, parent: &TreeHandle, text: &str, data: D)
+      -> Result<TreeHandle, Error> {
+        self.add(text, data, parent.path())
+    }
+
+    ///
+    /// Add a nested structure of items to the `CheckboxTree`.
+    ///
+    /// * `parent` - The handle of the item the items are added to, or `None`
+    ///   to add them to the root of the tree.
+    /// * `items` - The items to be added.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if `parent` doesn't refer to an
+    /// item.
+    ///
+    pub fn add_tree(&self, parent: Option<&TreeHandle>,
+                    items: &[TreeItem<D>]) -> Result<(), Error> {
+        let path = parent.map(|parent| parent.path()).unwrap_or(&[]);
+        for item in items.iter() {
+            let data = unsafe { D::newt_from_ptr(item.data.newt_to_ptr()) };
+            let handle = self.add(&item.text, data, path)?;
+            self.add_tree(Some(&handle), &item.children)?;
         }
+        Ok(())
+    }
+
+    ///
+    /// `Returns` handles to the items in the root of the `CheckboxTree`.
+    ///
+    pub fn roots(&self) -> Vec<TreeHandle> {
+        self.children_of(&[])
+    }
+
+    ///
+    /// `Returns` handles to the children of an item.
+    ///
+    /// * `handle` - The handle of the parent item.
+    ///
+    pub fn children(&self, handle: &TreeHandle) -> Vec<TreeHandle> {
+        self.children_of(handle.path())
+    }
+
+    ///
+    /// `Returns` the handle of the parent of an item, or `None` if the
+    /// item is in the root of the `CheckboxTree`.
+    ///
+    /// * `handle` - The handle of the child item.
+    ///
+    pub fn parent(&self, handle: &TreeHandle) -> Option<TreeHandle> {
+        match handle.path().split_last() {
+            Some((_, path)) if !path.is_empty() => {
+                Some(TreeHandle::from_path(path))
+            },
+            _ => None
+        }
+    }
+
+    ///
+    /// Find the handle of an item by its associated user `Data`.
+    ///
+    /// * `data` - The user `Data` associated with the item.
+    ///
+    pub fn handle(&self, data: D) -> Option<TreeHandle> {
+        let path = self.find_item(data);
+        if path.is_empty() {
+            return None;
+        }
+        Some(TreeHandle::from(path))
+    }
+
+    ///
+    /// Get the user `Data` associated with an item.
+    ///
+    /// * `handle` - The handle of the item.
+    ///
+    pub fn get_data(&self, handle: &TreeHandle) -> Option<D> {
+        self.tree.borrow().get(handle.path())
+            .map(|node| unsafe { D::newt_from_ptr(node.data) })
+    }
+
+    ///
+    /// `Returns` `true` if the item's children are shown.
+    ///
+    /// * `handle` - The handle of the item.
+    ///
+    pub fn is_expanded(&self, handle: &TreeHandle) -> bool {
+        match self.tree.borrow().get(handle.path()) {
+            Some(node) => unsafe {
+                newtCheckboxTreeGetEntryValue(self.co(), node.data)
+                    == constants::CHECKBOXTREE_EXPANDED
+            },
+            None => false
+        }
+    }
+
+    ///
+    /// Show the children of an item. The item's parents are also
+    /// expanded.
+    ///
+    /// Items are located by their associated user `Data`, so this may
+    /// not work as expected when `Data` is shared by multiple items.
+    ///
+    /// * `handle` - The handle of the item.
+    ///
+    pub fn expand(&self, handle: &TreeHandle) {
+        let child = match self.tree.borrow().get(handle.path()) {
+            Some(node) => match node.children.first() {
+                Some(child) => child.data,
+                None => return
+            },
+            None => return
+        };
+
+        unsafe {
+            let co = self.co();
+            let current = newtCheckboxTreeGetCurrent(co);
+            newtCheckboxTreeSetCurrent(co, child as *mut c_void);
+            newtCheckboxTreeSetCurrent(co, current as *mut c_void);
+        }
+    }
+
+    ///
+    /// Hide the children of an item.
+    ///
+    /// Items are located by their associated user `Data`, so this may
+    /// not work as expected when `Data` is shared by multiple items.
+    ///
+    /// * `handle` - The handle of the item.
+    ///
+    pub fn collapse(&self, handle: &TreeHandle) {
+        if !self.is_expanded(handle) {
+            return;
+        }
+
+        let data = match self.tree.borrow().get(handle.path()) {
+            Some(node) => node.data,
+            None => return
+        };
+
+        unsafe {
+            let co = self.co();
+            let current = newtCheckboxTreeGetCurrent(co);
+            let hidden = self.is_descendant(current, handle.path());
+
+            // newt provides no function for collapsing a branch, so the
+            // key press toggling it is passed to the CheckboxTree.
+            newtCheckboxTreeSetCurrent(co, data as *mut c_void);
+            let toggled = newt_component_key(co, ' ' as c_int);
+            if !toggled || !hidden {
+                newtCheckboxTreeSetCurrent(co, current as *mut c_void);
+            }
+        }
+    }
+
+    ///
+    /// Show the children of every item in the `CheckboxTree`.
+    ///
+    pub fn expand_all(&self) {
+        let branches = self.tree.borrow().branches();
+        for path in branches.iter() {
+            self.expand(&TreeHandle::from_path(path));
+        }
+    }
+
+    ///
+    /// Hide the children of every item in the `CheckboxTree`.
+    ///
+    pub fn collapse_all(&self) {
+        let branches = self.tree.borrow().branches();
+        for path in branches.iter().rev() {
+            self.collapse(&TreeHandle::from_path(path));
+        }
+    }
+
+    fn add(&self, text: &str, data: D, parent: &[i32])
+      -> Result<TreeHandle, Error> {
+        let mut indexes = parent.to_vec();
+        indexes.push(constants::ARG_APPEND);
+        if self.add_item(text, data, 0, Some(&indexes)) != 0 {
+            return Err(Error::InvalidItem);
+        }
+
+        let tree = self.tree.borrow();
+        let count = tree.children(parent).ok_or(Error::InvalidItem)?.len();
+        let count = count as i32;
+        indexes.pop();
+        indexes.push(count - 1);
+        Ok(TreeHandle::from_path(&indexes))
+    }
+
+    fn children_of(&self, path: &[i32]) -> Vec<TreeHandle> {
+        let count = self.tree.borrow().children(path)
+            .map(|children| children.len())
+            .unwrap_or(0);
+        (0..count as i32).map(|index| {
+            let mut path = path.to_vec();
+            path.push(index);
+            TreeHandle::from_path(&path)
+        }).collect()
+    }
+
+    fn is_descendant(&self, data: *const c_void, path: &[i32]) -> bool {
+        if data.is_null() {
+            return false;
+        }
+
+        let found = self.path(data);
+        found.len() > path.len() && found.starts_with(path)
     }
 
     ///
@@ -203,7 +520,7 @@ impl<D: Data> CheckboxTree<D> {
     pub fn get_current(&self) -> Option<D> {
         let c_data = unsafe { newtCheckboxTreeGetCurrent(self.co()) };
         if c_data.is_null() { return None; }
-        Some(D::newt_from_ptr(c_data))
+        Some(unsafe { D::newt_from_ptr(c_data) })
     }
 
     ///
@@ -269,12 +586,16 @@ impl<D: Data> CheckboxTree<D> {
     /// index in the tree.
     ///
     pub fn find_item(&self, data: D) -> Box<[i32]> {
+        self.path(data.newt_to_ptr())
+    }
+
+    fn path(&self, data: *const c_void) -> Box<[i32]> {
         let mut vec: Vec<i32> = Vec::new();
         unsafe {
-            let rv = newtCheckboxTreeFindItem(
-                self.co(),
-                data.newt_to_ptr() as *mut c_void
-            );
+            let rv = newtCheckboxTreeFindItem(self.co(), data as *mut c_void);
+            if rv.is_null() {
+                return vec.into_boxed_slice();
+            }
 
             let mut p = rv;
             let mut value: i32 = *p as i32;
@@ -296,7 +617,17 @@ impl<D: Data> CheckboxTree<D> {
     /// * `text` - The new display text of the item.
     ///
     pub fn set_entry(&self, data: D, text: &str) {
-        let c_str = CString::new(text).unwrap();
+        let path = self.path(data.newt_to_ptr());
+        let mut tree = self.tree.borrow_mut();
+        let label = match tree.get_mut(&path) {
+            Some(node) => {
+                node.text = text.to_string();
+                label(node)
+            },
+            None => text.to_string()
+        };
+
+        let c_str = CString::new(label).unwrap();
         unsafe {
             newtCheckboxTreeSetEntry(
                 self.co(),
@@ -318,12 +649,19 @@ impl<D: Data> CheckboxTree<D> {
     ///
     /// * `data` - The user `Data` associated with the checkbox.
     ///
+    /// With [hierarchical selection][hierarchical] enabled the value of a
+    /// tree node is the value shown beside it.
+    ///
     /// `Returns` the current sequence value of the checkbox.
     ///
     /// [C]: crate::constants::checkboxtree::CHECKBOXTREE_COLLAPSED
     /// [E]: crate::constants::checkboxtree::CHECKBOXTREE_EXPANDED
+    /// [hierarchical]: CheckboxTree::set_hierarchical
     ///
     pub fn get_entry_value(&self, data: D) -> char {
+        if let Some(mark) = self.mark(data.newt_to_ptr()) {
+            return mark as u8 as char;
+        }
         unsafe {
             newtCheckboxTreeGetEntryValue(
                 self.co(),
@@ -337,18 +675,308 @@ impl<D: Data> CheckboxTree<D> {
     ///
     /// The checkbox value is generally a `char` defined by the `sequence`
     /// parameter when the `CheckboxTree` is created with
-    /// [`CheckboxTree::new()`] but does not need to be.
+    /// [`CheckboxTree::new()`] but does not need to be. With
+    /// [hierarchical selection][hierarchical] enabled setting the value of
+    /// a tree node sets the value of every checkbox beneath it.
     ///
     /// * `data` - The user `Data` associated with the checkbox.
     /// * `value` - The value to set the checkbox item to.
     ///
+    /// [hierarchical]: CheckboxTree::set_hierarchical
+    ///
     pub fn set_entry_value(&self, data: D, value: char) {
+        let value = char_to_c_char(value);
+        let co = self.co();
+        let ptr = data.newt_to_ptr();
         unsafe {
-            newtCheckboxTreeSetEntryValue(
-                self.co(),
-                data.newt_to_ptr(),
-                char_to_c_char(value)
-            );
+            if self.mark(ptr).is_some() {
+                let path = self.path(ptr);
+                if let Some(node) = self.tree.borrow().get(&path) {
+                    set_leaves(co, node, value);
+                }
+            } else {
+                newtCheckboxTreeSetEntryValue(co, ptr, value);
+            }
+
+            let mut tree = self.tree.borrow_mut();
+            if tree.single.is_some() && tree.leaves().contains(&ptr) {
+                let single = tree.single.as_mut().unwrap();
+                if value == single.unset {
+                    if ptr == single.selected {
+                        single.selected = ptr::null();
+                    }
+                } else if ptr != single.selected {
+                    if !single.selected.is_null() {
+                        newtCheckboxTreeSetEntryValue(co, single.selected,
+                                                      single.unset);
+                    }
+                    single.selected = ptr;
+                }
+            }
+            if tree.partial.is_some() {
+                update_marks(co, &mut tree);
+            }
+        }
+    }
+
+    ///
+    /// Keep the values of tree nodes consistent with their children.
+    ///
+    /// newt draws tree nodes without a checkbox. With hierarchical
+    /// selection enabled the label of each node is prefixed with its value:
+    /// the value shared by every checkbox beneath it, or `partial` when
+    /// they differ. Setting the value of a node with
+    /// [`set_entry_value()`][set_entry_value], or by pressing a `sequence`
+    /// key while the node is selected, sets every checkbox beneath it.
+    ///
+    /// Hierarchical selection uses the `CheckboxTree`'s
+    /// [`Callback`][callback], so another callback must not be set on it.
+    ///
+    /// * `partial` - The value shown for partially selected nodes, or `None`
+    ///   to disable hierarchical selection.
+    ///
+    /// [callback]: crate::callbacks::Callback
+    /// [set_entry_value]: CheckboxTree::set_entry_value
+    ///
+    pub fn set_hierarchical(&self, partial: Option<char>) {
+        let co = self.co();
+        let mut tree = self.tree.borrow_mut();
+        tree.partial = partial.map(char_to_c_char);
+        unsafe {
+            self.set_callback(&tree);
+            update_marks(co, &mut tree);
+        }
+    }
+
+    ///
+    /// Allow only a single checkbox to be selected, as with a group of
+    /// [`Radiobutton`][radiobutton]s. Selecting a checkbox clears the
+    /// checkbox previously selected, and the selected checkbox can't be
+    /// cleared by the user. Only the first of the checkboxes already
+    /// selected remains selected, and selecting a checkbox with
+    /// [`set_entry_value()`][set_entry_value] also clears the checkbox
+    /// previously selected.
+    ///
+    /// Single selection uses the `CheckboxTree`'s [`Callback`][callback],
+    /// so another callback must not be set on it.
+    ///
+    /// * `single` - `true` to allow only a single checkbox to be selected.
+    ///
+    /// [callback]: crate::callbacks::Callback
+    /// [radiobutton]: crate::widgets::Radiobutton
+    /// [set_entry_value]: CheckboxTree::set_entry_value
+    ///
+    pub fn set_single_selection(&self, single: bool) {
+        let co = self.co();
+        let mut tree = self.tree.borrow_mut();
+        tree.single = None;
+        if single {
+            let mut selected: *const c_void = ptr::null();
+            for leaf in tree.leaves() {
+                unsafe {
+                    if newtCheckboxTreeGetEntryValue(co, leaf) == self.unset {
+                        continue;
+                    }
+                    if selected.is_null() {
+                        selected = leaf;
+                    } else {
+                        newtCheckboxTreeSetEntryValue(co, leaf, self.unset);
+                    }
+                }
+            }
+            tree.single = Some(Single {
+                unset: self.unset,
+                set: self.set,
+                selected
+            });
+        }
+        unsafe { self.set_callback(&tree); }
+    }
+
+    ///
+    /// Get the selected checkboxes, replacing the checkboxes beneath a
+    /// tree node with the node itself when all of them are selected.
+    ///
+    /// `Returns` an array of the associated user `Data` of the selected
+    /// checkboxes and tree nodes.
+    ///
+    pub fn get_compact_selection(&self) -> Box<[D]> {
+        let co = self.co();
+        let mut selection = Vec::new();
+        for node in self.tree.borrow().roots() {
+            unsafe {
+                selection.extend(compact_selection(co, node, self.unset).1);
+            }
+        }
+        selection.into_iter()
+            .map(|data| unsafe { D::newt_from_ptr(data) })
+            .collect()
+    }
+
+    //
+    // Set the callback maintaining hierarchical and single selection, or
+    // remove it when neither is enabled.
+    //
+    unsafe fn set_callback(&self, tree: &Tree) {
+        if tree.partial.is_none() && tree.single.is_none() {
+            newt_unset_callback(self);
+            return;
+        }
+        let c_ptr = self.tree.as_ref() as *const _;
+        newtComponentAddCallback(self.co(), Some(callback),
+                                 c_ptr as *mut c_void);
+    }
+
+    //
+    // `Returns` the value shown beside the tree node associated with
+    // `data` when hierarchical selection is enabled.
+    //
+    fn mark(&self, data: *const c_void) -> Option<c_char> {
+        self.tree.borrow().partial?;
+        let path = self.path(data);
+        self.tree.borrow().get(&path).and_then(|node| node.mark)
+    }
+
+    try_fns! {
+        fn try_set_width = set_width(width: i32);
+        fn try_add_item = add_item(text: &str, data: D, flags: i32,
+                                   indexes: Option<&[i32]>) -> i32;
+        fn try_add_root = add_root(text: &str, data: D)
+            -> Result<TreeHandle, Error>;
+        fn try_add_child = add_child(parent: &TreeHandle, text: &str, data: D)
+            -> Result<TreeHandle, Error>;
+        fn try_add_tree = add_tree(parent: Option<&TreeHandle>,
+                                   items: &[TreeItem<D>])
+            -> Result<(), Error>;
+        fn try_is_expanded = is_expanded(handle: &TreeHandle) -> bool;
+        fn try_expand = expand(handle: &TreeHandle);
+        fn try_collapse = collapse(handle: &TreeHandle);
+        fn try_expand_all = expand_all();
+        fn try_collapse_all = collapse_all();
+        fn try_get_current = get_current() -> Option<D>;
+        fn try_set_current = set_current(data: D);
+        fn try_get_selection = get_selection() -> Box<[D]>;
+        fn try_get_multi_selection = get_multi_selection(seqval: char)
+            -> Box<[D]>;
+        fn try_set_entry = set_entry(data: D, text: &str);
+        fn try_get_entry_value = get_entry_value(data: D) -> char;
+        fn try_set_entry_value = set_entry_value(data: D, value: char);
+        fn try_set_hierarchical = set_hierarchical(partial: Option<char>);
+        fn try_set_single_selection = set_single_selection(single: bool);
+        fn try_get_compact_selection = get_compact_selection() -> Box<[D]>;
+    }
+}
+
+fn label(node: &Node) -> String {
+    match node.mark {
+        Some(mark) => format!("[{}] {}", mark as u8 as char, node.text),
+        None => node.text.clone()
+    }
+}
+
+//
+// Update the values shown beside tree nodes. `Returns` the value of
+// `node`.
+//
+unsafe fn update_node(co: newtComponent, node: &mut Node,
+                      partial: Option<c_char>) -> c_char {
+    if node.children.is_empty() {
+        node.mark = None;
+        return newtCheckboxTreeGetEntryValue(co, node.data);
+    }
+
+    let mut value = None;
+    for child in node.children.iter_mut() {
+        let child_value = update_node(co, child, partial);
+        value = match value {
+            Some(value) if value != child_value => partial,
+            None => Some(child_value),
+            value => value
+        };
+    }
+
+    let mark = partial.and(value);
+    if mark != node.mark {
+        node.mark = mark;
+        let c_str = CString::new(label(node)).unwrap();
+        newtCheckboxTreeSetEntry(co, node.data, c_str.as_ptr());
+    }
+    value.unwrap_or(0)
+}
+
+unsafe fn update_marks(co: newtComponent, tree: &mut Tree) {
+    let partial = tree.partial;
+    for node in tree.roots_mut() {
+        update_node(co, node, partial);
+    }
+}
+
+unsafe fn set_leaves(co: newtComponent, node: &Node, value: c_char) {
+    if node.children.is_empty() {
+        newtCheckboxTreeSetEntryValue(co, node.data, value);
+    }
+    for child in node.children.iter() {
+        set_leaves(co, child, value);
+    }
+}
+
+//
+// `Returns` `true` if every checkbox beneath `node` is selected, along with
+// the compact selection of `node`.
+//
+unsafe fn compact_selection(co: newtComponent, node: &Node, unset: c_char)
+  -> (bool, Vec<*const c_void>) {
+    if node.children.is_empty() {
+        let value = newtCheckboxTreeGetEntryValue(co, node.data);
+        let selected = value != unset;
+        return (selected, if selected { vec![node.data] } else { vec![] });
+    }
+
+    let mut all = true;
+    let mut selection = Vec::new();
+    for child in node.children.iter() {
+        let (selected, child_selection) = compact_selection(co, child, unset);
+        all &= selected;
+        selection.extend(child_selection);
+    }
+
+    if all {
+        return (true, vec![node.data]);
+    }
+    (false, selection)
+}
+
+//
+// Keep the current checkbox selected in place of the checkbox previously
+// selected.
+//
+unsafe fn select_current(co: newtComponent, tree: &mut Tree) {
+    let current = newtCheckboxTreeGetCurrent(co) as *const c_void;
+    if current.is_null() || !tree.leaves().contains(&current) {
+        return;
+    }
+
+    let single = match tree.single {
+        Some(ref mut single) => single,
+        None => return
+    };
+    let set = newtCheckboxTreeGetEntryValue(co, current) != single.unset;
+    if current == single.selected {
+        if !set {
+            newtCheckboxTreeSetEntryValue(co, current, single.set);
+        }
+    } else if set {
+        if !single.selected.is_null() {
+            newtCheckboxTreeSetEntryValue(co, single.selected, single.unset);
         }
+        single.selected = current;
+    }
+}
+
+unsafe extern "C" fn callback(co: newtComponent, data: *mut c_void) {
+    let tree = &*(data as *const RefCell<Tree>);
+    if let Ok(mut tree) = tree.try_borrow_mut() {
+        select_current(co, &mut tree);
+        update_marks(co, &mut tree);
     }
 }
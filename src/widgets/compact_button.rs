@@ -20,6 +20,7 @@
 use std::cell::Cell;
 use std::ffi::CString;
 use newt_sys::*;
+use crate::private::traits::ReturnExit;
 
 ///
 /// A smaller [Button][button] with no padding around its label.
@@ -27,6 +28,7 @@ use newt_sys::*;
 /// [button]: crate::widgets::Button
 ///
 #[derive(Component)]
+#[newt(manual_return_exit)]
 pub struct CompactButton {
     co: Cell<newtComponent>,
     added_to_parent: Cell<bool>
@@ -51,3 +53,7 @@ impl CompactButton {
         }
     }
 }
+
+impl ReturnExit for CompactButton {
+    fn return_exit(&self) -> bool { true }
+}
@@ -0,0 +1,194 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use chrono::{NaiveDate,NaiveDateTime};
+
+use crate::callbacks::EntryFilter;
+use crate::component::Component;
+use crate::error::Error;
+use crate::private::date::{self,DateFormat};
+use crate::private::funcs::newt_entry_unset_filter;
+use crate::widgets::Entry;
+
+type Filter = fn(&Entry, Option<&DateFormat>, char, i32) -> char;
+
+///
+/// An [`Entry`] for editing a date, or a date and time.
+///
+/// Dates are displayed as `YYYY-MM-DD` and times as `HH:MM`. Typed
+/// digits overwrite the digits under the cursor. The `Up` and `Down`
+/// keys, or `+` and `-`, increment and decrement the year, month, day,
+/// hour or minute under the cursor. Other characters are rejected by an
+/// [`EntryFilter`] installed on the `Entry`, so another filter must not
+/// be set on it.
+///
+/// Requires the `chrono` feature.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate chrono;
+/// extern crate newt;
+/// use chrono::NaiveDate;
+/// use newt::prelude::*;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     newt::centered_window(20, 3, Some("Date")).unwrap();
+///
+///     let today = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+///     let date = DateEntry::new(5, 1, today, 0);
+///
+///     let mut form = Form::new(None, 0);
+///     form.add_component(date.entry()).unwrap();
+///     form.run().unwrap();
+///     newt::finished();
+///
+///     println!("Date: {:?}", date.get_date());
+/// }
+/// ```
+///
+pub struct DateEntry {
+    _filter: Box<EntryFilter<'static, Filter, DateFormat>>,
+    entry: Box<Entry>,
+    format: DateFormat
+}
+
+impl DateEntry {
+    ///
+    /// Create a new `DateEntry` for editing a date.
+    ///
+    /// * `left` - The left-most position of the `DateEntry`.
+    /// * `top` - The top-most position of the `DateEntry`.
+    /// * `date` - The initial date.
+    /// * `flags` - [Flags][entry] modifying the behavior of the `Entry`.
+    ///
+    /// [entry]: crate::constants::entry
+    ///
+    pub fn new(left: i32, top: i32, date: NaiveDate, flags: i32)
+      -> DateEntry {
+        let datetime = date.and_hms_opt(0, 0, 0).unwrap();
+        DateEntry::with_format(left, top, &datetime, DateFormat::Date, flags)
+    }
+
+    ///
+    /// Create a new `DateEntry` for editing a date and time.
+    ///
+    /// * `left` - The left-most position of the `DateEntry`.
+    /// * `top` - The top-most position of the `DateEntry`.
+    /// * `datetime` - The initial date and time.
+    /// * `flags` - [Flags][entry] modifying the behavior of the `Entry`.
+    ///
+    /// [entry]: crate::constants::entry
+    ///
+    pub fn new_datetime(left: i32, top: i32, datetime: NaiveDateTime,
+                        flags: i32) -> DateEntry {
+        DateEntry::with_format(left, top, &datetime, DateFormat::DateTime,
+                               flags)
+    }
+
+    fn with_format(left: i32, top: i32, datetime: &NaiveDateTime,
+                   format: DateFormat, flags: i32) -> DateEntry {
+        let text = format.format(datetime);
+        let width = format.width() as i32;
+        let entry = Box::new(Entry::new(left, top, Some(&text), width,
+                                        flags));
+        entry.set_cursor_position(0);
+
+        // The `Entry` is boxed so its address remains valid for the
+        // lifetime of the `DateEntry`.
+        let entry_ref: &'static Entry = unsafe { &*(&*entry as *const _) };
+        let filter = EntryFilter::new(entry_ref, Some(format),
+                                      date::filter as Filter);
+        DateEntry { _filter: filter, entry, format }
+    }
+
+    ///
+    /// Get the `Entry` to be added to a `Form`.
+    ///
+    pub fn entry(&self) -> &Entry {
+        &self.entry
+    }
+
+    ///
+    /// `Returns` `true` if the `DateEntry` edits a time as well as a date.
+    ///
+    pub fn has_time(&self) -> bool {
+        self.format == DateFormat::DateTime
+    }
+
+    ///
+    /// Get the date entered.
+    ///
+    /// `Returns` `None` if the entered text isn't a valid date.
+    ///
+    pub fn get_date(&self) -> Option<NaiveDate> {
+        self.get_datetime().map(|datetime| datetime.date())
+    }
+
+    ///
+    /// Get the date and time entered. The time is midnight if the
+    /// `DateEntry` doesn't edit a time.
+    ///
+    /// `Returns` `None` if the entered text isn't a valid date and time.
+    ///
+    pub fn get_datetime(&self) -> Option<NaiveDateTime> {
+        self.format.parse(&self.entry.get_text())
+    }
+
+    ///
+    /// Set the date displayed by the `DateEntry`. The time is reset
+    /// to midnight.
+    ///
+    /// * `date` - The new date.
+    ///
+    pub fn set_date(&self, date: NaiveDate) {
+        self.set_datetime(date.and_hms_opt(0, 0, 0).unwrap());
+    }
+
+    ///
+    /// Set the date and time displayed by the `DateEntry`.
+    ///
+    /// * `datetime` - The new date and time.
+    ///
+    pub fn set_datetime(&self, datetime: NaiveDateTime) {
+        self.entry.set_text(&self.format.format(&datetime), false);
+    }
+
+    try_fns! {
+        check = check_destroyed;
+        fn try_get_date = get_date() -> Option<NaiveDate>;
+        fn try_get_datetime = get_datetime() -> Option<NaiveDateTime>;
+        fn try_set_date = set_date(date: NaiveDate);
+        fn try_set_datetime = set_datetime(datetime: NaiveDateTime);
+    }
+
+    fn check_destroyed(&self) -> Result<(), Error> {
+        self.entry.check()?;
+        Ok(())
+    }
+}
+
+impl Drop for DateEntry {
+    fn drop(&mut self) {
+        if !self.entry.is_destroyed() {
+            unsafe { newt_entry_unset_filter(self.entry.co()); }
+        }
+    }
+}
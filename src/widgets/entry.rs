@@ -24,7 +24,9 @@ use std::ptr;
 
 use newt_sys::*;
 use crate::component::Component;
-use crate::constants::FlagsSense;
+use crate::constants::{FlagsSense,FLAG_RETURNEXIT};
+use crate::private::funcs::flags_set;
+use crate::private::traits::ReturnExit;
 
 ///
 /// A field for reading text input from the user.
@@ -93,9 +95,11 @@ use crate::constants::FlagsSense;
 /// ```
 ///
 #[derive(Component)]
+#[newt(manual_return_exit)]
 pub struct Entry {
     co: Cell<newtComponent>,
-    added_to_parent: Cell<bool>
+    added_to_parent: Cell<bool>,
+    flags: Cell<i32>
 }
 
 impl Entry {
@@ -129,7 +133,8 @@ impl Entry {
                                    flags);
                 Cell::new(co)
             },
-            added_to_parent: Cell::new(false)
+            added_to_parent: Cell::new(false),
+            flags: Cell::new(flags)
         }
     }
 
@@ -170,6 +175,7 @@ impl Entry {
     ///
     pub fn set_flags(&self, flags: i32, sense: FlagsSense) {
         unsafe { newtEntrySetFlags(self.co(), flags, sense as u32); }
+        self.flags.set(flags_set(self.flags.get(), flags, sense));
     }
 
     ///
@@ -199,4 +205,19 @@ impl Entry {
     pub fn set_cursor_position(&self, position: i32) {
         unsafe { newtEntrySetCursorPosition(self.co(), position) }
     }
+
+    try_fns! {
+        fn try_get_text = get_text() -> String;
+        fn try_set_text = set_text(text: &str, cursor_at_end: bool);
+        fn try_set_flags = set_flags(flags: i32, sense: FlagsSense);
+        fn try_set_colors = set_colors(normal: i32, disabled: i32);
+        fn try_get_cursor_position = get_cursor_position() -> i32;
+        fn try_set_cursor_position = set_cursor_position(position: i32);
+    }
+}
+
+impl ReturnExit for Entry {
+    fn return_exit(&self) -> bool {
+        self.flags.get() & FLAG_RETURNEXIT != 0
+    }
 }
@@ -73,4 +73,9 @@ impl Label {
     pub fn set_colors(&self, colorset: i32) {
         unsafe { newtLabelSetColors(self.co(), colorset); }
     }
+
+    try_fns! {
+        fn try_set_text = set_text(text: &str);
+        fn try_set_colors = set_colors(colorset: i32);
+    }
 }
@@ -0,0 +1,470 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+use std::cell::{Cell,Ref,RefCell};
+use std::os::raw::c_void;
+
+use newt_sys::*;
+use crate::component::Component;
+use crate::constants::{ARG_APPEND,CHECKBOXTREE_EXPANDED};
+use crate::error::Error;
+use crate::private::funcs::newt_unset_callback;
+use crate::widgets::CheckboxTree;
+
+///
+/// An item provided by a [`TreeSource`].
+///
+pub struct LazyNode<T> {
+    text: String,
+    value: T,
+    has_children: bool
+}
+
+impl<T> LazyNode<T> {
+    ///
+    /// Create a new `LazyNode`.
+    ///
+    /// * `text` - The label to be displayed with the item.
+    /// * `value` - The value associated with the item.
+    /// * `has_children` - Whether the item may be expanded to load its
+    ///   children.
+    ///
+    pub fn new(text: &str, value: T, has_children: bool) -> LazyNode<T> {
+        LazyNode { text: text.to_string(), value, has_children }
+    }
+
+    ///
+    /// `Returns` the label displayed with the item.
+    ///
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    ///
+    /// `Returns` the value associated with the item.
+    ///
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+///
+/// A source of items displayed by a [`LazyTree`].
+///
+pub trait TreeSource {
+    /// The type of the values associated with items.
+    type Value: Clone;
+
+    /// `Returns` the items at the root of the tree.
+    fn roots(&self) -> Vec<LazyNode<Self::Value>>;
+
+    /// `Returns` the children of the item associated with `parent`.
+    fn children(&self, parent: &Self::Value) -> Vec<LazyNode<Self::Value>>;
+}
+
+///
+/// A tree loading the children of an item the first time it's expanded.
+///
+/// Items are provided by a [`TreeSource`]. An item declared as having
+/// children is shown with a placeholder child until it's expanded, the
+/// children are then loaded from the `TreeSource` and kept until
+/// [`refresh()`][refresh] is called. The [`CheckboxTree`] returned by
+/// [`tree()`][tree] should be added to a `Form`.
+///
+/// newt provides no means of removing items from a `CheckboxTree`. When
+/// an item has fewer children after a refresh the remaining items are
+/// left blank.
+///
+/// The `LazyTree` uses the `CheckboxTree`'s [`Callback`][callback], so
+/// another callback must not be set on it.
+///
+/// [callback]: crate::callbacks::Callback
+/// [refresh]: LazyTree::refresh
+/// [tree]: LazyTree::tree
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::prelude::*;
+/// use std::fs;
+/// use std::path::PathBuf;
+///
+/// struct Directories;
+///
+/// impl TreeSource for Directories {
+///     type Value = PathBuf;
+///
+///     fn roots(&self) -> Vec<LazyNode<PathBuf>> {
+///         vec![LazyNode::new("/", PathBuf::from("/"), true)]
+///     }
+///
+///     fn children(&self, parent: &PathBuf) -> Vec<LazyNode<PathBuf>> {
+///         let mut children: Vec<LazyNode<PathBuf>> = fs::read_dir(parent)
+///             .into_iter().flatten().flatten()
+///             .map(|entry| {
+///                 let name = entry.file_name().to_string_lossy()
+///                     .into_owned();
+///                 let is_dir = entry.path().is_dir();
+///                 LazyNode::new(&name, entry.path(), is_dir)
+///             })
+///             .collect();
+///         children.sort_by(|a, b| a.text().cmp(b.text()));
+///         children
+///     }
+/// }
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     newt::centered_window(40, 12, Some("Files")).unwrap();
+///
+///     let tree = LazyTree::new(1, 1, 10, Directories, 0);
+///     tree.tree().set_width(38);
+///
+///     let mut form = Form::new(None, 0);
+///     form.add_component(tree.tree()).unwrap();
+///     form.run().unwrap();
+///     newt::finished();
+///
+///     println!("Selected: {:?}", tree.get_selection());
+/// }
+/// ```
+///
+pub struct LazyTree<S: TreeSource> {
+    inner: Box<Inner<S>>
+}
+
+struct Slot<T> {
+    value: Option<T>,
+    has_children: bool,
+    loaded: bool,
+    children: Vec<usize>
+}
+
+struct Inner<S: TreeSource> {
+    tree: CheckboxTree<usize>,
+    source: RefCell<S>,
+    slots: RefCell<Vec<Slot<S::Value>>>,
+    roots: RefCell<Vec<usize>>,
+    updating: Cell<bool>
+}
+
+const PLACEHOLDER: &str = "...";
+const EMPTY: &str = "(empty)";
+
+impl<S: TreeSource> LazyTree<S> {
+    ///
+    /// Create a new `LazyTree`.
+    ///
+    /// * `left` - The left-most position of the `LazyTree`.
+    /// * `top` - The top-most position of the `LazyTree`.
+    /// * `height` - The height of the `LazyTree`.
+    /// * `source` - The source of the items to be displayed.
+    /// * `flags` - [Flags][checkboxtree] modifying the behavior of the
+    ///   `CheckboxTree`.
+    ///
+    /// [checkboxtree]: crate::constants::checkboxtree
+    ///
+    pub fn new(left: i32, top: i32, height: i32, source: S, flags: i32)
+      -> LazyTree<S> {
+        let inner = Box::new(Inner {
+            tree: CheckboxTree::new(left, top, height, None, flags),
+            source: RefCell::new(source),
+            slots: RefCell::new(Vec::new()),
+            roots: RefCell::new(Vec::new()),
+            updating: Cell::new(false)
+        });
+
+        unsafe {
+            let c_ptr = inner.as_ref() as *const _ as *mut c_void;
+            newtComponentAddCallback(inner.tree.co(), Some(callback::<S>),
+                                     c_ptr);
+        }
+
+        inner.fill(None);
+        LazyTree { inner }
+    }
+
+    ///
+    /// `Returns` the `CheckboxTree` displaying the items.
+    ///
+    pub fn tree(&self) -> &CheckboxTree<usize> {
+        &self.inner.tree
+    }
+
+    ///
+    /// `Returns` the source of the items.
+    ///
+    pub fn source(&self) -> Ref<'_, S> {
+        self.inner.source.borrow()
+    }
+
+    ///
+    /// Reload the items after the source has changed.
+    ///
+    /// Loaded items that are collapsed are loaded again when they're next
+    /// expanded.
+    ///
+    pub fn refresh(&self) {
+        let inner = &self.inner;
+        inner.updating.set(true);
+        inner.fill(None);
+        inner.rebuild();
+        inner.updating.set(false);
+    }
+
+    ///
+    /// Get the value of the currently selected item.
+    ///
+    pub fn get_current(&self) -> Option<S::Value> {
+        self.inner.tree.get_current().and_then(|key| self.inner.value(key))
+    }
+
+    ///
+    /// Get the values of the checked items.
+    ///
+    pub fn get_selection(&self) -> Box<[S::Value]> {
+        self.inner.tree.get_selection().iter()
+            .filter_map(|key| self.inner.value(*key))
+            .collect()
+    }
+
+    try_fns! {
+        check = check_destroyed;
+        fn try_refresh = refresh();
+        fn try_get_current = get_current() -> Option<S::Value>;
+        fn try_get_selection = get_selection() -> Box<[S::Value]>;
+    }
+
+    fn check_destroyed(&self) -> Result<(), Error> {
+        self.inner.tree.check()?;
+        Ok(())
+    }
+}
+
+impl<S: TreeSource> LazyTree<S> where S::Value: PartialEq {
+    ///
+    /// Load the children of an item and show them.
+    ///
+    /// * `value` - The value associated with the item.
+    ///
+    pub fn expand(&self, value: &S::Value) {
+        let inner = &self.inner;
+        let key = match inner.key(value) {
+            Some(key) => key,
+            None => return
+        };
+
+        inner.updating.set(true);
+        if inner.needs_load(key) {
+            inner.fill(Some(key));
+        }
+        if let Some(handle) = inner.tree.handle(key) {
+            inner.tree.expand(&handle);
+        }
+        inner.updating.set(false);
+    }
+
+    try_fns! {
+        check = check_destroyed;
+        fn try_expand = expand(value: &S::Value);
+    }
+}
+
+impl<S: TreeSource> Drop for LazyTree<S> {
+    fn drop(&mut self) {
+        if !self.inner.tree.is_destroyed() {
+            unsafe { newt_unset_callback(&self.inner.tree); }
+        }
+    }
+}
+
+impl<S: TreeSource> Inner<S> {
+    fn value(&self, key: usize) -> Option<S::Value> {
+        self.slots.borrow().get(key.checked_sub(1)?)?.value.clone()
+    }
+
+    fn key(&self, value: &S::Value) -> Option<usize>
+        where S::Value: PartialEq
+    {
+        self.slots.borrow().iter()
+            .position(|slot| slot.value.as_ref() == Some(value))
+            .map(|index| index + 1)
+    }
+
+    fn is_expanded(&self, key: usize) -> bool {
+        self.tree.get_entry_value(key) == CHECKBOXTREE_EXPANDED as u8 as char
+    }
+
+    fn needs_load(&self, key: usize) -> bool {
+        let slots = self.slots.borrow();
+        let slot = &slots[key - 1];
+        slot.has_children && !slot.loaded && slot.value.is_some()
+    }
+
+    fn add_slot(&self, parent: &[i32], text: &str) -> usize {
+        let key = {
+            let mut slots = self.slots.borrow_mut();
+            slots.push(Slot {
+                value: None,
+                has_children: false,
+                loaded: false,
+                children: Vec::new()
+            });
+            slots.len()
+        };
+
+        let mut indexes = parent.to_vec();
+        indexes.push(ARG_APPEND);
+        self.tree.add_item(text, key, 0, Some(&indexes));
+        key
+    }
+
+    //
+    // Load the children of the item `parent`, or the root items, from the
+    // source. Existing items are reused.
+    //
+    fn fill(&self, parent: Option<usize>) {
+        let nodes = {
+            let source = self.source.borrow();
+            match parent {
+                Some(key) => match self.value(key) {
+                    Some(value) => source.children(&value),
+                    None => return
+                },
+                None => source.roots()
+            }
+        };
+        let path = match parent {
+            Some(key) => self.tree.find_item(key).to_vec(),
+            None => Vec::new()
+        };
+        let existing = match parent {
+            Some(key) => self.slots.borrow()[key - 1].children.clone(),
+            None => self.roots.borrow().clone()
+        };
+
+        let mut children = Vec::with_capacity(nodes.len());
+        for (index, node) in nodes.into_iter().enumerate() {
+            let key = match existing.get(index) {
+                Some(key) => {
+                    self.tree.set_entry(*key, &node.text);
+                    *key
+                },
+                None => self.add_slot(&path, &node.text)
+            };
+            children.push(key);
+            self.set_slot(key, node);
+        }
+
+        for (index, key) in existing.iter().enumerate().skip(children.len()) {
+            let text = match (index, parent) {
+                (0, Some(_)) => EMPTY,
+                _ => ""
+            };
+            self.clear_slot(*key, text);
+            children.push(*key);
+        }
+
+        match parent {
+            Some(key) => {
+                let mut slots = self.slots.borrow_mut();
+                slots[key - 1].children = children;
+                slots[key - 1].loaded = true;
+            },
+            None => *self.roots.borrow_mut() = children
+        }
+    }
+
+    fn set_slot(&self, key: usize, node: LazyNode<S::Value>) {
+        let (reload, placeholder) = {
+            let mut slots = self.slots.borrow_mut();
+            let slot = &mut slots[key - 1];
+            let reload = slot.loaded;
+            slot.value = Some(node.value);
+            slot.has_children = node.has_children;
+            slot.loaded = false;
+            (reload, node.has_children && slot.children.is_empty())
+        };
+
+        if placeholder {
+            let path = self.tree.find_item(key);
+            let child = self.add_slot(&path, PLACEHOLDER);
+            self.slots.borrow_mut()[key - 1].children.push(child);
+        }
+
+        if reload && self.is_expanded(key) {
+            self.fill(Some(key));
+        }
+    }
+
+    fn clear_slot(&self, key: usize, text: &str) {
+        let children = {
+            let mut slots = self.slots.borrow_mut();
+            let slot = &mut slots[key - 1];
+            slot.value = None;
+            slot.has_children = false;
+            slot.loaded = true;
+            slot.children.clone()
+        };
+
+        self.tree.set_entry(key, text);
+        for child in children {
+            self.clear_slot(child, "");
+        }
+    }
+
+    //
+    // Rebuild the list of items shown, which newt doesn't do when items
+    // are added.
+    //
+    fn rebuild(&self) {
+        if let Some(current) = self.tree.get_current() {
+            self.tree.set_current(current);
+        }
+    }
+
+    //
+    // Load the children of items expanded since the last update.
+    //
+    fn update(&self) {
+        if self.updating.get() {
+            return;
+        }
+
+        self.updating.set(true);
+        let count = self.slots.borrow().len();
+        let mut loaded = false;
+        for key in 1..=count {
+            if self.needs_load(key) && self.is_expanded(key) {
+                self.fill(Some(key));
+                loaded = true;
+            }
+        }
+        if loaded {
+            self.rebuild();
+        }
+        self.updating.set(false);
+    }
+}
+
+unsafe extern "C" fn callback<S: TreeSource>(_co: newtComponent,
+                                             data: *mut c_void) {
+    let inner = &*(data as *const Inner<S>);
+    inner.update();
+}
@@ -18,6 +18,7 @@
 //
 
 use std::cell::Cell;
+use std::cmp::Ordering;
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 use std::os::raw::{c_char,c_uint,c_void};
@@ -25,16 +26,20 @@ use std::ptr;
 
 use newt_sys::*;
 use crate::component::Component;
-use crate::constants::FlagsSense;
-use crate::private::data::Data;
+use crate::constants::{FlagsSense,FLAG_RETURNEXIT};
+use crate::data::Data;
+use crate::error::Error;
+use crate::private::traits::ReturnExit;
 
 ///
 /// A widget for displaying a list of selectable items.
 ///
 #[derive(Component)]
+#[newt(manual_return_exit)]
 pub struct Listbox<D: Data = isize> {
     co: Cell<newtComponent>,
     added_to_parent: Cell<bool>,
+    flags: Cell<i32>,
     data: PhantomData<D>
 }
 
@@ -59,6 +64,7 @@ impl<D: Data> Listbox<D> {
                 Cell::new(co)
             },
             added_to_parent: Cell::new(false),
+            flags: Cell::new(flags),
             data: PhantomData
         }
     }
@@ -79,6 +85,30 @@ impl<D: Data> Listbox<D> {
         unsafe { newtListboxItemCount(self.co()) }
     }
 
+    ///
+    /// Get the number of items in the `Listbox`.
+    ///
+    pub fn len(&self) -> usize {
+        self.item_count() as usize
+    }
+
+    ///
+    /// `Returns` `true` if the `Listbox` contains no items.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.item_count() == 0
+    }
+
+    ///
+    /// Iterate over the items in the `Listbox`.
+    ///
+    /// `Returns` an iterator of (`text`, `data`) tuples containing each
+    /// item's display text and the user `Data` associated with it.
+    ///
+    pub fn iter(&self) -> ListboxIter<'_, D> {
+        ListboxIter { listbox: self, num: 0, count: self.item_count() }
+    }
+
     ///
     /// Add a new item to the `Listbox`.
     ///
@@ -117,13 +147,30 @@ impl<D: Data> Listbox<D> {
         if rv == 0 { Ok(()) } else { Err(()) }
     }
 
+    ///
+    /// Add items from an iterator to the end of the `Listbox`.
+    ///
+    /// * `entries` - An iterator of (`text`, `data`) tuples.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if newt fails to add an item.
+    ///
+    pub fn extend<I, S>(&self, entries: I) -> Result<(), Error>
+        where I: IntoIterator<Item = (S, D)>, S: AsRef<str>
+    {
+        for (text, data) in entries {
+            self.append_entry(text.as_ref(), data)
+                .map_err(|_| Error::InvalidItem)?;
+        }
+        Ok(())
+    }
+
     ///
     /// Get the user `Data` of the currently selected item in the `Listbox`.
     ///
     pub fn get_current(&self) -> Option<D> {
         let c_data = unsafe { newtListboxGetCurrent(self.co()) };
         if c_data.is_null() { return None; }
-        Some(D::newt_from_ptr(c_data))
+        Some(unsafe { D::newt_from_ptr(c_data) })
     }
 
     ///
@@ -160,15 +207,23 @@ impl<D: Data> Listbox<D> {
     /// `Returns` a tuple (`text`, `data`) containing the items display text
     /// and the user `Data` associated with it.
     ///
-    pub fn get_entry(&self, num: i32) -> (&str, D) {
-        let mut c_str: *mut c_char = ptr::null_mut();
-        let mut c_data: *mut c_void = ptr::null_mut();
+    pub fn get_entry(&self, num: i32) -> (String, D) {
+        let (c_str, c_data) = self.raw_entry(num);
+        let data = unsafe { D::newt_from_ptr(c_data) };
+        (c_str.to_string_lossy().into_owned(), data)
+    }
 
-        unsafe {
-            newtListboxGetEntry(self.co(), num, &mut c_str, &mut c_data);
-        }
-        let c_str = unsafe { CStr::from_ptr(c_str) };
-        (c_str.to_str().unwrap(), D::newt_from_ptr(c_data))
+    ///
+    /// Find the index number of an item by associated user `Data`.
+    ///
+    /// * `data` - The user `Data` associated with the item.
+    ///
+    /// `Returns` the index number of the first item associated with
+    /// `data`.
+    ///
+    pub fn position(&self, data: D) -> Option<i32> {
+        let key = data.newt_to_ptr();
+        (0..self.item_count()).find(|num| self.raw_entry(*num).1 == key)
     }
 
     ///
@@ -221,6 +276,66 @@ impl<D: Data> Listbox<D> {
         unsafe { newtListboxClear(self.co()); }
     }
 
+    ///
+    /// Keep only the items for which `f` returns `true`.
+    ///
+    /// * `f` - A function receiving each item's display text and user `Data`.
+    ///
+    pub fn retain<F>(&self, mut f: F)
+        where F: FnMut(&str, &D) -> bool
+    {
+        let mut entries = self.raw_entries();
+        entries.retain(|(text, data)| {
+            f(&text.to_string_lossy(), &unsafe { D::newt_from_ptr(*data) })
+        });
+        self.rebuild(&entries, &self.raw_selection());
+    }
+
+    ///
+    /// Sort the items in the `Listbox` with a comparison function.
+    ///
+    /// * `compare` - A function comparing two items' display text and user
+    ///   `Data`.
+    ///
+    pub fn sort_by<F>(&self, mut compare: F)
+        where F: FnMut((&str, &D), (&str, &D)) -> Ordering
+    {
+        let mut entries = self.raw_entries();
+        entries.sort_by(|(text1, data1), (text2, data2)| {
+            let (data1, data2) = unsafe {
+                (D::newt_from_ptr(*data1), D::newt_from_ptr(*data2))
+            };
+            compare((&text1.to_string_lossy(), &data1),
+                    (&text2.to_string_lossy(), &data2))
+        });
+        self.rebuild(&entries, &self.raw_selection());
+    }
+
+    ///
+    /// Swap two items in the `Listbox` by index number.
+    ///
+    /// * `a` - The index number of the first item.
+    /// * `b` - The index number of the second item.
+    ///
+    pub fn swap(&self, a: i32, b: i32) {
+        let mut entries = self.raw_entries();
+        entries.swap(self.index(a), self.index(b));
+        self.rebuild(&entries, &self.raw_selection());
+    }
+
+    ///
+    /// Move an item in the `Listbox` to a new index number.
+    ///
+    /// * `from` - The index number of the item to be moved.
+    /// * `to` - The new index number of the item.
+    ///
+    pub fn move_item(&self, from: i32, to: i32) {
+        let mut entries = self.raw_entries();
+        let entry = entries.remove(self.index(from));
+        entries.insert(self.index(to), entry);
+        self.rebuild(&entries, &self.raw_selection());
+    }
+
     ///
     /// Get the current selections in the `Listbox`.
     ///
@@ -275,4 +390,139 @@ impl<D: Data> Listbox<D> {
     pub fn clear_selection(&self) {
         unsafe { newtListboxClearSelection(self.co()) };
     }
+
+    fn index(&self, num: i32) -> usize {
+        if num < 0 || num >= self.item_count() {
+            panic!("Invalid index number {}", num);
+        }
+        num as usize
+    }
+
+    fn raw_entry(&self, num: i32) -> (&CStr, *const c_void) {
+        let mut c_str: *mut c_char = ptr::null_mut();
+        let mut c_data: *mut c_void = ptr::null_mut();
+
+        self.index(num);
+        unsafe {
+            newtListboxGetEntry(self.co(), num, &mut c_str, &mut c_data);
+            (CStr::from_ptr(c_str), c_data)
+        }
+    }
+
+    pub(crate) fn raw_entries(&self) -> Vec<(CString, *const c_void)> {
+        (0..self.item_count()).map(|num| {
+            let (c_str, c_data) = self.raw_entry(num);
+            (c_str.to_owned(), c_data)
+        }).collect()
+    }
+
+    pub(crate) fn raw_selection(&self) -> Vec<*const c_void> {
+        let mut numitems: i32 = 0;
+        unsafe {
+            let ptr = newtListboxGetSelection(self.co(), &mut numitems);
+            if ptr.is_null() {
+                return Vec::new();
+            }
+            let selection = (0..numitems as usize)
+                .map(|i| *ptr.add(i) as *const c_void)
+                .collect();
+            libc::free(ptr as *mut libc::c_void);
+            selection
+        }
+    }
+
+    //
+    // Replace the items in the `Listbox`, keeping the current item if it
+    // remains and selecting the items associated with `selection`.
+    //
+    pub(crate) fn rebuild(&self, entries: &[(CString, *const c_void)],
+                          selection: &[*const c_void]) {
+        let co = self.co();
+        unsafe {
+            let current = newtListboxGetCurrent(co);
+            newtListboxClear(co);
+            for (c_str, c_data) in entries {
+                newtListboxAppendEntry(co, c_str.as_ptr(), *c_data);
+            }
+
+            for key in selection {
+                newtListboxSelectItem(co, *key, FlagsSense::Set as c_uint);
+            }
+
+            newtListboxSetCurrent(co, 0);
+            if !current.is_null() {
+                newtListboxSetCurrentByKey(co, current as *mut c_void);
+            }
+        }
+    }
+
+    try_fns! {
+        fn try_set_width = set_width(width: i32);
+        fn try_item_count = item_count() -> i32;
+        fn try_append_entry = append_entry(text: &str, data: D)
+            -> Result<(), ()>;
+        fn try_insert_entry = insert_entry(text: &str, data: D, key: D)
+            -> Result<(), ()>;
+        fn try_get_current = get_current() -> Option<D>;
+        fn try_set_current = set_current(num: i32);
+        fn try_set_current_by_key = set_current_by_key(key: D);
+        fn try_get_entry = get_entry(num: i32) -> (String, D);
+        fn try_position = position(data: D) -> Option<i32>;
+        fn try_set_entry = set_entry(num: i32, text: &str);
+        fn try_set_data = set_data(num: i32, data: D);
+        fn try_delete_entry = delete_entry(data: D) -> i32;
+        fn try_clear = clear();
+        fn try_swap = swap(a: i32, b: i32);
+        fn try_move_item = move_item(from: i32, to: i32);
+        fn try_get_selection = get_selection() -> Box<[D]>;
+        fn try_select_item = select_item(key: D, sense: FlagsSense);
+        fn try_select_items = select_items(keys: &[D], sense: FlagsSense);
+        fn try_clear_selection = clear_selection();
+    }
+}
+
+///
+/// An iterator over the items in a [`Listbox`].
+///
+/// Created by [`Listbox::iter()`].
+///
+pub struct ListboxIter<'a, D: Data> {
+    listbox: &'a Listbox<D>,
+    num: i32,
+    count: i32
+}
+
+impl<'a, D: Data> Iterator for ListboxIter<'a, D> {
+    type Item = (String, D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num >= self.count {
+            return None;
+        }
+        let entry = self.listbox.get_entry(self.num);
+        self.num += 1;
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.num) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, D: Data> ExactSizeIterator for ListboxIter<'a, D> { }
+
+impl<'a, D: Data> IntoIterator for &'a Listbox<D> {
+    type Item = (String, D);
+    type IntoIter = ListboxIter<'a, D>;
+
+    fn into_iter(self) -> ListboxIter<'a, D> {
+        self.iter()
+    }
+}
+
+impl<D: Data> ReturnExit for Listbox<D> {
+    fn return_exit(&self) -> bool {
+        self.flags.get() & FLAG_RETURNEXIT != 0 && self.item_count() > 0
+    }
 }
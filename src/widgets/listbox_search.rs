@@ -0,0 +1,298 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::os::raw::c_void;
+use std::rc::Rc;
+
+use crate::callbacks::EntryFilter;
+use crate::component::Component;
+use crate::constants::{FlagsSense,FLAG_SCROLL};
+#[cfg(test)]
+use crate::constants::{KEY_BKSPC,KEY_DELETE};
+use crate::data::Data;
+use crate::error::Error;
+use crate::private::funcs::{entry_edit as edit,newt_entry_unset_filter};
+use crate::widgets::{Entry,Listbox};
+
+///
+/// The method used by a [`ListboxSearch`] to search a `Listbox`.
+///
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum SearchMode {
+    /// Set the current item to the first item starting with the search
+    /// text.
+    Jump,
+
+    /// Show only the items containing the search text.
+    Filter
+}
+
+type SearchFilter<'a, D> =
+    fn(&Entry, Option<&Rc<SearchState<'a, D>>>, char, i32) -> char;
+
+///
+/// Search the items of a [`Listbox`] with text entered into an [`Entry`].
+///
+/// The `Listbox` is searched as text is typed into the `Entry`. Matching
+/// is case insensitive. In [`SearchMode::Filter`] mode items not
+/// containing the search text are removed from the `Listbox` and
+/// restored once the search text is cleared. The `Data` associated with
+/// items is unchanged, so [`Listbox::get_current()`] continues to return
+/// the original `Data`. Use [`ListboxSearch::get_selection()`] to include
+/// selected items that are currently hidden.
+///
+/// Items should be added to the `Listbox` before the `ListboxSearch` is
+/// created. The `Entry` is set to scroll its text. It must not be used
+/// with another [`EntryFilter`].
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::prelude::*;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     newt::centered_window(22, 10, Some("Hosts")).unwrap();
+///
+///     let entry = Entry::new(1, 1, None, 20, 0);
+///     let listbox: Listbox<usize> = Listbox::new(1, 3, 6, FLAG_MULTIPLE);
+///     for (i, host) in ["alpha", "bravo", "charlie"].iter().enumerate() {
+///         listbox.append_entry(host, i + 1).unwrap();
+///     }
+///     let search = ListboxSearch::new(&entry, &listbox, SearchMode::Filter);
+///
+///     let mut form = Form::new(None, 0);
+///     form.add_components(&[&entry, &listbox]).unwrap();
+///     form.run().unwrap();
+///     newt::finished();
+///
+///     println!("Selected: {:?}", search.get_selection());
+/// }
+/// ```
+///
+pub struct ListboxSearch<'a, D: Data> {
+    entry: &'a Entry,
+    state: Rc<SearchState<'a, D>>,
+    _filter: Box<EntryFilter<'a, SearchFilter<'a, D>, Rc<SearchState<'a, D>>>>
+}
+
+struct SearchState<'a, D: Data> {
+    listbox: &'a Listbox<D>,
+    mode: SearchMode,
+    entries: Vec<(CString, *const c_void)>,
+    visible: RefCell<Vec<usize>>,
+    selected: RefCell<HashSet<*const c_void>>
+}
+
+impl<'a, D: Data> ListboxSearch<'a, D> {
+    ///
+    /// Create a new `ListboxSearch`.
+    ///
+    /// * `entry` - The `Entry` the search text is entered into.
+    /// * `listbox` - The `Listbox` to be searched.
+    /// * `mode` - The method used to search the `Listbox`.
+    ///
+    pub fn new(entry: &'a Entry, listbox: &'a Listbox<D>, mode: SearchMode)
+      -> ListboxSearch<'a, D> {
+        let entries = listbox.raw_entries();
+        let state = Rc::new(SearchState {
+            listbox,
+            mode,
+            visible: RefCell::new((0..entries.len()).collect()),
+            selected: RefCell::new(listbox.raw_selection()
+                                          .into_iter().collect()),
+            entries
+        });
+
+        entry.set_flags(FLAG_SCROLL, FlagsSense::Set);
+        let function: SearchFilter<'a, D> = filter::<D>;
+        let filter = EntryFilter::new(entry, Some(state.clone()), function);
+        ListboxSearch { entry, state, _filter: filter }
+    }
+
+    ///
+    /// Search the `Listbox` for `text` as if it were entered into the
+    /// `Entry`. The `Entry` itself is not modified.
+    ///
+    /// * `text` - The text to search for.
+    ///
+    pub fn search(&self, text: &str) {
+        self.state.search(text);
+    }
+
+    ///
+    /// Clear the search text from the `Entry`, restoring any items
+    /// hidden from the `Listbox`.
+    ///
+    pub fn clear(&self) {
+        self.entry.set_text("", true);
+        self.state.search("");
+    }
+
+    ///
+    /// Get the user `Data` of the currently selected item in the `Listbox`.
+    ///
+    pub fn get_current(&self) -> Option<D> {
+        self.state.listbox.get_current()
+    }
+
+    ///
+    /// Get the current selections in the `Listbox`, including selected
+    /// items hidden by the search.
+    ///
+    /// `Returns` an array of the user `Data` associated with the current
+    /// selections.
+    ///
+    pub fn get_selection(&self) -> Box<[D]> {
+        self.state.sync_selection();
+        let selected = self.state.selected.borrow();
+        self.state.entries.iter()
+            .filter(|(_, data)| selected.contains(data))
+            .map(|(_, data)| unsafe { D::newt_from_ptr(*data) })
+            .collect()
+    }
+
+    try_fns! {
+        check = check_destroyed;
+        fn try_search = search(text: &str);
+        fn try_clear = clear();
+        fn try_get_current = get_current() -> Option<D>;
+        fn try_get_selection = get_selection() -> Box<[D]>;
+    }
+
+    fn check_destroyed(&self) -> Result<(), Error> {
+        self.entry.check()?;
+        self.state.listbox.check()?;
+        Ok(())
+    }
+}
+
+impl<'a, D: Data> Drop for ListboxSearch<'a, D> {
+    fn drop(&mut self) {
+        if !self.entry.is_destroyed() {
+            unsafe { newt_entry_unset_filter(self.entry.co()); }
+        }
+    }
+}
+
+impl<'a, D: Data> SearchState<'a, D> {
+    fn search(&self, text: &str) {
+        let pattern = text.to_lowercase();
+        match self.mode {
+            SearchMode::Jump => {
+                if pattern.is_empty() {
+                    return;
+                }
+
+                let visible = self.visible.borrow();
+                let found = visible.iter().position(|i| {
+                    self.text(*i).starts_with(&pattern)
+                });
+                if let Some(num) = found {
+                    self.listbox.set_current(num as i32);
+                }
+            },
+
+            SearchMode::Filter => {
+                self.sync_selection();
+                let visible: Vec<usize> = (0..self.entries.len())
+                    .filter(|i| self.text(*i).contains(&pattern))
+                    .collect();
+
+                let selected = self.selected.borrow();
+                let entries: Vec<(CString, *const c_void)> = visible.iter()
+                    .map(|i| self.entries[*i].clone())
+                    .collect();
+                let selection: Vec<*const c_void> = entries.iter()
+                    .map(|(_, data)| *data)
+                    .filter(|data| selected.contains(data))
+                    .collect();
+
+                self.listbox.rebuild(&entries, &selection);
+                *self.visible.borrow_mut() = visible;
+            }
+        }
+    }
+
+    //
+    // Record the selection status of the items currently displayed in the
+    // `Listbox`.
+    //
+    fn sync_selection(&self) {
+        let current: HashSet<*const c_void> =
+            self.listbox.raw_selection().into_iter().collect();
+        let mut selected = self.selected.borrow_mut();
+        for i in self.visible.borrow().iter() {
+            let data = self.entries[*i].1;
+            if current.contains(&data) {
+                selected.insert(data);
+            } else {
+                selected.remove(&data);
+            }
+        }
+    }
+
+    fn text(&self, index: usize) -> String {
+        self.entries[index].0.to_string_lossy().to_lowercase()
+    }
+}
+
+fn filter<D: Data>(entry: &Entry, state: Option<&Rc<SearchState<'_, D>>>,
+                   ch: char, cursor: i32) -> char {
+    if let Some(state) = state {
+        if let Some(text) = edit(&entry.get_text(), ch, cursor) {
+            state.search(&text);
+        }
+    }
+    ch
+}
+
+#[test]
+fn search_edit_insert() {
+    assert_eq!(edit("ac", 'b', 1).as_deref(), Some("abc"));
+    assert_eq!(edit("ab", 'c', 2).as_deref(), Some("abc"));
+    assert_eq!(edit("ab", '\u{e9}', 1).as_deref(), Some("a\u{e9}b"));
+    assert_eq!(edit("a\u{e9}", '\u{4e2d}', 3).as_deref(),
+               Some("a\u{e9}\u{4e2d}"));
+}
+
+#[test]
+fn search_edit_delete() {
+    let bkspc = char::from_u32(KEY_BKSPC as u32).unwrap();
+    let delete = char::from_u32(KEY_DELETE as u32).unwrap();
+    assert_eq!(edit("abc", bkspc, 2).as_deref(), Some("ac"));
+    assert_eq!(edit("abc", bkspc, 0), None);
+    assert_eq!(edit("abc", delete, 0).as_deref(), Some("bc"));
+    assert_eq!(edit("abc", delete, 3), None);
+    assert_eq!(edit("a\u{e9}b", bkspc, 3).as_deref(), Some("ab"));
+    assert_eq!(edit("abc", '\u{08}', 2).as_deref(), Some("ac"));
+    assert_eq!(edit("abc", '\u{7f}', 2).as_deref(), Some("ac"));
+    assert_eq!(edit("abc", '\u{7f}', 0), None);
+}
+
+#[test]
+fn search_edit_kill() {
+    assert_eq!(edit("abcd", '\u{0b}', 1).as_deref(), Some("a"));
+    assert_eq!(edit("abcd", '\u{15}', 1).as_deref(), Some("bcd"));
+    assert_eq!(edit("abcd", '\r', 1), None);
+}
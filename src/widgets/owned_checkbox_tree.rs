@@ -0,0 +1,241 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::cell::RefCell;
+
+use crate::component::Component;
+use crate::private::slab::Slab;
+use crate::widgets::CheckboxTree;
+
+///
+/// A [`CheckboxTree`] holding owned values.
+///
+/// Unlike `CheckboxTree` the values associated with items are not limited
+/// to types that fit in a pointer. Values are stored by the
+/// `OwnedCheckboxTree` and cloned when returned. Functions identifying an
+/// item by its value require `T` to implement `PartialEq`.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::prelude::*;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     newt::centered_window(20, 7, Some("Packages")).unwrap();
+///
+///     let tree = OwnedCheckboxTree::new(0, 0, 5, None, 0);
+///     tree.add_item("Editors", String::from("editors"), 0, Some(&[0]));
+///     tree.add_item("vim", String::from("vim"), 0, Some(&[0, ARG_APPEND]));
+///     tree.add_item("emacs", String::from("emacs"), 0,
+///                   Some(&[0, ARG_APPEND]));
+///     let ok = CompactButton::new(7, 6, "Ok");
+///
+///     let mut form = Form::new(None, 0);
+///     form.add_components(&[&tree, &ok]).unwrap();
+///     form.run().unwrap();
+///     newt::finished();
+///
+///     println!("selection: {:?}", tree.get_selection());
+/// }
+/// ```
+///
+#[derive(Component)]
+pub struct OwnedCheckboxTree<T: Clone> {
+    #[component]
+    tree: CheckboxTree<usize>,
+    values: RefCell<Slab<T>>
+}
+
+impl<T: Clone> OwnedCheckboxTree<T> {
+    ///
+    /// Create a new `OwnedCheckboxTree`.
+    ///
+    /// See [`CheckboxTree::new()`] for a description of the parameters.
+    ///
+    pub fn new(left: i32, top: i32, height: i32, sequence: Option<&[char]>,
+               flags: i32) -> OwnedCheckboxTree<T>
+    {
+        OwnedCheckboxTree {
+            tree: CheckboxTree::new(left, top, height, sequence, flags),
+            values: RefCell::new(Slab::new())
+        }
+    }
+
+    ///
+    /// Set the width of the `OwnedCheckboxTree`.
+    ///
+    /// * `width` - The width to set the `OwnedCheckboxTree`.
+    ///
+    pub fn set_width(&self, width: i32) {
+        self.tree.set_width(width);
+    }
+
+    ///
+    /// Add an additional checkbox to the `OwnedCheckboxTree`.
+    ///
+    /// See [`CheckboxTree::add_item()`] for a description of item indexes.
+    ///
+    /// * `text` - The label to be displayed with the checkbox.
+    /// * `value` - The value to be associated with the checkbox.
+    /// * `flags` - [Flags][flags] modifying the behavior of the checkbox.
+    /// * `indexes` - The optional index of the item in the tree.
+    ///
+    /// `Returns` `-1` on error, `0` on success
+    ///
+    /// [flags]: crate::constants::checkboxtree
+    ///
+    pub fn add_item(&self, text: &str, value: T, flags: i32,
+                    indexes: Option<&[i32]>) -> i32 {
+        let key = self.values.borrow_mut().insert(value);
+        let rv = self.tree.add_item(text, key, flags, indexes);
+        if rv != 0 {
+            self.values.borrow_mut().remove(key);
+        }
+        rv
+    }
+
+    ///
+    /// Get the currently selected `OwnedCheckboxTree` item.
+    ///
+    /// `Returns` the value of the currently selected item.
+    ///
+    pub fn get_current(&self) -> Option<T> {
+        self.tree.get_current().and_then(|key| self.value(key))
+    }
+
+    ///
+    /// Get the currently selected checkbox items.
+    ///
+    /// `Returns` an array of the values of the selected checkboxes.
+    ///
+    pub fn get_selection(&self) -> Box<[T]> {
+        self.values(&self.tree.get_selection())
+    }
+
+    ///
+    /// Select multiple checkbox items by their current checkbox value.
+    /// See [`CheckboxTree::get_multi_selection()`].
+    ///
+    /// * `seqval` - The `sequence` value by which to select checkbox items.
+    ///
+    /// `Returns` an array of the values of the selected checkboxes.
+    ///
+    pub fn get_multi_selection(&self, seqval: char) -> Box<[T]> {
+        self.values(&self.tree.get_multi_selection(seqval))
+    }
+
+    try_fns! {
+        fn try_set_width = set_width(width: i32);
+        fn try_add_item = add_item(text: &str, value: T, flags: i32,
+                                   indexes: Option<&[i32]>) -> i32;
+        fn try_get_current = get_current() -> Option<T>;
+        fn try_get_selection = get_selection() -> Box<[T]>;
+        fn try_get_multi_selection = get_multi_selection(seqval: char)
+            -> Box<[T]>;
+    }
+
+    fn value(&self, key: usize) -> Option<T> {
+        self.values.borrow().get(key).cloned()
+    }
+
+    fn values(&self, keys: &[usize]) -> Box<[T]> {
+        let values = self.values.borrow();
+        keys.iter().filter_map(|key| values.get(*key).cloned()).collect()
+    }
+}
+
+impl<T: Clone + PartialEq> OwnedCheckboxTree<T> {
+    ///
+    /// Set the currently selected `OwnedCheckboxTree` item.
+    ///
+    /// * `value` - The value of the item to be selected.
+    ///
+    pub fn set_current(&self, value: &T) {
+        if let Some(key) = self.key(value) {
+            self.tree.set_current(key);
+        }
+    }
+
+    ///
+    /// Find an item by its associated value.
+    ///
+    /// * `value` - The value associated with the item.
+    ///
+    /// `Returns` an array of integers representing the item's location
+    /// index in the tree. The array is empty if no item is found.
+    ///
+    pub fn find_item(&self, value: &T) -> Box<[i32]> {
+        match self.key(value) {
+            Some(key) => self.tree.find_item(key),
+            None => Box::new([])
+        }
+    }
+
+    ///
+    /// Set an item's display text by associated value.
+    ///
+    /// * `value` - The value associated with the checkbox item.
+    /// * `text` - The new display text of the item.
+    ///
+    pub fn set_entry(&self, value: &T, text: &str) {
+        if let Some(key) = self.key(value) {
+            self.tree.set_entry(key, text);
+        }
+    }
+
+    ///
+    /// Get the current checkbox value of an item by associated value.
+    /// See [`CheckboxTree::get_entry_value()`].
+    ///
+    /// * `value` - The value associated with the checkbox.
+    ///
+    /// `Returns` the current sequence value of the checkbox or `None` if
+    /// no item is found.
+    ///
+    pub fn get_entry_value(&self, value: &T) -> Option<char> {
+        self.key(value).map(|key| self.tree.get_entry_value(key))
+    }
+
+    ///
+    /// Set the current checkbox value of an item by associated value.
+    /// See [`CheckboxTree::set_entry_value()`].
+    ///
+    /// * `value` - The value associated with the checkbox.
+    /// * `seqval` - The value to set the checkbox item to.
+    ///
+    pub fn set_entry_value(&self, value: &T, seqval: char) {
+        if let Some(key) = self.key(value) {
+            self.tree.set_entry_value(key, seqval);
+        }
+    }
+
+    try_fns! {
+        fn try_set_current = set_current(value: &T);
+        fn try_find_item = find_item(value: &T) -> Box<[i32]>;
+        fn try_set_entry = set_entry(value: &T, text: &str);
+        fn try_get_entry_value = get_entry_value(value: &T) -> Option<char>;
+        fn try_set_entry_value = set_entry_value(value: &T, seqval: char);
+    }
+
+    fn key(&self, value: &T) -> Option<usize> {
+        self.values.borrow().position(|v| v == value)
+    }
+}
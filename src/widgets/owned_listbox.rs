@@ -0,0 +1,287 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::cell::RefCell;
+
+use crate::component::Component;
+use crate::constants::FlagsSense;
+use crate::error::Error;
+use crate::private::slab::Slab;
+use crate::widgets::Listbox;
+
+///
+/// A [`Listbox`] holding owned values.
+///
+/// Unlike `Listbox` the values associated with items are not limited to
+/// types that fit in a pointer. Values are stored by the `OwnedListbox`
+/// and cloned when returned.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::prelude::*;
+/// use std::path::PathBuf;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     newt::centered_window(20, 6, Some("Files")).unwrap();
+///
+///     let listbox = OwnedListbox::new(1, 1, 4, LISTBOX_RETURNEXIT);
+///     listbox.append_entry("passwd", PathBuf::from("/etc/passwd")).unwrap();
+///     listbox.append_entry("group", PathBuf::from("/etc/group")).unwrap();
+///
+///     let mut form = Form::new(None, 0);
+///     form.add_component(&listbox).unwrap();
+///     form.run().unwrap();
+///     newt::finished();
+///
+///     if let Some(path) = listbox.get_current() {
+///         println!("Selected: {}", path.display());
+///     }
+/// }
+/// ```
+///
+#[derive(Component)]
+pub struct OwnedListbox<T: Clone> {
+    #[component]
+    listbox: Listbox<usize>,
+    values: RefCell<Slab<T>>
+}
+
+impl<T: Clone> OwnedListbox<T> {
+    ///
+    /// Create a new `OwnedListbox`.
+    ///
+    /// * `left` - The left-most position of the `OwnedListbox`.
+    /// * `top` - The top-most position of the `OwnedListbox`.
+    /// * `height` - The height of the `OwnedListbox`.
+    /// * `flags` - [Flags][listbox] modifying the behavior of the
+    ///   `OwnedListbox`. See also [generalized flags][flags].
+    ///
+    /// [listbox]: crate::constants::listbox
+    /// [flags]: crate::constants::flags
+    ///
+    pub fn new(left: i32, top: i32, height: i32, flags: i32)
+      -> OwnedListbox<T> {
+        OwnedListbox {
+            listbox: Listbox::new(left, top, height, flags),
+            values: RefCell::new(Slab::new())
+        }
+    }
+
+    ///
+    /// Set the width of the `OwnedListbox`.
+    ///
+    /// * `width` - The new width of the `OwnedListbox`.
+    ///
+    pub fn set_width(&self, width: i32) {
+        self.listbox.set_width(width);
+    }
+
+    ///
+    /// Get the number of items in the `OwnedListbox`.
+    ///
+    pub fn item_count(&self) -> i32 {
+        self.listbox.item_count()
+    }
+
+    ///
+    /// Add a new item to the `OwnedListbox`.
+    ///
+    /// * `text` - The displayed text of the item.
+    /// * `value` - The value associated with the item.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if newt fails to add the item.
+    ///
+    pub fn append_entry(&self, text: &str, value: T) -> Result<(), Error> {
+        let key = self.values.borrow_mut().insert(value);
+        let rv = self.listbox.append_entry(text, key);
+        if rv.is_err() {
+            self.values.borrow_mut().remove(key);
+            return Err(Error::InvalidItem);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Add a new item to the `OwnedListbox` at index number `num`.
+    ///
+    /// * `text` - The displayed text of the item.
+    /// * `value` - The value associated with the item.
+    /// * `num` - The index number of the new item. Items at and after `num`
+    ///   are moved down.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if `num` is greater than the number
+    /// of items.
+    ///
+    pub fn insert_entry(&self, text: &str, value: T, num: i32)
+          -> Result<(), Error> {
+        // newtListboxInsertEntry() inserts after the item with the given
+        // key, or at the start of the list when the key is null.
+        let after = match num {
+            0 => 0,
+            _ => self.key(num - 1).ok_or(Error::InvalidItem)?
+        };
+        let key = self.values.borrow_mut().insert(value);
+        let rv = self.listbox.insert_entry(text, key, after);
+        if rv.is_err() {
+            self.values.borrow_mut().remove(key);
+            return Err(Error::InvalidItem);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Get the value of the currently selected item in the `OwnedListbox`.
+    ///
+    pub fn get_current(&self) -> Option<T> {
+        self.listbox.get_current().and_then(|key| self.value(key))
+    }
+
+    ///
+    /// Set the currently selected item in the `OwnedListbox` by index
+    /// number.
+    ///
+    /// * `num` - The index number of the item to be set as the currently
+    ///   selected item.
+    ///
+    pub fn set_current(&self, num: i32) {
+        self.listbox.set_current(num);
+    }
+
+    ///
+    /// Get an item in the `OwnedListbox` by index number.
+    ///
+    /// * `num` - The index number of the item.
+    ///
+    /// `Returns` a tuple (`text`, `value`) containing the item's display
+    /// text and the value associated with it.
+    ///
+    pub fn get_entry(&self, num: i32) -> (String, T) {
+        let (text, key) = self.listbox.get_entry(num);
+        (text, self.value(key).unwrap())
+    }
+
+    ///
+    /// Set an item's display text in the `OwnedListbox` by index number.
+    ///
+    /// * `num` - The index number of the item to be modified.
+    /// * `text` - The new display text of the item.
+    ///
+    pub fn set_entry(&self, num: i32, text: &str) {
+        self.listbox.set_entry(num, text);
+    }
+
+    ///
+    /// Set an item's associated value in the `OwnedListbox` by index
+    /// number.
+    ///
+    /// * `num` - The index number of the item to be modified.
+    /// * `value` - The new value to be associated with the item.
+    ///
+    pub fn set_value(&self, num: i32, value: T) {
+        if let Some(key) = self.key(num) {
+            self.values.borrow_mut().set(key, value);
+        }
+    }
+
+    ///
+    /// Delete an item from the `OwnedListbox` by index number.
+    ///
+    /// * `num` - The index number of the item to be deleted.
+    ///
+    /// `Returns` the value associated with the deleted item.
+    ///
+    pub fn delete_entry(&self, num: i32) -> Option<T> {
+        let key = self.key(num)?;
+        self.listbox.delete_entry(key);
+        self.values.borrow_mut().remove(key)
+    }
+
+    ///
+    /// Delete all items in the `OwnedListbox`.
+    ///
+    pub fn clear(&self) {
+        self.listbox.clear();
+        self.values.borrow_mut().clear();
+    }
+
+    ///
+    /// Get the current selections in the `OwnedListbox`.
+    ///
+    /// `Returns` an array of the values associated with the current
+    /// selections.
+    ///
+    pub fn get_selection(&self) -> Box<[T]> {
+        self.listbox.get_selection().iter()
+            .filter_map(|key| self.value(*key))
+            .collect()
+    }
+
+    ///
+    /// Modify an item's current selection status by index number.
+    ///
+    /// * `num` - The index number of the item.
+    /// * `sense` - The sense in which the selection should be modified (`Set`,
+    ///   `Reset`, or `Toggle`).
+    ///
+    pub fn select_item(&self, num: i32, sense: FlagsSense) {
+        if let Some(key) = self.key(num) {
+            self.listbox.select_item(key, sense);
+        }
+    }
+
+    ///
+    /// Clear all selections in the `OwnedListbox`.
+    ///
+    pub fn clear_selection(&self) {
+        self.listbox.clear_selection();
+    }
+
+    try_fns! {
+        fn try_set_width = set_width(width: i32);
+        fn try_item_count = item_count() -> i32;
+        fn try_append_entry = append_entry(text: &str, value: T)
+            -> Result<(), Error>;
+        fn try_insert_entry = insert_entry(text: &str, value: T, num: i32)
+            -> Result<(), Error>;
+        fn try_get_current = get_current() -> Option<T>;
+        fn try_set_current = set_current(num: i32);
+        fn try_get_entry = get_entry(num: i32) -> (String, T);
+        fn try_set_entry = set_entry(num: i32, text: &str);
+        fn try_set_value = set_value(num: i32, value: T);
+        fn try_delete_entry = delete_entry(num: i32) -> Option<T>;
+        fn try_clear = clear();
+        fn try_get_selection = get_selection() -> Box<[T]>;
+        fn try_select_item = select_item(num: i32, sense: FlagsSense);
+        fn try_clear_selection = clear_selection();
+    }
+
+    fn key(&self, num: i32) -> Option<usize> {
+        if num < 0 || num >= self.item_count() {
+            return None;
+        }
+        Some(self.listbox.get_entry(num).1)
+    }
+
+    fn value(&self, key: usize) -> Option<T> {
+        self.values.borrow().get(key).cloned()
+    }
+}
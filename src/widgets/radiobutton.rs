@@ -21,9 +21,11 @@ use std::cell::Cell;
 use std::ffi::CString;
 use std::marker::PhantomData;
 use std::ptr;
+use std::rc::Rc;
 
 use newt_sys::*;
 use crate::component::Component;
+use crate::private::destroyed;
 use crate::private::traits::ComponentClone;
 
 ///
@@ -85,6 +87,7 @@ use crate::private::traits::ComponentClone;
 pub struct Radiobutton<'a> {
     co: Cell<newtComponent>,
     added_to_parent: Cell<bool>,
+    destroyed: Rc<Cell<bool>>,
     data: PhantomData<&'a Radiobutton<'a>>
 }
 
@@ -145,6 +148,7 @@ impl<'a> Radiobutton<'a> {
         Radiobutton {
             co: Cell::new(co),
             added_to_parent: Cell::new(false),
+            destroyed: destroyed::flag(co),
             data: PhantomData
         }
     }
@@ -169,6 +173,11 @@ impl<'a> Radiobutton<'a> {
     pub fn set_current(&self) {
         unsafe { newtRadioSetCurrent(self.co()) }
     }
+
+    try_fns! {
+        fn try_get_current = get_current() -> Option<Radiobutton<'a>>;
+        fn try_set_current = set_current();
+    }
 }
 
 impl<'a> ComponentClone for Radiobutton<'a> {
@@ -176,6 +185,7 @@ impl<'a> ComponentClone for Radiobutton<'a> {
         Radiobutton {
             co: Cell::new(co),
             added_to_parent: Cell::new(added_to_parent),
+            destroyed: destroyed::flag(co),
             data: PhantomData
         }
     }
@@ -18,6 +18,7 @@
 //
 
 use crate::component::Component;
+use crate::error::Error;
 use crate::form::Form;
 use crate::widgets::Radiobutton;
 use crate::private::traits::ComponentClone;
@@ -131,4 +132,17 @@ impl<'a> RadiobuttonSet<'a> {
     pub fn set_current(&self, index: usize) {
         self.radiobuttons[index].set_current();
     }
+
+    try_fns! {
+        check = check_destroyed;
+        fn try_get_current = get_current() -> usize;
+        fn try_set_current = set_current(index: usize);
+    }
+
+    fn check_destroyed(&self) -> Result<(), Error> {
+        for radiobutton in &self.radiobuttons {
+            radiobutton.check()?;
+        }
+        Ok(())
+    }
 }
@@ -71,4 +71,9 @@ impl Scale {
     pub fn set_colors(&self, empty: i32, full: i32) {
         unsafe { newtScaleSetColors(self.co(), empty, full); }
     }
+
+    try_fns! {
+        fn try_set = set(amount: u64);
+        fn try_set_colors = set_colors(empty: i32, full: i32);
+    }
 }
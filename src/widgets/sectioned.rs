@@ -0,0 +1,826 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+use std::cell::{Cell,RefCell};
+use std::marker::PhantomData;
+use std::os::raw::{c_char,c_void};
+
+use newt_sys::*;
+use crate::component::Component;
+use crate::constants::{ARG_LAST,CHECKBOXTREE_COLLAPSED,CHECKBOXTREE_EXPANDED};
+use crate::constants::{CHECKBOXTREE_UNSELECTABLE,FlagsSense};
+use crate::data::Data;
+use crate::error::Error;
+use crate::private::funcs::{char_to_c_char,newt_unset_callback};
+use crate::widgets::{CheckboxTree,Listbox};
+
+#[derive(Clone,Copy,PartialEq)]
+enum Item {
+    Entry(*const c_void),
+    Header,
+    Separator
+}
+
+//
+// The items of a sectioned widget in the order they were added. Items are
+// identified in the underlying widget by their index plus `1`.
+//
+struct Sections {
+    items: RefCell<Vec<Item>>,
+    widest: Cell<usize>,
+    width: Cell<Option<usize>>,
+    previous: Cell<usize>,
+    unset: c_char,
+    updating: Cell<bool>
+}
+
+impl Sections {
+    fn new(unset: c_char) -> Box<Sections> {
+        Box::new(Sections {
+            items: RefCell::new(Vec::new()),
+            widest: Cell::new(0),
+            width: Cell::new(None),
+            previous: Cell::new(0),
+            unset,
+            updating: Cell::new(false)
+        })
+    }
+
+    fn as_ptr(&self) -> *mut c_void {
+        self as *const _ as *mut c_void
+    }
+
+    fn push(&self, item: Item, text: &str) -> usize {
+        let mut items = self.items.borrow_mut();
+        items.push(item);
+        if item != Item::Separator {
+            let len = text.chars().count();
+            if len > self.widest.get() {
+                self.widest.set(len);
+            }
+        }
+        items.len()
+    }
+
+    fn pop(&self) {
+        self.items.borrow_mut().pop();
+    }
+
+    fn item(&self, key: usize) -> Option<Item> {
+        if key == 0 { return None; }
+        self.items.borrow().get(key - 1).copied()
+    }
+
+    fn data(&self, key: usize) -> Option<*const c_void> {
+        match self.item(key) {
+            Some(Item::Entry(data)) => Some(data),
+            _ => None
+        }
+    }
+
+    fn is_selectable(&self, key: usize) -> bool {
+        self.data(key).is_some()
+    }
+
+    fn key(&self, data: *const c_void) -> Option<usize> {
+        self.items.borrow().iter()
+            .position(|item| *item == Item::Entry(data))
+            .map(|index| index + 1)
+    }
+
+    fn separators(&self) -> Vec<usize> {
+        self.items.borrow().iter().enumerate()
+            .filter(|(_, item)| **item == Item::Separator)
+            .map(|(index, _)| index + 1)
+            .collect()
+    }
+
+    fn separator_text(&self) -> String {
+        let width = self.width.get().unwrap_or(self.widest.get());
+        "-".repeat(width)
+    }
+
+    fn selection(&self, keys: &[usize]) -> Vec<*const c_void> {
+        keys.iter().filter_map(|key| self.data(*key)).collect()
+    }
+
+    fn clear(&self) {
+        self.items.borrow_mut().clear();
+        self.widest.set(0);
+        self.previous.set(0);
+    }
+}
+
+//
+// Choose the item closest to `current` in `order` that is not a header or
+// separator, preferring the direction the cursor was moving in.
+//
+fn skip<F>(sections: &Sections, order: &[usize], current: usize,
+           selectable: F) -> Option<usize>
+    where F: Fn(usize) -> bool
+{
+    let index = order.iter().position(|key| *key == current)?;
+    let forward = match order.iter()
+        .position(|key| *key == sections.previous.get()) {
+        Some(previous) => index >= previous,
+        None => true
+    };
+
+    let selectable = |key: &&usize| selectable(**key);
+    let mut after = order[index..].iter();
+    let mut before = order[..index].iter().rev();
+    let found = if forward {
+        after.find(selectable).or_else(|| before.find(selectable))
+    } else {
+        before.find(selectable).or_else(|| after.find(selectable))
+    };
+    found.copied()
+}
+
+///
+/// A [`Listbox`] with non-selectable section headers and separators.
+///
+/// Headers and separators are skipped when the cursor is moved and are
+/// never part of the selection, whether or not the `Listbox` was created
+/// with [`FLAG_MULTIPLE`][multiple]. Separators are drawn as a line as
+/// wide as the widest item, or the width set with
+/// [`set_width()`][set_width].
+///
+/// The `SectionedListbox` uses the `Listbox`'s [`Callback`][callback], so
+/// another callback must not be set on it.
+///
+/// [callback]: crate::callbacks::Callback
+/// [multiple]: crate::constants::FLAG_MULTIPLE
+/// [set_width]: SectionedListbox::set_width
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::prelude::*;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     newt::centered_window(20, 9, Some("Devices")).unwrap();
+///
+///     let listbox: SectionedListbox =
+///         SectionedListbox::new(1, 1, 7, FLAG_MULTIPLE);
+///     listbox.add_header("Network").unwrap();
+///     listbox.add_item("eth0", 1).unwrap();
+///     listbox.add_item("wlan0", 2).unwrap();
+///     listbox.add_separator().unwrap();
+///     listbox.add_header("Storage").unwrap();
+///     listbox.add_item("sda", 3).unwrap();
+///
+///     let mut form = Form::new(None, 0);
+///     form.add_component(&listbox).unwrap();
+///     form.run().unwrap();
+///     newt::finished();
+///
+///     println!("Selected: {:?}", listbox.get_selection());
+/// }
+/// ```
+///
+#[derive(Component)]
+pub struct SectionedListbox<D: Data = isize> {
+    #[component]
+    listbox: Listbox<usize>,
+    sections: Box<Sections>,
+    data: PhantomData<D>
+}
+
+impl<D: Data> SectionedListbox<D> {
+    ///
+    /// Create a new `SectionedListbox`.
+    ///
+    /// See [`Listbox::new()`] for a description of the parameters.
+    ///
+    pub fn new(left: i32, top: i32, height: i32, flags: i32)
+      -> SectionedListbox<D> {
+        let listbox = Listbox::new(left, top, height, flags);
+        let sections = Sections::new(0);
+        unsafe {
+            newtComponentAddCallback(listbox.co(), Some(listbox_callback),
+                                     sections.as_ptr());
+        }
+        SectionedListbox { listbox, sections, data: PhantomData }
+    }
+
+    ///
+    /// Set the width of the `SectionedListbox`.
+    ///
+    /// * `width` - The new width of the `SectionedListbox`.
+    ///
+    pub fn set_width(&self, width: i32) {
+        self.listbox.set_width(width);
+        self.sections.width.set(Some(width.max(0) as usize));
+        self.update_separators();
+    }
+
+    ///
+    /// Get the number of items in the `SectionedListbox`, including
+    /// headers and separators.
+    ///
+    pub fn item_count(&self) -> i32 {
+        self.listbox.item_count()
+    }
+
+    ///
+    /// Add a new item to the `SectionedListbox`.
+    ///
+    /// * `text` - The displayed text of the item.
+    /// * `data` - The user `Data` associated with the item.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if newt fails to add the item.
+    ///
+    pub fn add_item(&self, text: &str, data: D) -> Result<(), Error> {
+        self.add(text, Item::Entry(data.newt_to_ptr()))
+    }
+
+    ///
+    /// Add a section header to the `SectionedListbox`.
+    ///
+    /// * `text` - The displayed text of the header.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if newt fails to add the header.
+    ///
+    pub fn add_header(&self, text: &str) -> Result<(), Error> {
+        self.add(text, Item::Header)
+    }
+
+    ///
+    /// Add a separator line to the `SectionedListbox`.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if newt fails to add the separator.
+    ///
+    pub fn add_separator(&self) -> Result<(), Error> {
+        self.add("", Item::Separator)
+    }
+
+    ///
+    /// `Returns` `true` if the item at index number `num` is a header or
+    /// separator.
+    ///
+    /// * `num` - The index number of the item.
+    ///
+    pub fn is_header(&self, num: i32) -> bool {
+        num >= 0 && !self.sections.is_selectable(num as usize + 1)
+    }
+
+    ///
+    /// Get the user `Data` of the currently selected item in the
+    /// `SectionedListbox`.
+    ///
+    pub fn get_current(&self) -> Option<D> {
+        self.listbox.get_current()
+            .and_then(|key| self.sections.data(key))
+            .map(|data| unsafe { D::newt_from_ptr(data) })
+    }
+
+    ///
+    /// Set the currently selected item in the `SectionedListbox` by index
+    /// number. If the item is a header or separator the closest item in
+    /// the direction of movement is selected instead.
+    ///
+    /// * `num` - The index number of the item to be set as the currently
+    ///   selected item.
+    ///
+    pub fn set_current(&self, num: i32) {
+        self.listbox.set_current(num);
+    }
+
+    ///
+    /// Set the currently selected item in the `SectionedListbox` by
+    /// associated user `Data`.
+    ///
+    /// * `key` - The user `Data` associated with the item to be set as the
+    ///   currently selected item.
+    ///
+    pub fn set_current_by_key(&self, key: D) {
+        if let Some(key) = self.sections.key(key.newt_to_ptr()) {
+            self.listbox.set_current_by_key(key);
+        }
+    }
+
+    ///
+    /// Get the current selections in the `SectionedListbox`.
+    ///
+    /// `Returns` an array of the user `Data` associated with the current
+    /// selections.
+    ///
+    pub fn get_selection(&self) -> Box<[D]> {
+        let keys = self.listbox.get_selection();
+        self.sections.selection(&keys).into_iter()
+            .map(|data| unsafe { D::newt_from_ptr(data) })
+            .collect()
+    }
+
+    ///
+    /// Modify an item's current selection status.
+    ///
+    /// * `key` - The user `Data` associated with the item.
+    /// * `sense` - The sense in which the selection should be modified (`Set`,
+    ///   `Reset`, or `Toggle`).
+    ///
+    pub fn select_item(&self, key: D, sense: FlagsSense) {
+        if let Some(key) = self.sections.key(key.newt_to_ptr()) {
+            self.listbox.select_item(key, sense);
+        }
+    }
+
+    ///
+    /// Clear all selections in the `SectionedListbox`.
+    ///
+    pub fn clear_selection(&self) {
+        self.listbox.clear_selection();
+    }
+
+    ///
+    /// Delete all items, headers and separators in the
+    /// `SectionedListbox`.
+    ///
+    pub fn clear(&self) {
+        self.listbox.clear();
+        self.sections.clear();
+    }
+
+    try_fns! {
+        fn try_set_width = set_width(width: i32);
+        fn try_item_count = item_count() -> i32;
+        fn try_add_item = add_item(text: &str, data: D) -> Result<(), Error>;
+        fn try_add_header = add_header(text: &str) -> Result<(), Error>;
+        fn try_add_separator = add_separator() -> Result<(), Error>;
+        fn try_get_current = get_current() -> Option<D>;
+        fn try_set_current = set_current(num: i32);
+        fn try_set_current_by_key = set_current_by_key(key: D);
+        fn try_get_selection = get_selection() -> Box<[D]>;
+        fn try_select_item = select_item(key: D, sense: FlagsSense);
+        fn try_clear_selection = clear_selection();
+        fn try_clear = clear();
+    }
+
+    fn add(&self, text: &str, item: Item) -> Result<(), Error> {
+        let widest = self.sections.widest.get();
+        let key = self.sections.push(item, text);
+        let rv = match item {
+            Item::Separator => {
+                let text = self.sections.separator_text();
+                self.listbox.append_entry(&text, key)
+            },
+            _ => self.listbox.append_entry(text, key)
+        };
+        if rv.is_err() {
+            self.sections.pop();
+            return Err(Error::InvalidItem);
+        }
+
+        if self.sections.widest.get() != widest {
+            self.update_separators();
+        }
+        unsafe { listbox_update(&self.sections, self.listbox.co()); }
+        Ok(())
+    }
+
+    fn update_separators(&self) {
+        let text = self.sections.separator_text();
+        for key in self.sections.separators() {
+            self.listbox.set_entry(key as i32 - 1, &text);
+        }
+    }
+}
+
+impl<D: Data> Drop for SectionedListbox<D> {
+    fn drop(&mut self) {
+        if !self.listbox.is_destroyed() {
+            unsafe { newt_unset_callback(&self.listbox); }
+        }
+    }
+}
+
+//
+// Move the cursor off of a header or separator.
+//
+unsafe fn listbox_update(sections: &Sections, co: newtComponent) {
+    if sections.updating.get() {
+        return;
+    }
+
+    let mut current = newtListboxGetCurrent(co) as usize;
+    if current != 0 && !sections.is_selectable(current) {
+        let order: Vec<usize> = (1..=sections.items.borrow().len()).collect();
+        let found = skip(sections, &order, current,
+                         |key| sections.is_selectable(key));
+        if let Some(key) = found {
+            sections.updating.set(true);
+            newtListboxSetCurrent(co, key as i32 - 1);
+            sections.updating.set(false);
+            current = key;
+        }
+    }
+    sections.previous.set(current);
+}
+
+unsafe extern "C" fn listbox_callback(co: newtComponent, data: *mut c_void) {
+    listbox_update(&*(data as *const Sections), co);
+}
+
+///
+/// A [`CheckboxTree`] with non-selectable section headers and separators.
+///
+/// Headers and separators are added with [`add_header()`][add_header] and
+/// [`add_separator()`][add_separator], or by passing the
+/// [`CHECKBOXTREE_UNSELECTABLE`][unselectable] flag to
+/// [`add_item()`][add_item]. newt applies `CHECKBOXTREE_UNSELECTABLE` to a
+/// whole tree, so the `SectionedCheckboxTree` instead resets the value of
+/// a header if it's changed. Headers without children are skipped when
+/// the cursor is moved, headers with children may be focused to expand
+/// and collapse them. Headers and separators are never part of the
+/// selection.
+///
+/// The `SectionedCheckboxTree` uses the `CheckboxTree`'s
+/// [`Callback`][callback], so another callback must not be set on it.
+///
+/// [add_header]: SectionedCheckboxTree::add_header
+/// [add_item]: SectionedCheckboxTree::add_item
+/// [add_separator]: SectionedCheckboxTree::add_separator
+/// [callback]: crate::callbacks::Callback
+/// [unselectable]: crate::constants::CHECKBOXTREE_UNSELECTABLE
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::prelude::*;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     newt::centered_window(20, 9, Some("Services")).unwrap();
+///
+///     let tree: SectionedCheckboxTree =
+///         SectionedCheckboxTree::new(1, 1, 7, None, 0);
+///     tree.add_header("Network", None).unwrap();
+///     tree.add_item("sshd", 1, 0, None).unwrap();
+///     tree.add_item("httpd", 2, 0, None).unwrap();
+///     tree.add_separator(None).unwrap();
+///     tree.add_header("Storage", None).unwrap();
+///     tree.add_item("nfsd", 3, 0, None).unwrap();
+///     tree.set_current(1);
+///
+///     let mut form = Form::new(None, 0);
+///     form.add_component(&tree).unwrap();
+///     form.run().unwrap();
+///     newt::finished();
+///
+///     println!("Selected: {:?}", tree.get_selection());
+/// }
+/// ```
+///
+#[derive(Component)]
+pub struct SectionedCheckboxTree<D: Data = isize> {
+    #[component]
+    tree: CheckboxTree<usize>,
+    sections: Box<Sections>,
+    data: PhantomData<D>
+}
+
+impl<D: Data> SectionedCheckboxTree<D> {
+    ///
+    /// Create a new `SectionedCheckboxTree`.
+    ///
+    /// See [`CheckboxTree::new()`] for a description of the parameters.
+    ///
+    pub fn new(left: i32, top: i32, height: i32, sequence: Option<&[char]>,
+               flags: i32) -> SectionedCheckboxTree<D>
+    {
+        let tree = CheckboxTree::new(left, top, height, sequence, flags);
+        let unset = match sequence {
+            Some(seq) if !seq.is_empty() => char_to_c_char(seq[0]),
+            _ => b' ' as c_char
+        };
+        let sections = Sections::new(unset);
+        unsafe {
+            newtComponentAddCallback(tree.co(), Some(tree_callback),
+                                     sections.as_ptr());
+        }
+        SectionedCheckboxTree { tree, sections, data: PhantomData }
+    }
+
+    ///
+    /// Set the width of the `SectionedCheckboxTree`.
+    ///
+    /// * `width` - The width to set the `SectionedCheckboxTree`.
+    ///
+    pub fn set_width(&self, width: i32) {
+        self.tree.set_width(width);
+        self.sections.width.set(Some(width.max(0) as usize));
+        self.update_separators();
+    }
+
+    ///
+    /// Add an additional checkbox to the `SectionedCheckboxTree`.
+    ///
+    /// See [`CheckboxTree::add_item()`] for a description of item indexes.
+    /// An item added with the [`CHECKBOXTREE_UNSELECTABLE`][unselectable]
+    /// flag is a header, its `data` is ignored.
+    ///
+    /// * `text` - The label to be displayed with the checkbox.
+    /// * `data` - User `Data` to be associated with the checkbox.
+    /// * `flags` - [Flags][flags] modifying the behavior of the checkbox.
+    /// * `indexes` - The optional index of the item in the tree.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if `indexes` doesn't refer to a
+    /// position in the tree.
+    ///
+    /// [flags]: crate::constants::checkboxtree
+    /// [unselectable]: crate::constants::CHECKBOXTREE_UNSELECTABLE
+    ///
+    pub fn add_item(&self, text: &str, data: D, flags: i32,
+                    indexes: Option<&[i32]>) -> Result<(), Error> {
+        if flags & CHECKBOXTREE_UNSELECTABLE != 0 {
+            return self.add(text, Item::Header, 0, indexes);
+        }
+        self.add(text, Item::Entry(data.newt_to_ptr()), flags, indexes)
+    }
+
+    ///
+    /// Add a section header to the `SectionedCheckboxTree`.
+    ///
+    /// * `text` - The label to be displayed with the header.
+    /// * `indexes` - The optional index of the header in the tree.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if `indexes` doesn't refer to a
+    /// position in the tree.
+    ///
+    pub fn add_header(&self, text: &str, indexes: Option<&[i32]>)
+      -> Result<(), Error> {
+        self.add(text, Item::Header, 0, indexes)
+    }
+
+    ///
+    /// Add a separator line to the `SectionedCheckboxTree`.
+    ///
+    /// * `indexes` - The optional index of the separator in the tree.
+    ///
+    /// `Returns` [`Error::InvalidItem`] if `indexes` doesn't refer to a
+    /// position in the tree.
+    ///
+    pub fn add_separator(&self, indexes: Option<&[i32]>)
+      -> Result<(), Error> {
+        self.add("", Item::Separator, 0, indexes)
+    }
+
+    ///
+    /// Get the currently selected `SectionedCheckboxTree` item.
+    ///
+    /// `Returns` the user `Data` of the currently selected item, or `None`
+    /// if a header is selected.
+    ///
+    pub fn get_current(&self) -> Option<D> {
+        self.tree.get_current()
+            .and_then(|key| self.sections.data(key))
+            .map(|data| unsafe { D::newt_from_ptr(data) })
+    }
+
+    ///
+    /// Set the currently selected `SectionedCheckboxTree` item.
+    ///
+    /// * `data` - Associated user `Data` of the item to be selected.
+    ///
+    pub fn set_current(&self, data: D) {
+        if let Some(key) = self.sections.key(data.newt_to_ptr()) {
+            self.tree.set_current(key);
+            self.sections.previous.set(key);
+        }
+    }
+
+    ///
+    /// Get the currently selected checkbox items.
+    ///
+    /// `Returns` an array of the associated user `Data` of the selected
+    /// checkboxes.
+    ///
+    pub fn get_selection(&self) -> Box<[D]> {
+        let keys = self.tree.get_selection();
+        self.sections.selection(&keys).into_iter()
+            .map(|data| unsafe { D::newt_from_ptr(data) })
+            .collect()
+    }
+
+    ///
+    /// Select multiple checkbox items by their current checkbox value.
+    ///
+    /// See [`CheckboxTree::get_multi_selection()`].
+    ///
+    /// * `seqval` - The `sequence` value by which to select checkbox items.
+    ///
+    /// `Returns` an array of the associated user `Data` of the selected
+    /// checkboxes.
+    ///
+    pub fn get_multi_selection(&self, seqval: char) -> Box<[D]> {
+        let keys = self.tree.get_multi_selection(seqval);
+        self.sections.selection(&keys).into_iter()
+            .map(|data| unsafe { D::newt_from_ptr(data) })
+            .collect()
+    }
+
+    ///
+    /// Find an item by its associated user `Data`.
+    ///
+    /// * `data` - The user `Data` associated with the item.
+    ///
+    /// `Returns` an array of integers representing the item's location
+    /// index in the tree.
+    ///
+    pub fn find_item(&self, data: D) -> Box<[i32]> {
+        match self.sections.key(data.newt_to_ptr()) {
+            Some(key) => self.tree.find_item(key),
+            None => Box::new([])
+        }
+    }
+
+    ///
+    /// Get the current checkbox value of an item by associated user `Data`.
+    ///
+    /// See [`CheckboxTree::get_entry_value()`].
+    ///
+    /// * `data` - The user `Data` associated with the checkbox.
+    ///
+    /// `Returns` the current value of the checkbox, or `None` if there is
+    /// no item associated with `data`.
+    ///
+    pub fn get_entry_value(&self, data: D) -> Option<char> {
+        self.sections.key(data.newt_to_ptr())
+            .map(|key| self.tree.get_entry_value(key))
+    }
+
+    ///
+    /// Set the current checkbox value of an item by associated user `Data`.
+    ///
+    /// See [`CheckboxTree::set_entry_value()`].
+    ///
+    /// * `data` - The user `Data` associated with the checkbox.
+    /// * `value` - The value to set the checkbox item to.
+    ///
+    pub fn set_entry_value(&self, data: D, value: char) {
+        if let Some(key) = self.sections.key(data.newt_to_ptr()) {
+            self.tree.set_entry_value(key, value);
+        }
+    }
+
+    try_fns! {
+        fn try_set_width = set_width(width: i32);
+        fn try_add_item = add_item(text: &str, data: D, flags: i32,
+                                   indexes: Option<&[i32]>)
+            -> Result<(), Error>;
+        fn try_add_header = add_header(text: &str, indexes: Option<&[i32]>)
+            -> Result<(), Error>;
+        fn try_add_separator = add_separator(indexes: Option<&[i32]>)
+            -> Result<(), Error>;
+        fn try_get_current = get_current() -> Option<D>;
+        fn try_set_current = set_current(data: D);
+        fn try_get_selection = get_selection() -> Box<[D]>;
+        fn try_get_multi_selection = get_multi_selection(seqval: char)
+            -> Box<[D]>;
+        fn try_find_item = find_item(data: D) -> Box<[i32]>;
+        fn try_get_entry_value = get_entry_value(data: D) -> Option<char>;
+        fn try_set_entry_value = set_entry_value(data: D, value: char);
+    }
+
+    fn add(&self, text: &str, item: Item, flags: i32,
+           indexes: Option<&[i32]>) -> Result<(), Error> {
+        let widest = self.sections.widest.get();
+        let key = self.sections.push(item, text);
+        let rv = match item {
+            Item::Separator => {
+                let text = self.sections.separator_text();
+                self.tree.add_item(&text, key, flags, indexes)
+            },
+            _ => self.tree.add_item(text, key, flags, indexes)
+        };
+        if rv != 0 {
+            self.sections.pop();
+            return Err(Error::InvalidItem);
+        }
+
+        if self.sections.widest.get() != widest {
+            self.update_separators();
+        }
+        Ok(())
+    }
+
+    fn update_separators(&self) {
+        let text = self.sections.separator_text();
+        for key in self.sections.separators() {
+            self.tree.set_entry(key, &text);
+        }
+    }
+}
+
+impl<D: Data> Drop for SectionedCheckboxTree<D> {
+    fn drop(&mut self) {
+        if !self.tree.is_destroyed() {
+            unsafe { newt_unset_callback(&self.tree); }
+        }
+    }
+}
+
+unsafe fn is_branch(co: newtComponent, key: usize) -> bool {
+    let value = newtCheckboxTreeGetEntryValue(co, key as *const c_void);
+    value == CHECKBOXTREE_COLLAPSED || value == CHECKBOXTREE_EXPANDED
+}
+
+unsafe fn tree_path(co: newtComponent, key: usize) -> Option<Vec<i32>> {
+    let rv = newtCheckboxTreeFindItem(co, key as *mut c_void);
+    if rv.is_null() {
+        return None;
+    }
+
+    let mut path = Vec::new();
+    let mut p = rv;
+    while *p != ARG_LAST {
+        path.push(*p);
+        p = p.offset(1);
+    }
+    libc::free(rv as *mut libc::c_void);
+    Some(path)
+}
+
+//
+// `Returns` the keys of the items currently shown in the tree, in the
+// order they're displayed.
+//
+unsafe fn tree_order(sections: &Sections, co: newtComponent) -> Vec<usize> {
+    let count = sections.items.borrow().len();
+    let mut paths: Vec<(Vec<i32>, usize)> = (1..=count)
+        .filter_map(|key| tree_path(co, key).map(|path| (path, key)))
+        .collect();
+    paths.sort();
+
+    let mut order = Vec::new();
+    let mut collapsed: Option<Vec<i32>> = None;
+    for (path, key) in paths {
+        if let Some(ref branch) = collapsed {
+            if path.len() > branch.len() && path.starts_with(branch) {
+                continue;
+            }
+        }
+
+        collapsed = None;
+        let value = newtCheckboxTreeGetEntryValue(co, key as *const c_void);
+        if value == CHECKBOXTREE_COLLAPSED {
+            collapsed = Some(path);
+        }
+        order.push(key);
+    }
+    order
+}
+
+//
+// Reset the value of a header and move the cursor off of it.
+//
+unsafe fn tree_update(sections: &Sections, co: newtComponent) {
+    if sections.updating.get() {
+        return;
+    }
+
+    let mut current = newtCheckboxTreeGetCurrent(co) as usize;
+    if current != 0 && !sections.is_selectable(current)
+        && !is_branch(co, current)
+    {
+        let data = current as *const c_void;
+        if newtCheckboxTreeGetEntryValue(co, data) != sections.unset {
+            newtCheckboxTreeSetEntryValue(co, data, sections.unset);
+        }
+
+        let order = tree_order(sections, co);
+        let found = skip(sections, &order, current, |key| {
+            sections.is_selectable(key) || is_branch(co, key)
+        });
+        if let Some(key) = found {
+            sections.updating.set(true);
+            newtCheckboxTreeSetCurrent(co, key as *mut c_void);
+            sections.updating.set(false);
+            current = key;
+        }
+    }
+    sections.previous.set(current);
+}
+
+unsafe extern "C" fn tree_callback(co: newtComponent, data: *mut c_void) {
+    tree_update(&*(data as *const Sections), co);
+}
@@ -0,0 +1,563 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::cell::{Cell,RefCell};
+use std::cmp::Ordering;
+use std::ffi::CString;
+use std::os::raw::c_void;
+
+use crate::component::Component;
+use crate::constants::{FLAG_BORDER,FLAG_SCROLL};
+use crate::data::Data;
+use crate::error::Error;
+use crate::form::{ExitReason,Form};
+use crate::widgets::{Label,Listbox};
+
+#[cfg(feature = "asm")]
+use crate::constants::GRID_ANCHOR_LEFT;
+#[cfg(feature = "asm")]
+use crate::grid::{Composite,Layout,Parent};
+
+///
+/// The alignment of text within a [`Column`].
+///
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum Align {
+    /// Align text to the left of the column.
+    Left,
+    /// Center text within the column.
+    Center,
+    /// Align text to the right of the column.
+    Right
+}
+
+///
+/// A column displayed by a [`Table`].
+///
+/// Text wider than the column is truncated with an ellipsis.
+///
+#[derive(Clone,Debug)]
+pub struct Column {
+    title: String,
+    width: usize,
+    align: Align
+}
+
+impl Column {
+    ///
+    /// Create a new `Column`.
+    ///
+    /// * `title` - The title displayed in the `Table` header.
+    /// * `width` - The width of the column in characters.
+    /// * `align` - The alignment of text within the column.
+    ///
+    pub fn new(title: &str, width: usize, align: Align) -> Column {
+        assert!(width > 0, "`width` must be greater than 0");
+        Column { title: title.to_string(), width, align }
+    }
+
+    ///
+    /// `Returns` the title of the column.
+    ///
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    ///
+    /// `Returns` the width of the column.
+    ///
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+struct Row {
+    cells: Vec<String>,
+    data: *const c_void
+}
+
+///
+/// A multi-column list of records built on a [`Listbox`].
+///
+/// A `Table` consists of a header [`Label`] displaying the column titles
+/// and a `Listbox` displaying the rows beneath it. Both should be added to
+/// a `Form`. When the `asm` feature is enabled a `Table` is also a
+/// [`Composite`][composite] and can be added to a `Form` or `Grid`
+/// directly.
+///
+/// Rows can be sorted by column. Cells containing numbers are compared
+/// numerically, others by their text.
+///
+/// [composite]: crate::grid::Composite
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::prelude::*;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     newt::centered_window(30, 7, Some("Packages")).unwrap();
+///
+///     let columns = [Column::new("Name", 12, Align::Left),
+///                    Column::new("Size", 8, Align::Right)];
+///     let table: Table<usize> = Table::new(1, 0, 5, &columns, 0);
+///     table.append_row(&["newt", "152"], 1).unwrap();
+///     table.append_row(&["slang", "2048"], 2).unwrap();
+///     table.set_sort_key(0, KEY_F2);
+///     table.set_sort_key(1, KEY_F3);
+///
+///     let mut form = Form::new(None, 0);
+///     form.add_components(&[table.header(), table.listbox()]).unwrap();
+///     table.add_hot_keys(&form);
+///     while table.handle_hot_key(&form.run().unwrap()) { }
+///     newt::finished();
+///
+///     println!("Selected: {:?}", table.get_current());
+/// }
+/// ```
+///
+pub struct Table<D: Data = isize> {
+    header: Label,
+    listbox: Listbox<D>,
+    columns: RefCell<Vec<Column>>,
+    rows: RefCell<Vec<Row>>,
+    sort: Cell<Option<(usize, bool)>>,
+    sort_keys: RefCell<Vec<(i32, usize)>>,
+    margin: usize,
+    #[cfg(feature = "asm")]
+    layout: Layout
+}
+
+impl<D: Data> Table<D> {
+    ///
+    /// Create a new `Table`.
+    ///
+    /// * `left` - The left-most position of the `Table`.
+    /// * `top` - The top-most position of the `Table` header.
+    /// * `height` - The height of the `Table`'s `Listbox`.
+    /// * `columns` - The columns displayed by the `Table`.
+    /// * `flags` - [Flags][listbox] modifying the behavior of the `Listbox`.
+    ///
+    /// [listbox]: crate::constants::listbox
+    ///
+    pub fn new(left: i32, top: i32, height: i32, columns: &[Column],
+               flags: i32) -> Table<D> {
+        assert!(!columns.is_empty(), "`columns` must not be empty");
+
+        let border = if flags & FLAG_BORDER != 0 { 2 } else { 0 };
+        let scroll = if flags & FLAG_SCROLL != 0 && height > 0 {
+            3
+        } else {
+            0
+        };
+        let header = Label::new(left + border, top, "");
+        let listbox = Listbox::new(left, top + 1, height, flags);
+
+        #[cfg(feature = "asm")]
+        let layout = {
+            let mut layout = Layout::new(1, 2);
+            layout.set_field(0, 0, &header, border, 0, 0, 0,
+                             GRID_ANCHOR_LEFT, 0);
+            layout.set_field(0, 1, &listbox, 0, 0, 0, 0, 0, 0);
+            layout
+        };
+
+        let table = Table {
+            header, listbox,
+            columns: RefCell::new(columns.to_vec()),
+            rows: RefCell::new(Vec::new()),
+            sort: Cell::new(None),
+            sort_keys: RefCell::new(Vec::new()),
+            margin: (2 * border + scroll) as usize,
+            #[cfg(feature = "asm")]
+            layout
+        };
+
+        let width = table.content_width() + table.margin;
+        table.listbox.set_width(width as i32);
+        table.render_header();
+        table
+    }
+
+    ///
+    /// `Returns` the `Label` displaying the column titles.
+    ///
+    pub fn header(&self) -> &Label {
+        &self.header
+    }
+
+    ///
+    /// `Returns` the `Listbox` displaying the rows.
+    ///
+    pub fn listbox(&self) -> &Listbox<D> {
+        &self.listbox
+    }
+
+    ///
+    /// `Returns` the columns displayed by the `Table`.
+    ///
+    pub fn columns(&self) -> Vec<Column> {
+        self.columns.borrow().clone()
+    }
+
+    ///
+    /// Set the width of the `Table`.
+    ///
+    /// The last column is resized to fill the width and all rows are
+    /// rendered again.
+    ///
+    /// * `width` - The new width of the `Table`.
+    ///
+    pub fn set_width(&self, width: i32) {
+        {
+            let mut columns = self.columns.borrow_mut();
+            let count = columns.len();
+            let used: usize = columns[..count - 1].iter()
+                .map(|c| c.width + 1)
+                .sum();
+            let available = (width.max(0) as usize)
+                .saturating_sub(self.margin + used);
+            columns[count - 1].width = available.max(1);
+        }
+
+        self.listbox.set_width(width);
+        self.render_header();
+        self.render_rows();
+    }
+
+    ///
+    /// Add a row to the `Table`.
+    ///
+    /// * `cells` - The text of each of the row's cells.
+    /// * `data` - The user `Data` associated with the row.
+    ///
+    /// `Returns` [`Error::CellCount`] if the number of cells does not match
+    /// the number of columns.
+    ///
+    pub fn append_row(&self, cells: &[&str], data: D) -> Result<(), Error> {
+        if cells.len() != self.columns.borrow().len() {
+            return Err(Error::CellCount);
+        }
+
+        let row = Row {
+            cells: cells.iter().map(|c| c.to_string()).collect(),
+            data: data.newt_to_ptr()
+        };
+
+        if self.sort.get().is_some() {
+            self.rows.borrow_mut().push(row);
+            self.sort_rows();
+            self.render_rows();
+            return Ok(());
+        }
+
+        let text = self.render_row(&row.cells);
+        self.listbox.append_entry(&text, data)
+            .map_err(|_| Error::InvalidItem)?;
+        self.rows.borrow_mut().push(row);
+        Ok(())
+    }
+
+    ///
+    /// Get the number of rows in the `Table`.
+    ///
+    pub fn len(&self) -> usize {
+        self.rows.borrow().len()
+    }
+
+    ///
+    /// `Returns` `true` if the `Table` contains no rows.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.rows.borrow().is_empty()
+    }
+
+    ///
+    /// Get a row in the `Table` by index number.
+    ///
+    /// * `num` - The index number of the row as currently displayed.
+    ///
+    /// `Returns` a tuple (`cells`, `data`) containing the text of the row's
+    /// cells and the user `Data` associated with it.
+    ///
+    pub fn get_row(&self, num: usize) -> (Vec<String>, D) {
+        let rows = self.rows.borrow();
+        let row = rows.get(num)
+            .unwrap_or_else(|| panic!("Invalid index number {}", num));
+        (row.cells.clone(), unsafe { D::newt_from_ptr(row.data) })
+    }
+
+    ///
+    /// Delete all rows in the `Table`.
+    ///
+    pub fn clear(&self) {
+        self.rows.borrow_mut().clear();
+        self.listbox.clear();
+    }
+
+    ///
+    /// Get the user `Data` of the currently selected row.
+    ///
+    pub fn get_current(&self) -> Option<D> {
+        self.listbox.get_current()
+    }
+
+    ///
+    /// Set the currently selected row by index number.
+    ///
+    /// * `num` - The index number of the row as currently displayed.
+    ///
+    pub fn set_current(&self, num: i32) {
+        self.listbox.set_current(num);
+    }
+
+    ///
+    /// Get the current selections in the `Table`.
+    ///
+    /// `Returns` an array of the user `Data` associated with the selected
+    /// rows.
+    ///
+    pub fn get_selection(&self) -> Box<[D]> {
+        self.listbox.get_selection()
+    }
+
+    ///
+    /// Sort the rows of the `Table` by a column.
+    ///
+    /// Rows added later are kept sorted.
+    ///
+    /// * `col` - The index number of the column to sort by.
+    /// * `ascending` - Sort in ascending order if `true`, otherwise in
+    ///   descending order.
+    ///
+    pub fn sort_by_column(&self, col: usize, ascending: bool) {
+        self.check_column(col);
+        self.sort.set(Some((col, ascending)));
+        self.sort_rows();
+        self.render_header();
+        self.render_rows();
+    }
+
+    ///
+    /// `Returns` the column index and order the `Table` is sorted by.
+    ///
+    pub fn sort_column(&self) -> Option<(usize, bool)> {
+        self.sort.get()
+    }
+
+    ///
+    /// Set a hot key sorting the `Table` by a column.
+    ///
+    /// The hot key is registered with a `Form` by
+    /// [`Table::add_hot_keys()`]. Pressing the key again reverses the
+    /// sort order.
+    ///
+    /// * `col` - The index number of the column.
+    /// * `key` - The [key][keys] sorting the column (i.e. `KEY_F2`).
+    ///
+    /// [keys]: crate::constants::keys
+    ///
+    pub fn set_sort_key(&self, col: usize, key: i32) {
+        self.check_column(col);
+        let mut sort_keys = self.sort_keys.borrow_mut();
+        sort_keys.retain(|&(k, _)| k != key);
+        sort_keys.push((key, col));
+    }
+
+    ///
+    /// Register the `Table`'s sort keys with a `Form`.
+    ///
+    /// * `form` - The `Form` to register the hot keys with.
+    ///
+    pub fn add_hot_keys(&self, form: &Form) {
+        for &(key, _) in self.sort_keys.borrow().iter() {
+            form.add_hot_key(key);
+        }
+    }
+
+    ///
+    /// Sort the `Table` if a `Form` exited due to one of its sort keys.
+    ///
+    /// * `reason` - The `ExitReason` returned by [`Form::run()`].
+    ///
+    /// `Returns` `true` if the `Table` was sorted and the `Form` should be
+    /// run again.
+    ///
+    pub fn handle_hot_key(&self, reason: &ExitReason) -> bool {
+        let key = match reason {
+            ExitReason::HotKey(key) => *key,
+            _ => return false
+        };
+
+        let col = self.sort_keys.borrow().iter()
+            .find(|&&(k, _)| k == key)
+            .map(|&(_, col)| col);
+
+        match col {
+            Some(col) => {
+                let ascending = match self.sort.get() {
+                    Some((c, ascending)) if c == col => !ascending,
+                    _ => true
+                };
+                self.sort_by_column(col, ascending);
+                true
+            },
+            None => false
+        }
+    }
+
+    try_fns! {
+        check = check_destroyed;
+        fn try_set_width = set_width(width: i32);
+        fn try_append_row = append_row(cells: &[&str], data: D)
+            -> Result<(), Error>;
+        fn try_clear = clear();
+        fn try_get_current = get_current() -> Option<D>;
+        fn try_set_current = set_current(num: i32);
+        fn try_get_selection = get_selection() -> Box<[D]>;
+        fn try_sort_by_column = sort_by_column(col: usize, ascending: bool);
+        fn try_handle_hot_key = handle_hot_key(reason: &ExitReason) -> bool;
+    }
+
+    fn check_destroyed(&self) -> Result<(), Error> {
+        self.header.check()?;
+        self.listbox.check()?;
+        Ok(())
+    }
+
+    fn check_column(&self, col: usize) {
+        if col >= self.columns.borrow().len() {
+            panic!("Invalid column index {}", col);
+        }
+    }
+
+    fn content_width(&self) -> usize {
+        let columns = self.columns.borrow();
+        columns.iter().map(|c| c.width).sum::<usize>() + columns.len() - 1
+    }
+
+    fn sort_rows(&self) {
+        if let Some((col, ascending)) = self.sort.get() {
+            self.rows.borrow_mut().sort_by(|a, b| {
+                let ordering = compare_cells(&a.cells[col], &b.cells[col]);
+                if ascending { ordering } else { ordering.reverse() }
+            });
+        }
+    }
+
+    fn render_header(&self) {
+        let columns = self.columns.borrow();
+        let cells: Vec<String> = columns.iter().enumerate()
+            .map(|(i, column)| match self.sort.get() {
+                Some((col, true)) if col == i => {
+                    format!("{} ^", column.title)
+                },
+                Some((col, false)) if col == i => {
+                    format!("{} v", column.title)
+                },
+                _ => column.title.clone()
+            })
+            .collect();
+
+        let titles: Vec<Column> = columns.iter()
+            .map(|c| Column { align: Align::Left, ..c.clone() })
+            .collect();
+        self.header.set_text(&render(&titles, &cells));
+    }
+
+    fn render_row(&self, cells: &[String]) -> String {
+        render(&self.columns.borrow(), cells)
+    }
+
+    fn render_rows(&self) {
+        let entries: Vec<(CString, *const c_void)> = self.rows.borrow()
+            .iter()
+            .map(|row| {
+                let text = self.render_row(&row.cells);
+                (CString::new(text).unwrap(), row.data)
+            })
+            .collect();
+        self.listbox.rebuild(&entries, &self.listbox.raw_selection());
+    }
+}
+
+#[cfg(feature = "asm")]
+impl<D: Data> Parent for Table<D> {
+    fn children(&self) -> Vec<&dyn Component> {
+        vec![&self.header, &self.listbox]
+    }
+}
+
+#[cfg(feature = "asm")]
+impl<D: Data> Composite for Table<D> {
+    fn layout(&self) -> &Layout {
+        &self.layout
+    }
+}
+
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b)
+    }
+}
+
+fn render(columns: &[Column], cells: &[String]) -> String {
+    let cells: Vec<String> = columns.iter().zip(cells.iter())
+        .map(|(column, text)| fit(text, column.width, column.align))
+        .collect();
+    cells.join(" ")
+}
+
+//
+// Pad or truncate `text` to exactly `width` characters.
+//
+fn fit(text: &str, width: usize, align: Align) -> String {
+    let len = text.chars().count();
+    if len > width {
+        let mut text: String = text.chars().take(width - 1).collect();
+        text.push('\u{2026}');
+        return text;
+    }
+
+    let pad = width - len;
+    let (left, right) = match align {
+        Align::Left => (0, pad),
+        Align::Center => (pad / 2, pad - pad / 2),
+        Align::Right => (pad, 0)
+    };
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+#[test]
+fn table_fit() {
+    assert_eq!(fit("abc", 5, Align::Left), "abc  ");
+    assert_eq!(fit("abc", 5, Align::Right), "  abc");
+    assert_eq!(fit("abc", 6, Align::Center), " abc  ");
+    assert_eq!(fit("abcdef", 4, Align::Left), "abc\u{2026}");
+    assert_eq!(fit("abcd", 4, Align::Right), "abcd");
+}
+
+#[test]
+fn table_compare_cells() {
+    assert_eq!(compare_cells("9", "10"), Ordering::Less);
+    assert_eq!(compare_cells("b", "a"), Ordering::Greater);
+}
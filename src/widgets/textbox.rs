@@ -134,4 +134,11 @@ impl Textbox {
     pub fn set_colors(&self, normal: i32, active: i32) {
         unsafe { newtTextboxSetColors(self.co(), normal, active); }
     }
+
+    try_fns! {
+        fn try_set_text = set_text(text: &str);
+        fn try_set_height = set_height(height: i32);
+        fn try_get_num_lines = get_num_lines() -> i32;
+        fn try_set_colors = set_colors(normal: i32, active: i32);
+    }
 }
@@ -85,4 +85,9 @@ impl VerticalScrollbar {
             newtScrollbarSetColors(self.co(), normal, thumb);
         }
     }
+
+    try_fns! {
+        fn try_set = set(where_: i32, total: i32);
+        fn try_set_colors = set_colors(normal: i32, thumb: i32);
+    }
 }
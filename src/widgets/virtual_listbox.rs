@@ -0,0 +1,353 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+use std::cell::{Cell,Ref,RefCell};
+use std::ffi::CString;
+use std::os::raw::c_void;
+
+use newt_sys::*;
+use crate::component::Component;
+use crate::constants::{COLORSET_ACTLISTBOX,COLORSET_LISTBOX,FLAG_SCROLL};
+use crate::error::Error;
+use crate::private::funcs::newt_unset_callback;
+use crate::widgets::{Listbox,VerticalScrollbar};
+
+#[cfg(feature = "asm")]
+use crate::grid::{Composite,Layout,Parent};
+
+///
+/// A source of rows displayed by a [`VirtualListbox`].
+///
+pub trait ListSource {
+    /// `Returns` the total number of rows.
+    fn len(&self) -> usize;
+
+    /// `Returns` `true` if there are no rows.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `Returns` the text of the row at index `index`.
+    fn row(&self, index: usize) -> String;
+}
+
+impl<T: AsRef<str>> ListSource for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn row(&self, index: usize) -> String {
+        self[index].as_ref().to_string()
+    }
+}
+
+///
+/// A list displaying rows from a [`ListSource`] on demand.
+///
+/// Only a window of rows surrounding the current row is loaded into the
+/// underlying [`Listbox`]. Further rows are loaded as the user scrolls.
+/// The [`VerticalScrollbar`] placed to the right of the `Listbox` shows
+/// the position within all of the rows. Both should be added to a `Form`.
+/// When the `asm` feature is enabled a `VirtualListbox` is also a
+/// [`Composite`][composite] and can be added to a `Form` or `Grid`
+/// directly.
+///
+/// Rows are identified by their index in the `ListSource`. Only the
+/// current row is tracked, selecting multiple rows is not supported. The
+/// `VirtualListbox` uses the `Listbox`'s [`Callback`][callback], so
+/// another callback must not be set on it.
+///
+/// [callback]: crate::callbacks::Callback
+/// [composite]: crate::grid::Composite
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::prelude::*;
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     newt::centered_window(22, 10, Some("Rows")).unwrap();
+///
+///     let rows: Vec<String> = (0..100_000)
+///         .map(|i| format!("Row {}", i))
+///         .collect();
+///     let list = VirtualListbox::new(1, 1, 18, 8, rows, 0);
+///
+///     let mut form = Form::new(None, 0);
+///     form.add_components(&[list.listbox(), list.scrollbar()]).unwrap();
+///     form.run().unwrap();
+///     newt::finished();
+///
+///     println!("Selected: {:?}", list.get_current());
+/// }
+/// ```
+///
+pub struct VirtualListbox<S: ListSource> {
+    inner: Box<Inner<S>>,
+    #[cfg(feature = "asm")]
+    layout: Layout
+}
+
+struct Inner<S: ListSource> {
+    listbox: Listbox<usize>,
+    scrollbar: VerticalScrollbar,
+    source: RefCell<S>,
+    start: Cell<usize>,
+    height: usize,
+    updating: Cell<bool>
+}
+
+impl<S: ListSource> VirtualListbox<S> {
+    ///
+    /// Create a new `VirtualListbox`.
+    ///
+    /// * `left` - The left-most position of the `VirtualListbox`.
+    /// * `top` - The top-most position of the `VirtualListbox`.
+    /// * `width` - The width of the `Listbox`, not including the
+    ///   `VerticalScrollbar`.
+    /// * `height` - The height of the `VirtualListbox`.
+    /// * `source` - The source of the rows to be displayed.
+    /// * `flags` - [Flags][listbox] modifying the behavior of the `Listbox`.
+    ///
+    /// [listbox]: crate::constants::listbox
+    ///
+    pub fn new(left: i32, top: i32, width: i32, height: i32, source: S,
+               flags: i32) -> VirtualListbox<S> {
+        assert!(height > 0, "`height` must be greater than 0");
+
+        let listbox = Listbox::new(left, top, height, flags & !FLAG_SCROLL);
+        listbox.set_width(width);
+        let scrollbar = VerticalScrollbar::new(left + width, top, height,
+                                               COLORSET_LISTBOX,
+                                               COLORSET_ACTLISTBOX);
+
+        #[cfg(feature = "asm")]
+        let layout = {
+            let mut layout = Layout::new(2, 1);
+            layout.set_field(0, 0, &listbox, 0, 0, 0, 0, 0, 0);
+            layout.set_field(1, 0, &scrollbar, 0, 0, 0, 0, 0, 0);
+            layout
+        };
+
+        let inner = Box::new(Inner {
+            listbox, scrollbar,
+            source: RefCell::new(source),
+            start: Cell::new(0),
+            height: height as usize,
+            updating: Cell::new(false)
+        });
+
+        unsafe {
+            let c_ptr = inner.as_ref() as *const _ as *mut c_void;
+            newtComponentAddCallback(inner.listbox.co(),
+                                     Some(callback::<S>), c_ptr);
+        }
+
+        inner.load(0);
+        inner.update();
+        VirtualListbox {
+            inner,
+            #[cfg(feature = "asm")]
+            layout
+        }
+    }
+
+    ///
+    /// `Returns` the `Listbox` displaying the loaded rows.
+    ///
+    pub fn listbox(&self) -> &Listbox<usize> {
+        &self.inner.listbox
+    }
+
+    ///
+    /// `Returns` the `VerticalScrollbar` showing the current position.
+    ///
+    pub fn scrollbar(&self) -> &VerticalScrollbar {
+        &self.inner.scrollbar
+    }
+
+    ///
+    /// `Returns` the source of the rows.
+    ///
+    pub fn source(&self) -> Ref<'_, S> {
+        self.inner.source.borrow()
+    }
+
+    ///
+    /// Replace the source of the rows.
+    ///
+    /// The current row is kept if it exists in the new source.
+    ///
+    /// * `source` - The new source of the rows.
+    ///
+    pub fn set_source(&self, source: S) {
+        let current = self.get_current().unwrap_or(0);
+        *self.inner.source.borrow_mut() = source;
+        self.set_current(current);
+    }
+
+    ///
+    /// Reload the rows after the source has changed.
+    ///
+    pub fn refresh(&self) {
+        self.set_current(self.get_current().unwrap_or(0));
+    }
+
+    ///
+    /// Get the total number of rows.
+    ///
+    pub fn len(&self) -> usize {
+        self.inner.source.borrow().len()
+    }
+
+    ///
+    /// `Returns` `true` if there are no rows.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// Get the index of the currently selected row.
+    ///
+    pub fn get_current(&self) -> Option<usize> {
+        self.inner.listbox.get_current().map(|key| key - 1)
+    }
+
+    ///
+    /// Set the currently selected row.
+    ///
+    /// * `index` - The index of the row in the `ListSource`.
+    ///
+    pub fn set_current(&self, index: usize) {
+        let inner = &self.inner;
+        let len = inner.source.borrow().len();
+        let index = index.min(len.saturating_sub(1));
+        inner.updating.set(true);
+        inner.load(inner.window_start(index));
+        inner.listbox.set_current((index - inner.start.get()) as i32);
+        inner.updating.set(false);
+        inner.update();
+    }
+
+    try_fns! {
+        check = check_destroyed;
+        fn try_set_source = set_source(source: S);
+        fn try_refresh = refresh();
+        fn try_get_current = get_current() -> Option<usize>;
+        fn try_set_current = set_current(index: usize);
+    }
+
+    fn check_destroyed(&self) -> Result<(), Error> {
+        self.inner.listbox.check()?;
+        self.inner.scrollbar.check()?;
+        Ok(())
+    }
+}
+
+impl<S: ListSource> Drop for VirtualListbox<S> {
+    fn drop(&mut self) {
+        if !self.inner.listbox.is_destroyed() {
+            unsafe { newt_unset_callback(&self.inner.listbox); }
+        }
+    }
+}
+
+#[cfg(feature = "asm")]
+impl<S: ListSource> Parent for VirtualListbox<S> {
+    fn children(&self) -> Vec<&dyn Component> {
+        vec![&self.inner.listbox, &self.inner.scrollbar]
+    }
+}
+
+#[cfg(feature = "asm")]
+impl<S: ListSource> Composite for VirtualListbox<S> {
+    fn layout(&self) -> &Layout {
+        &self.layout
+    }
+}
+
+impl<S: ListSource> Inner<S> {
+    // The number of rows loaded into the `Listbox` at once.
+    fn capacity(&self) -> usize {
+        self.height * 3
+    }
+
+    //
+    // `Returns` the start of a window centered on row `index`.
+    //
+    fn window_start(&self, index: usize) -> usize {
+        let len = self.source.borrow().len();
+        index.saturating_sub(self.capacity() / 2)
+            .min(len.saturating_sub(self.capacity()))
+    }
+
+    fn load(&self, start: usize) {
+        let source = self.source.borrow();
+        let end = (start + self.capacity()).min(source.len());
+        let entries: Vec<(CString, *const c_void)> = (start..end)
+            .map(|i| {
+                let text = CString::new(source.row(i)).unwrap();
+                (text, (i + 1) as *const c_void)
+            })
+            .collect();
+        self.start.set(start);
+        self.listbox.rebuild(&entries, &[]);
+    }
+
+    //
+    // Move the window of loaded rows when the current row approaches
+    // either end of it.
+    //
+    fn update(&self) {
+        if self.updating.get() {
+            return;
+        }
+
+        let len = self.source.borrow().len();
+        let index = match self.listbox.get_current() {
+            Some(key) => key - 1,
+            None => {
+                self.scrollbar.set(0, 0);
+                return;
+            }
+        };
+
+        let start = self.start.get();
+        let loaded = self.listbox.item_count() as usize;
+        let near_start = start > 0 && index < start + self.height;
+        let near_end = start + loaded < len
+            && index + self.height >= start + loaded;
+
+        if near_start || near_end {
+            self.updating.set(true);
+            self.load(self.window_start(index));
+            self.updating.set(false);
+        }
+        self.scrollbar.set(index as i32 + 1, len as i32);
+    }
+}
+
+unsafe extern "C" fn callback<S: ListSource>(_co: newtComponent,
+                                             data: *mut c_void) {
+    let inner = &*(data as *const Inner<S>);
+    inner.update();
+}
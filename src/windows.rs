@@ -21,10 +21,18 @@
 //! Convenient windowing functions.
 //!
 use std::ffi::CString;
+use std::fmt::Display;
+use std::ops::{Bound,RangeBounds};
 use std::os::raw::c_char;
+use std::str::FromStr;
 
 use newt_sys::*;
 
+use crate::component::Component;
+use crate::constants::{ENTRY_SCROLL,FLAG_PASSWORD,FLAG_SCROLL,KEY_ESCAPE};
+use crate::form::{ExitReason,Form};
+use crate::widgets::{Button,CheckboxTree,Entry,Label,Textbox};
+
 #[cfg(feature = "asm")]
 pub use crate::asm::windows::*;
 
@@ -102,3 +110,409 @@ pub fn win_ternary(title: &str, button1: &str, button2: &str, button3: &str,
                        c_text.as_ptr() as *mut c_char) as i32
     }
 }
+
+///
+/// Open a window containing a list of items that may be checked.
+///
+/// * `title` - The title to be displayed at the top of the window.
+/// * `text` - The text to be displayed above the list.
+/// * `items` - A slice of tuples containing the text of each item and whether
+///   it is initially checked.
+/// * `buttons` - A slice of tuples containing the label of each button
+///   displayed in the window and the value it returns. The first button is
+///   activated when `Enter` is pressed.
+///
+/// `Returns` a tuple pair as `(`button`, `items`)` where `button` is the
+/// value of the button pressed to close the window and `items` contains
+/// the indexes of the checked items, or `None` if `Escape` was pressed.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::windows::win_checklist;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum Action { Install, Cancel }
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     let rv = win_checklist("Packages", "Choose packages to install:",
+///                            &[("vim", true), ("emacs", false)],
+///                            &[("Install", Action::Install),
+///                              ("Cancel", Action::Cancel)]);
+///     newt::finished();
+///
+///     if let Some((Action::Install, packages)) = rv {
+///         println!("install: {:?}", packages);
+///     }
+/// }
+/// ```
+///
+pub fn win_checklist<T: Clone>(title: &str, text: &str,
+                               items: &[(&str, bool)],
+                               buttons: &[(&str, T)])
+      -> Option<(T, Vec<usize>)>
+{
+    win_list(title, text, items, false, buttons)
+}
+
+///
+/// Open a window containing a list of items of which only one may be
+/// selected.
+///
+/// * `title` - The title to be displayed at the top of the window.
+/// * `text` - The text to be displayed above the list.
+/// * `items` - A slice containing the text of each item.
+/// * `default` - The index of the item initially selected.
+/// * `buttons` - A slice of tuples containing the label of each button
+///   displayed in the window and the value it returns. The first button is
+///   activated when `Enter` is pressed.
+///
+/// `Returns` a tuple pair as `(`button`, `item`)` where `button` is the
+/// value of the button pressed to close the window and `item` is the
+/// index of the selected item, or `None` if `Escape` was pressed.
+///
+pub fn win_radiolist<T: Clone>(title: &str, text: &str, items: &[&str],
+                               default: usize, buttons: &[(&str, T)])
+      -> Option<(T, usize)>
+{
+    let items: Vec<(&str, bool)> = items.iter().enumerate()
+        .map(|(i, item)| (*item, i == default))
+        .collect();
+    let (button, selected) = win_list(title, text, &items, true, buttons)?;
+    Some((button, selected.first().copied().unwrap_or(default)))
+}
+
+///
+/// Open a window providing a choice of any number of buttons, each
+/// associated with a value.
+///
+/// * `title` - The title to be displayed at the top of the window.
+/// * `text` - The text to be displayed in the main area of the window.
+/// * `choices` - A slice of tuples containing the label of each button and the
+///   value it returns.
+/// * `default` - The index of the button initially focused.
+/// * `timeout` - An optional number of milliseconds after which the `default`
+///   button is chosen.
+///
+/// `Returns` the value of the button pressed, or `None` if `Escape` was
+/// pressed.
+///
+/// ## Example
+/// ```rust no_run
+/// extern crate newt;
+/// use newt::windows::win_choose;
+///
+/// #[derive(Clone, Copy, Debug)]
+/// enum Action { Save, Discard, Cancel }
+///
+/// pub fn main() {
+///     newt::init().unwrap();
+///     newt::cls();
+///     let action = win_choose("Quit", "Save changes before quitting?",
+///                             &[("Save", Action::Save),
+///                               ("Discard", Action::Discard),
+///                               ("Cancel", Action::Cancel)],
+///                             0, Some(10000));
+///     newt::finished();
+///     println!("action: {:?}", action);
+/// }
+/// ```
+///
+pub fn win_choose<T: Clone>(title: &str, text: &str, choices: &[(&str, T)],
+                            default: usize, timeout: Option<i32>)
+      -> Option<T>
+{
+    if choices.is_empty() {
+        return None;
+    }
+
+    let (cols, _rows) = crate::get_screen_size();
+    let (text, text_width, text_height) = crate::reflow_text(text, 40, 5, 5);
+    let widths: Vec<i32> = choices.iter()
+        .map(|(label, _)| label.chars().count() as i32 + 5)
+        .collect();
+    let width = text_width
+        .max(widths.iter().sum::<i32>() + widths.len() as i32)
+        .min(cols - 6) + 2;
+    let height = text_height + 5;
+
+    crate::centered_window(width as u32, height as u32, Some(title)).ok()?;
+    let textbox = Textbox::new(1, 0, width - 2, text_height, 0);
+    textbox.set_text(&text);
+
+    let top = text_height + 1;
+    let buttons: Vec<Button> = button_positions(width, &widths)
+        .zip(choices.iter())
+        .map(|(left, (label, _))| Button::new(left, top, label))
+        .collect();
+
+    let default = default.min(buttons.len() - 1);
+    let mut form = Form::new(None, 0);
+    form.add_component(&textbox).unwrap();
+    for button in buttons.iter() {
+        form.add_component(button).unwrap();
+    }
+    form.set_current(&buttons[default]);
+    form.add_hot_key(KEY_ESCAPE);
+    if let Some(timeout) = timeout {
+        form.set_timer(timeout);
+    }
+
+    let choice = match form.run() {
+        Ok(ExitReason::Timer) => Some(default),
+        Ok(ExitReason::Component(ref co)) =>
+            buttons.iter().position(|button| button.co() == co.co()),
+        _ => None
+    };
+    crate::pop_window();
+    choice.map(|i| choices[i].1.clone())
+}
+
+//
+// Display the window for `win_checklist()` and `win_radiolist()`.
+//
+fn win_list<T: Clone>(title: &str, text: &str, items: &[(&str, bool)],
+                      radio: bool, buttons: &[(&str, T)])
+      -> Option<(T, Vec<usize>)>
+{
+    if buttons.is_empty() {
+        return None;
+    }
+
+    let (cols, rows) = crate::get_screen_size();
+    let (text, text_width, text_height) = crate::reflow_text(text, 40, 5, 5);
+
+    let list_height = (items.len() as i32).min(rows - text_height - 12)
+        .max(1);
+    let scroll = list_height < items.len() as i32;
+    let item_width = items.iter()
+        .map(|(item, _)| item.chars().count() as i32)
+        .max().unwrap_or(0) + if scroll { 6 } else { 4 };
+    let button_widths: Vec<i32> = buttons.iter()
+        .map(|(label, _)| label.chars().count() as i32 + 5)
+        .collect();
+    let width = text_width.max(item_width)
+        .max(button_widths.iter().sum::<i32>() + button_widths.len() as i32)
+        .min(cols - 6) + 2;
+    let height = text_height + list_height + 6;
+
+    crate::centered_window(width as u32, height as u32, Some(title)).ok()?;
+    let textbox = Textbox::new(1, 0, width - 2, text_height, 0);
+    textbox.set_text(&text);
+
+    let flags = if scroll { FLAG_SCROLL } else { 0 };
+    let tree: CheckboxTree<usize> =
+        CheckboxTree::new(1, text_height + 1, list_height, None, flags);
+    tree.set_width(width - 2);
+    for (i, (item, checked)) in items.iter().enumerate() {
+        tree.add_item(item, i + 1, 0, None);
+        if *checked {
+            tree.set_entry_value(i + 1, '*');
+        }
+    }
+
+    let top = text_height + list_height + 2;
+    let widgets: Vec<Button> = button_positions(width, &button_widths)
+        .zip(buttons.iter())
+        .map(|(left, (label, _))| Button::new(left, top, label))
+        .collect();
+
+    let mut form = Form::new(None, 0);
+    form.add_components(&[&textbox, &tree]).unwrap();
+    for button in widgets.iter() {
+        form.add_component(button).unwrap();
+    }
+    form.set_default_button(&widgets[0]);
+    form.add_hot_key(KEY_ESCAPE);
+    tree.set_single_selection(radio);
+
+    let reason = form.run();
+    crate::pop_window();
+    let button = match reason {
+        Ok(ExitReason::Component(ref co)) =>
+            widgets.iter().position(|button| button.co() == co.co())?,
+        _ => return None
+    };
+    let checked = tree.get_multi_selection('*').iter()
+        .map(|i| i - 1)
+        .collect();
+    Some((buttons[button].1.clone(), checked))
+}
+
+///
+/// Open a window prompting for a line of text.
+///
+/// * `title` - The title to be displayed at the top of the window.
+/// * `text` - The text to be displayed above the entry field.
+/// * `default` - The initial text of the entry field.
+///
+/// `Returns` the text entered, or `None` if the window was cancelled.
+///
+pub fn prompt_string(title: &str, text: &str, default: &str)
+      -> Option<String>
+{
+    prompt(title, text, default, 0, |value| Ok(value.to_string()))
+}
+
+///
+/// Open a window prompting for a password. The characters typed are not
+/// displayed.
+///
+/// * `title` - The title to be displayed at the top of the window.
+/// * `text` - The text to be displayed above the entry field.
+///
+/// `Returns` the password entered, or `None` if the window was cancelled.
+///
+pub fn prompt_password(title: &str, text: &str) -> Option<String> {
+    prompt(title, text, "", FLAG_PASSWORD, |value| Ok(value.to_string()))
+}
+
+///
+/// Open a window prompting for a number within `range`. The user is
+/// prompted again until a valid number is entered or the window is
+/// cancelled.
+///
+/// * `title` - The title to be displayed at the top of the window.
+/// * `text` - The text to be displayed above the entry field.
+/// * `range` - The range of numbers accepted.
+///
+/// `Returns` the number entered, or `None` if the window was cancelled.
+///
+pub fn prompt_number<T, R>(title: &str, text: &str, range: R) -> Option<T>
+    where T: FromStr + PartialOrd + Display, R: RangeBounds<T>
+{
+    prompt(title, text, "", 0, |value| parse_number(value, &range))
+}
+
+///
+/// Open a window asking a yes or no question.
+///
+/// * `title` - The title to be displayed at the top of the window.
+/// * `text` - The question to be displayed in the window.
+///
+/// `Returns` `true` if `Yes` was pressed, `false` if `No` was pressed, or
+/// `None` if the window was cancelled.
+///
+pub fn prompt_confirm(title: &str, text: &str) -> Option<bool> {
+    win_choose(title, text, &[("Yes", true), ("No", false)], 0, None)
+}
+
+//
+// Display the window for the `prompt_` functions, running it until
+// `parse` accepts the text entered.
+//
+fn prompt<T, F>(title: &str, text: &str, default: &str, flags: i32,
+                parse: F) -> Option<T>
+    where F: Fn(&str) -> Result<T, String>
+{
+    let (cols, _rows) = crate::get_screen_size();
+    let (text, text_width, text_height) = crate::reflow_text(text, 40, 5, 5);
+    let width = text_width.max(30).min(cols - 6) + 2;
+    let height = text_height + 7;
+
+    crate::centered_window(width as u32, height as u32, Some(title)).ok()?;
+    let textbox = Textbox::new(1, 0, width - 2, text_height, 0);
+    textbox.set_text(&text);
+    let entry = Entry::new(1, text_height + 1, Some(default), width - 2,
+                           ENTRY_SCROLL | flags);
+    let error = Label::new(1, text_height + 2, "");
+
+    let mut positions = button_positions(width, &[7, 11]);
+    let top = text_height + 3;
+    let ok = Button::new(positions.next().unwrap(), top, "Ok");
+    let cancel = Button::new(positions.next().unwrap(), top, "Cancel");
+
+    let mut form = Form::new(None, 0);
+    form.add_components(&[&textbox, &entry, &error, &ok, &cancel])
+        .unwrap();
+    form.set_default_button(&ok);
+    form.set_cancel_button(&cancel);
+
+    let value = loop {
+        match form.run() {
+            Ok(ref reason) if *reason == ok => (),
+            _ => break None
+        }
+        match parse(&entry.get_text()) {
+            Ok(value) => break Some(value),
+            Err(message) => {
+                error.set_text(&message);
+                form.set_current(&entry);
+            }
+        }
+    };
+    crate::pop_window();
+    value
+}
+
+//
+// Parse a number within `range`, returning a message describing the
+// problem on failure.
+//
+fn parse_number<T, R>(text: &str, range: &R) -> Result<T, String>
+    where T: FromStr + PartialOrd + Display, R: RangeBounds<T>
+{
+    let number: T = text.trim().parse()
+        .map_err(|_| "Please enter a number.".to_string())?;
+    if range.contains(&number) {
+        return Ok(number);
+    }
+
+    let message = match (range.start_bound(), range.end_bound()) {
+        (Bound::Included(start), Bound::Included(end)) =>
+            format!("Enter a number from {} to {}.", start, end),
+        (Bound::Included(start), Bound::Excluded(end)) =>
+            format!("Enter a number of at least {} and below {}.",
+                    start, end),
+        (Bound::Included(start), _) =>
+            format!("Enter a number of at least {}.", start),
+        (Bound::Excluded(start), _) =>
+            format!("Enter a number above {}.", start),
+        (_, Bound::Included(end)) =>
+            format!("Enter a number of at most {}.", end),
+        (_, Bound::Excluded(end)) =>
+            format!("Enter a number below {}.", end),
+        (_, Bound::Unbounded) => "Please enter a number.".to_string()
+    };
+    Err(message)
+}
+
+//
+// Spread buttons of the given widths evenly across a window.
+//
+fn button_positions(width: i32, widths: &[i32])
+      -> impl Iterator<Item = i32> + '_ {
+    let gap = ((width - widths.iter().sum::<i32>()) /
+               (widths.len() as i32 + 1)).max(0);
+    widths.iter().scan(gap, move |left, w| {
+        let position = *left;
+        *left += w + gap;
+        Some(position)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{button_positions,parse_number};
+
+    #[test]
+    fn spread_buttons() {
+        let positions: Vec<i32> = button_positions(30, &[7, 11]).collect();
+        assert_eq!(positions, [4, 15]);
+        let positions: Vec<i32> = button_positions(10, &[7, 11]).collect();
+        assert_eq!(positions, [0, 7]);
+    }
+
+    #[test]
+    fn parse_numbers() {
+        assert_eq!(parse_number::<i32, _>(" 5 ", &(1..=10)), Ok(5));
+        assert_eq!(parse_number::<u8, _>("-1", &(..)),
+                   Err("Please enter a number.".to_string()));
+        assert_eq!(parse_number::<i32, _>("11", &(1..=10)),
+                   Err("Enter a number from 1 to 10.".to_string()));
+        assert_eq!(parse_number::<f64, _>("0.5", &(1.0..)),
+                   Err("Enter a number of at least 1.".to_string()));
+    }
+}
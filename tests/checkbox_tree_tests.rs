@@ -22,10 +22,10 @@ use std::{i8,i32,isize};
 use std::{u8,u32,usize};
 use std::ptr;
 
-use newt::Component;
+use newt::{Component,Error};
 use newt::component::Data;
-use newt::widgets::{Button,CheckboxTree};
-use newt::constants::ARG_APPEND;
+use newt::widgets::{Button,CheckboxTree,TreeHandle,TreeItem};
+use newt::constants::{ARG_APPEND,CHECKBOXTREE_EXPANDED};
 
 struct TestStruct<'a> {
     pub v1: usize,
@@ -275,3 +275,184 @@ fn checkbox_tree_get_multi_selection_struct() {
     assert!(result[1].v2 == 27);
     assert!(result[1].v3 == "Baz");
 }
+
+fn tree_model() -> CheckboxTree {
+    let tree: CheckboxTree = CheckboxTree::new(-1, -1, 10, None, 0);
+    tree.add_tree(None, &[
+        TreeItem::new("Editors", 1, vec![
+            TreeItem::new("vim", 2, vec![]),
+            TreeItem::new("emacs", 3, vec![])
+        ]),
+        TreeItem::new("Shells", 4, vec![
+            TreeItem::new("bash", 5, vec![
+                TreeItem::new("bash-completion", 6, vec![])
+            ])
+        ])
+    ]).unwrap();
+    tree
+}
+
+#[test]
+fn checkbox_tree_add_root_and_child() {
+    let tree: CheckboxTree = CheckboxTree::new(-1, -1, 10, None, 0);
+    let root = tree.add_root("Editors", 1).unwrap();
+    let vim = tree.add_child(&root, "vim", 2).unwrap();
+    let emacs = tree.add_child(&root, "emacs", 3).unwrap();
+    assert_eq!(root.path(), &[0]);
+    assert_eq!(vim.path(), &[0, 0]);
+    assert_eq!(emacs.path(), &[0, 1]);
+    assert_eq!(tree.children(&root), vec![vim, emacs.clone()]);
+    assert_eq!(tree.parent(&emacs), Some(root.clone()));
+    assert_eq!(tree.parent(&root), None);
+    assert_eq!(tree.get_data(&emacs), Some(3));
+}
+
+#[test]
+fn checkbox_tree_add_child_invalid_parent() {
+    let tree: CheckboxTree = CheckboxTree::new(-1, -1, 10, None, 0);
+    tree.add_root("Editors", 1).unwrap();
+    let missing = TreeHandle::from_path(&[4]);
+    assert_eq!(tree.add_child(&missing, "vim", 2).err(),
+               Some(Error::InvalidItem));
+}
+
+#[test]
+fn checkbox_tree_add_tree() {
+    let tree = tree_model();
+    let roots = tree.roots();
+    assert_eq!(roots.len(), 2);
+    assert_eq!(tree.children(&roots[0]).len(), 2);
+    let bash = &tree.children(&roots[1])[0];
+    assert_eq!(tree.get_data(bash), Some(5));
+    assert_eq!(tree.children(bash).len(), 1);
+}
+
+#[test]
+fn checkbox_tree_handle_round_trip() {
+    let tree = tree_model();
+    let handle = tree.handle(6).unwrap();
+    assert_eq!(handle.path(), &*tree.find_item(6));
+    assert_eq!(handle, TreeHandle::from(tree.find_item(6)));
+    assert_eq!(tree.get_data(&handle), Some(6));
+    assert_eq!(tree.handle(100), None);
+}
+
+#[test]
+fn checkbox_tree_add_item_updates_model() {
+    let tree = tree_model();
+    tree.add_item("Browsers", 7, 0, Some(&[1]));
+    assert_eq!(tree.roots().len(), 3);
+    assert_eq!(tree.handle(4).unwrap().path(), &[2]);
+    assert_eq!(tree.get_data(&TreeHandle::from_path(&[1])), Some(7));
+}
+
+#[test]
+fn checkbox_tree_expand_collapse() {
+    let tree = tree_model();
+    let editors = tree.handle(1).unwrap();
+    assert!(!tree.is_expanded(&editors));
+    tree.expand(&editors);
+    assert!(tree.is_expanded(&editors));
+    assert_eq!(tree.get_current(), Some(1));
+    tree.collapse(&editors);
+    assert!(!tree.is_expanded(&editors));
+    assert_eq!(tree.get_current(), Some(1));
+}
+
+#[test]
+fn checkbox_tree_collapse_current_child() {
+    let tree = tree_model();
+    let editors = tree.handle(1).unwrap();
+    tree.set_current(3);
+    assert!(tree.is_expanded(&editors));
+    tree.collapse(&editors);
+    assert!(!tree.is_expanded(&editors));
+    assert_eq!(tree.get_current(), Some(1));
+}
+
+#[test]
+fn checkbox_tree_expand_all() {
+    let tree = tree_model();
+    tree.expand_all();
+    for data in [1, 4, 5].iter() {
+        assert!(tree.is_expanded(&tree.handle(*data).unwrap()));
+    }
+    tree.collapse_all();
+    for data in [1, 4, 5].iter() {
+        assert!(!tree.is_expanded(&tree.handle(*data).unwrap()));
+    }
+}
+
+#[test]
+fn checkbox_tree_find_missing_item() {
+    let tree = tree_model();
+    assert!(tree.find_item(100).is_empty());
+}
+
+#[test]
+fn checkbox_tree_hierarchical_values() {
+    let tree = tree_model();
+    tree.set_hierarchical(Some('~'));
+    assert_eq!(tree.get_entry_value(1), ' ');
+
+    tree.set_entry_value(2, '*');
+    assert_eq!(tree.get_entry_value(1), '~');
+    tree.set_entry_value(3, '*');
+    assert_eq!(tree.get_entry_value(1), '*');
+
+    tree.set_entry_value(4, '*');
+    assert_eq!(tree.get_entry_value(5), '*');
+    assert_eq!(tree.get_entry_value(6), '*');
+    tree.set_entry_value(4, ' ');
+    assert_eq!(tree.get_entry_value(6), ' ');
+}
+
+#[test]
+fn checkbox_tree_hierarchical_add_item() {
+    let tree = tree_model();
+    tree.set_hierarchical(Some('~'));
+    tree.set_entry_value(1, '*');
+    let editors = tree.handle(1).unwrap();
+    tree.add_child(&editors, "nano", 7).unwrap();
+    assert_eq!(tree.get_entry_value(1), '~');
+}
+
+#[test]
+fn checkbox_tree_hierarchical_disable() {
+    let tree = tree_model();
+    tree.set_hierarchical(Some('~'));
+    tree.set_hierarchical(None);
+    tree.expand(&tree.handle(1).unwrap());
+    assert_eq!(tree.get_entry_value(1), CHECKBOXTREE_EXPANDED as u8 as char);
+}
+
+#[test]
+fn checkbox_tree_single_selection() {
+    let tree = tree_model();
+    tree.set_entry_value(2, '*');
+    tree.set_entry_value(3, '*');
+    tree.set_single_selection(true);
+    assert_eq!(&*tree.get_selection(), &[2]);
+
+    tree.set_entry_value(6, '*');
+    assert_eq!(&*tree.get_selection(), &[6]);
+    tree.set_entry_value(6, ' ');
+    assert!(tree.get_selection().is_empty());
+    tree.set_entry_value(3, '*');
+    assert_eq!(&*tree.get_selection(), &[3]);
+
+    tree.set_single_selection(false);
+    tree.set_entry_value(2, '*');
+    assert_eq!(&*tree.get_selection(), &[2, 3]);
+}
+
+#[test]
+fn checkbox_tree_compact_selection() {
+    let tree = tree_model();
+    tree.set_entry_value(2, '*');
+    tree.set_entry_value(3, '*');
+    tree.set_entry_value(6, '*');
+    assert_eq!(&*tree.get_compact_selection(), &[1, 4]);
+    tree.set_entry_value(3, ' ');
+    assert_eq!(&*tree.get_compact_selection(), &[2, 4]);
+}
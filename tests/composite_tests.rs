@@ -0,0 +1,106 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+#![cfg(all(feature = "asm",
+           any(target_arch = "aarch64", target_arch = "arm",
+               target_arch = "riscv32", target_arch = "riscv64",
+               target_arch = "x86",     target_arch = "x86_64")))]
+extern crate newt;
+use newt::grid::*;
+use newt::prelude::*;
+
+struct LabeledEntry {
+    label: Label,
+    entry: Entry,
+    layout: Layout
+}
+
+impl LabeledEntry {
+    fn new(text: &str) -> LabeledEntry {
+        let label = Label::new(0, 0, text);
+        let entry = Entry::new(0, 0, None, 10, 0);
+
+        let mut layout = Layout::new(2, 1);
+        layout.set_field(0, 0, &label, 0, 0, 1, 0, 0, 0);
+        layout.set_field(1, 0, &entry, 0, 0, 0, 0, 0, 0);
+        LabeledEntry { label, entry, layout }
+    }
+}
+
+impl Parent for LabeledEntry {
+    fn children(&self) -> Vec<&dyn Component> {
+        vec![&self.label, &self.entry]
+    }
+}
+
+impl Composite for LabeledEntry {
+    fn layout(&self) -> &Layout {
+        &self.layout
+    }
+}
+
+#[test]
+fn composite_create() {
+    let composite = LabeledEntry::new("Name:");
+    assert!(!composite.co().is_null());
+    assert_eq!(composite.children().len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn composite_invalid_position() {
+    let label = Label::new(0, 0, "Name:");
+    let mut layout = Layout::new(1, 1);
+    layout.set_field(1, 0, &label, 0, 0, 0, 0, 0, 0);
+}
+
+#[test]
+fn composite_add_to_form() {
+    let composite = LabeledEntry::new("Name:");
+    let mut form = Form::new(None, 0);
+    form.add_component(&composite).unwrap();
+    assert!(form.add_component(&composite).is_err());
+    assert!(form.add_component(&composite.entry).is_err());
+}
+
+#[test]
+fn composite_add_to_grid() {
+    let c1 = LabeledEntry::new("Name:");
+    let c2 = LabeledEntry::new("Email:");
+
+    let mut grid = Grid::new(1, 2);
+    grid.set_field(0, 0, &c1, 0, 0, 0, 0, 0, 0);
+    grid.set_field(0, 1, &c2, 0, 0, 0, 0, 0, 0);
+    assert_eq!(grid.children().len(), 4);
+
+    let mut form = Form::new(None, 0);
+    grid.add_to_form(&mut form).unwrap();
+    assert!(form.add_component(&c1).is_err());
+}
+
+#[test]
+#[should_panic]
+fn composite_nullify() {
+    let composite = LabeledEntry::new("Name:");
+    {
+        let mut form = Form::new(None, 0);
+        form.add_component(&composite).unwrap();
+    }
+    composite.entry.get_text();
+}
@@ -0,0 +1,99 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+use std::num::{NonZeroI32,NonZeroU8,NonZeroUsize};
+
+use newt::data::Data;
+use newt::widgets::{CheckboxTree,Listbox};
+use newt::constants::FLAG_MULTIPLE;
+use newt::constants::FlagsSense::Set;
+
+#[derive(Clone, Copy, Data, Debug, PartialEq)]
+enum Action {
+    Install,
+    Upgrade = 5,
+    Remove
+}
+
+#[derive(Clone, Copy, Data, Debug, PartialEq)]
+struct PackageId(u32);
+
+#[derive(Clone, Copy, Data, Debug, PartialEq)]
+struct Host {
+    id: Action
+}
+
+#[test]
+fn data_derive_enum() {
+    let listbox: Listbox<Action> = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
+    listbox.append_entry("Install", Action::Install).unwrap();
+    listbox.append_entry("Upgrade", Action::Upgrade).unwrap();
+    listbox.append_entry("Remove", Action::Remove).unwrap();
+    assert_eq!(listbox.get_current(), Some(Action::Install));
+
+    listbox.select_item(Action::Install, Set);
+    listbox.select_item(Action::Remove, Set);
+    let result = listbox.get_selection();
+    assert_eq!(*result, [Action::Install, Action::Remove]);
+}
+
+#[test]
+fn data_derive_newtype() {
+    let listbox: Listbox<PackageId> = Listbox::new(-1, -1, 5, 0);
+    listbox.append_entry("entry1", PackageId(42)).unwrap();
+    assert_eq!(listbox.get_current(), Some(PackageId(42)));
+    assert_eq!(listbox.get_entry(0).1, PackageId(42));
+}
+
+#[test]
+fn data_derive_named_newtype() {
+    let tree: CheckboxTree<Host> = CheckboxTree::new(-1, -1, 5, None, 0);
+    tree.add_item("host", Host { id: Action::Remove }, 0, None);
+    assert_eq!(tree.get_current(), Some(Host { id: Action::Remove }));
+}
+
+#[test]
+#[should_panic(expected = "Invalid Action value.")]
+fn data_derive_enum_invalid() {
+    unsafe { Action::newt_from_ptr(10 as *const _); }
+}
+
+#[test]
+fn data_bool() {
+    let listbox: Listbox<bool> = Listbox::new(-1, -1, 5, 0);
+    listbox.append_entry("entry1", true).unwrap();
+    assert_eq!(listbox.get_current(), Some(true));
+    assert!(!unsafe { bool::newt_from_ptr(false.newt_to_ptr()) });
+}
+
+#[test]
+fn data_non_zero() {
+    let listbox: Listbox<NonZeroI32> = Listbox::new(-1, -1, 5, 0);
+    let value = NonZeroI32::new(i32::MIN).unwrap();
+    listbox.append_entry("entry1", value).unwrap();
+    assert_eq!(listbox.get_current(), Some(value));
+
+    let value = NonZeroU8::new(u8::MAX).unwrap();
+    let ptr = value.newt_to_ptr();
+    assert_eq!(unsafe { NonZeroU8::newt_from_ptr(ptr) }, value);
+    let value = NonZeroUsize::new(usize::MAX).unwrap();
+    let ptr = value.newt_to_ptr();
+    assert_eq!(unsafe { NonZeroUsize::newt_from_ptr(ptr) }, value);
+}
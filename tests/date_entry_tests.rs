@@ -0,0 +1,61 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+#![cfg(feature = "chrono")]
+extern crate chrono;
+extern crate newt;
+
+use chrono::NaiveDate;
+use newt::widgets::DateEntry;
+
+#[test]
+fn date_entry_create() {
+    let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+    let entry = DateEntry::new(-1, -1, date, 0);
+    assert!(!entry.has_time());
+    assert_eq!(entry.entry().get_text(), "2026-10-19");
+    assert_eq!(entry.get_date(), Some(date));
+    assert_eq!(entry.get_datetime(), date.and_hms_opt(0, 0, 0));
+}
+
+#[test]
+fn date_entry_datetime() {
+    let datetime = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap()
+        .and_hms_opt(9, 5, 0).unwrap();
+    let entry = DateEntry::new_datetime(-1, -1, datetime, 0);
+    assert!(entry.has_time());
+    assert_eq!(entry.entry().get_text(), "2026-10-19 09:05");
+    assert_eq!(entry.get_datetime(), Some(datetime));
+
+    let date = NaiveDate::from_ymd_opt(2027, 1, 2).unwrap();
+    entry.set_date(date);
+    assert_eq!(entry.entry().get_text(), "2027-01-02 00:00");
+}
+
+#[test]
+fn date_entry_invalid() {
+    let date = NaiveDate::from_ymd_opt(2026, 2, 28).unwrap();
+    let entry = DateEntry::new(-1, -1, date, 0);
+    entry.entry().set_text("2026-02-30", false);
+    assert_eq!(entry.get_date(), None);
+
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    entry.set_date(date);
+    assert_eq!(entry.get_date(), Some(date));
+}
@@ -0,0 +1,99 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+use newt::component::Component;
+use newt::prelude::*;
+
+#[derive(Component)]
+struct Names(Listbox<i32>);
+
+#[derive(Component)]
+struct Name {
+    #[component]
+    entry: Entry,
+    changed: bool
+}
+
+#[derive(Component)]
+struct Wrapper<C: Component> {
+    inner: C
+}
+
+#[test]
+fn derive_newtype() {
+    let names = Names(Listbox::new(0, 0, 5, 0));
+    assert!(!names.co().is_null());
+    assert!(names.co() == names.0.co());
+    assert!(names == names.0);
+
+    names.0.append_entry("One", 1).unwrap();
+    assert_eq!(names.0.item_count(), 1);
+}
+
+#[test]
+fn derive_marked_field() {
+    let name = Name { entry: Entry::new(0, 0, None, 10, 0), changed: false };
+    assert!(name.co() == name.entry.co());
+    assert!(!name.changed);
+}
+
+#[test]
+fn derive_generic() {
+    let wrapper = Wrapper { inner: Button::new(0, 0, "Ok") };
+    assert!(wrapper.co() == wrapper.inner.co());
+}
+
+#[test]
+fn derive_add_to_form() {
+    let names = Names(Listbox::new(0, 0, 5, 0));
+    let mut form = Form::new(None, 0);
+    form.add_component(&names).unwrap();
+    assert!(form.add_component(&names).is_err());
+    assert!(form.add_component(&names.0).is_err());
+}
+
+#[test]
+#[should_panic]
+fn derive_nullify() {
+    let name = Name { entry: Entry::new(0, 0, None, 10, 0), changed: false };
+    {
+        let mut form = Form::new(None, 0);
+        form.add_component(&name).unwrap();
+    }
+    name.get_position();
+}
+
+#[test]
+#[cfg(feature = "asm")]
+fn derive_grid_placement() {
+    use newt::grid::*;
+
+    let names = Names(Listbox::new(0, 0, 5, 0));
+    let buttons = Wrapper { inner: ButtonBar::new(&["Ok", "Cancel"]) };
+
+    let mut grid = Grid::new(1, 2);
+    grid.set_field(0, 0, &names, 0, 0, 0, 0, 0, 0);
+    grid.set_field(0, 1, &buttons, 0, 0, 0, 0, 0, 0);
+    assert_eq!(grid.children().len(), 3);
+
+    let mut form = Form::new(None, 0);
+    grid.add_to_form(&mut form).unwrap();
+    assert!(form.add_component(&names).is_err());
+}
@@ -0,0 +1,99 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+extern crate tempfile;
+
+use std::fs::{self,File};
+use std::path::{Path,PathBuf};
+
+use newt::dialogs::FileChooser;
+
+fn setup() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("src")).unwrap();
+    fs::create_dir(dir.path().join(".git")).unwrap();
+    File::create(dir.path().join("Cargo.toml")).unwrap();
+    File::create(dir.path().join("README.md")).unwrap();
+    File::create(dir.path().join("build.rs")).unwrap();
+    File::create(dir.path().join(".gitignore")).unwrap();
+    File::create(dir.path().join("src/lib.rs")).unwrap();
+    dir
+}
+
+fn names(paths: &[PathBuf]) -> Vec<String> {
+    paths.iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+        .collect()
+}
+
+#[test]
+fn file_chooser_entries() {
+    let dir = setup();
+    let chooser = FileChooser::new("Open", dir.path());
+    assert_eq!(chooser.directory(), dir.path());
+
+    let entries = chooser.entries(dir.path()).unwrap();
+    assert_eq!(names(&entries), ["src", "Cargo.toml", "README.md",
+                                 "build.rs"]);
+    assert_eq!(entries[0], dir.path().join("src"));
+}
+
+#[test]
+fn file_chooser_show_hidden() {
+    let dir = setup();
+    let mut chooser = FileChooser::new("Open", dir.path());
+    chooser.set_show_hidden(true);
+
+    let entries = chooser.entries(dir.path()).unwrap();
+    assert_eq!(names(&entries), [".git", "src", ".gitignore", "Cargo.toml",
+                                 "README.md", "build.rs"]);
+}
+
+#[test]
+fn file_chooser_filters() {
+    let dir = setup();
+    let mut chooser = FileChooser::new("Open", dir.path());
+    chooser.set_filters(&["*.rs", "*.toml"]);
+    assert!(chooser.matches("lib.rs"));
+    assert!(!chooser.matches("README.md"));
+
+    let entries = chooser.entries(dir.path()).unwrap();
+    assert_eq!(names(&entries), ["src", "Cargo.toml", "build.rs"]);
+
+    let entries = chooser.entries(dir.path().join("src")).unwrap();
+    assert_eq!(names(&entries), ["lib.rs"]);
+}
+
+#[test]
+fn file_chooser_directories() {
+    let dir = setup();
+    let mut chooser = FileChooser::new("Choose Directory", dir.path());
+    chooser.set_directories(true);
+    chooser.set_show_hidden(true);
+
+    let entries = chooser.entries(dir.path()).unwrap();
+    assert_eq!(names(&entries), [".git", "src"]);
+}
+
+#[test]
+fn file_chooser_missing_directory() {
+    let chooser = FileChooser::new("Open", "/");
+    assert!(chooser.entries(Path::new("/nonexistent/directory")).is_err());
+}
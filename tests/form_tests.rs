@@ -19,7 +19,7 @@
 
 extern crate newt;
 use newt::form::Form;
-use newt::widgets::Button;
+use newt::widgets::{Button,Entry};
 
 use newt::constants::KEY_ENTER;
 
@@ -75,6 +75,22 @@ fn form_take_component() {
     form.take_component(button).unwrap();
 }
 
+#[test]
+fn form_component() {
+    let button = Button::new(-1, -1, "Ok");
+    let entry = Entry::new(-1, -1, Some("text"), 10, 0);
+
+    let mut form = Form::new(None, 0);
+    let b_handle = form.take_component(button).unwrap();
+    let e_handle = form.take_component(entry).unwrap();
+    assert!(b_handle != e_handle);
+
+    assert!(form.component::<Button>(b_handle).is_some());
+    assert!(form.component::<Entry>(b_handle).is_none());
+    let entry = form.component::<Entry>(e_handle).unwrap();
+    assert_eq!(entry.get_text(), "text");
+}
+
 #[test]
 fn form_set_height() {
     let form = Form::new(None, 0);
@@ -93,6 +109,17 @@ fn form_add_hot_key() {
     form.add_hot_key(KEY_ENTER);
 }
 
+#[test]
+fn form_set_default_button() {
+    let ok = Button::new(-1, -1, "Ok");
+    let cancel = Button::new(-1, -1, "Cancel");
+
+    let mut form = Form::new(None, 0);
+    form.add_components(&[&ok, &cancel]).unwrap();
+    form.set_default_button(&ok);
+    form.set_cancel_button(&cancel);
+}
+
 #[test]
 fn form_set_timer() {
     let form = Form::new(None, 0);
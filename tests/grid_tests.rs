@@ -23,6 +23,8 @@
                target_arch = "x86",     target_arch = "x86_64")))]
 extern crate newt;
 use newt::Component;
+use newt::constants::*;
+use newt::form::{ExitReason,Form};
 use newt::widgets::Button;
 use newt::grid::*;
 use std::ptr;
@@ -50,6 +52,41 @@ fn button_bar_create() {
     assert_eq!(button_bar.buttons().len(), 5);
 }
 
+#[test]
+fn button_bar_values() {
+    let button_bar = ButtonBar::new(&["One", "Two", "Three"]);
+    assert_eq!(button_bar.values(), &[0, 1, 2]);
+
+    let button_bar = ButtonBar::with_values(&[("Yes", 'y'), ("No", 'n')]);
+    assert_eq!(button_bar.buttons().len(), 2);
+    assert_eq!(button_bar.values(), &['y', 'n']);
+}
+
+#[test]
+fn button_bar_hot_keys() {
+    let button_bar = ButtonBar::with_values(&[("Yes", "yes"), ("No", "no")]);
+    assert_eq!(button_bar.value(&ExitReason::HotKey(KEY_ENTER)), None);
+    assert_eq!(button_bar.value(&ExitReason::HotKey(KEY_ESCAPE)), None);
+
+    button_bar.set_hot_key(0, KEY_F2);
+    button_bar.set_hot_key(1, KEY_F3);
+    button_bar.set_default(0);
+    button_bar.set_cancel(1);
+    assert_eq!(button_bar.value(&ExitReason::HotKey(KEY_F2)), Some(&"yes"));
+    assert_eq!(button_bar.value(&ExitReason::HotKey(KEY_F3)), Some(&"no"));
+    assert_eq!(button_bar.value(&ExitReason::HotKey(KEY_ENTER)), Some(&"yes"));
+    assert_eq!(button_bar.value(&ExitReason::HotKey(KEY_ESCAPE)), Some(&"no"));
+    assert_eq!(button_bar.value(&ExitReason::HotKey(KEY_F4)), None);
+    assert_eq!(button_bar.value(&ExitReason::Timer), None);
+}
+
+#[test]
+#[should_panic]
+fn button_bar_invalid_index() {
+    let button_bar = ButtonBar::new(&["One", "Two"]);
+    button_bar.set_default(2);
+}
+
 #[test]
 fn grid_create() {
     let grid = Grid::new(2, 2);
@@ -215,3 +252,10 @@ fn horizontal_grid_close_stacked_create() {
     let grid = HorizontalGrid::new_close_stacked(&[&b1, &b2, &b3, &b4, &b5]);
     assert!(grid.co() != ptr::null_mut());
 }
+
+#[test]
+fn grid_take_component() {
+    let grid = ButtonBar::new(&["Ok"]);
+    let mut form = Form::new(None, 0);
+    assert!(form.take_component(grid).is_err());
+}
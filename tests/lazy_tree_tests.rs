@@ -0,0 +1,127 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+extern crate newt;
+use std::cell::Cell;
+use std::ptr;
+
+use newt::Component;
+use newt::widgets::{LazyNode,LazyTree,TreeSource};
+
+struct Numbers {
+    width: Cell<u32>,
+    loads: Cell<u32>
+}
+
+impl Numbers {
+    fn new(width: u32) -> Numbers {
+        Numbers { width: Cell::new(width), loads: Cell::new(0) }
+    }
+}
+
+impl TreeSource for Numbers {
+    type Value = u32;
+
+    fn roots(&self) -> Vec<LazyNode<u32>> {
+        (1..=self.width.get())
+            .map(|i| LazyNode::new(&i.to_string(), i, true))
+            .collect()
+    }
+
+    fn children(&self, parent: &u32) -> Vec<LazyNode<u32>> {
+        self.loads.set(self.loads.get() + 1);
+        (1..=self.width.get())
+            .map(|i| {
+                let value = parent * 10 + i;
+                LazyNode::new(&value.to_string(), value, value < 100)
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn lazy_tree_create() {
+    let tree = LazyTree::new(0, 0, 10, Numbers::new(3), 0);
+    assert!(tree.tree().co() != ptr::null_mut());
+    assert_eq!(tree.tree().roots().len(), 3);
+    assert_eq!(tree.source().loads.get(), 0);
+    assert_eq!(tree.get_current(), Some(1));
+}
+
+#[test]
+fn lazy_tree_placeholder() {
+    let tree = LazyTree::new(0, 0, 10, Numbers::new(3), 0);
+    let root = &tree.tree().roots()[0];
+    assert_eq!(tree.tree().children(root).len(), 1);
+}
+
+#[test]
+fn lazy_tree_expand() {
+    let tree = LazyTree::new(0, 0, 10, Numbers::new(3), 0);
+    tree.expand(&2);
+    assert_eq!(tree.source().loads.get(), 1);
+
+    let handle = tree.tree().handle(tree.tree().get_current().unwrap());
+    assert_eq!(handle.unwrap().path(), &[0]);
+    let root = &tree.tree().roots()[1];
+    assert!(tree.tree().is_expanded(root));
+    let children = tree.tree().children(root);
+    assert_eq!(children.len(), 3);
+
+    let key = tree.tree().get_data(&children[2]).unwrap();
+    tree.tree().set_current(key);
+    assert_eq!(tree.get_current(), Some(23));
+}
+
+#[test]
+fn lazy_tree_cache() {
+    let tree = LazyTree::new(0, 0, 10, Numbers::new(3), 0);
+    tree.expand(&2);
+    tree.tree().collapse(&tree.tree().roots()[1]);
+    tree.expand(&2);
+    assert_eq!(tree.source().loads.get(), 1);
+}
+
+#[test]
+fn lazy_tree_refresh() {
+    let tree = LazyTree::new(0, 0, 10, Numbers::new(3), 0);
+    tree.expand(&1);
+    tree.source().width.set(4);
+    tree.refresh();
+    assert_eq!(tree.source().loads.get(), 2);
+    assert_eq!(tree.tree().roots().len(), 4);
+    let root = &tree.tree().roots()[0];
+    assert_eq!(tree.tree().children(root).len(), 4);
+
+    tree.source().width.set(2);
+    tree.refresh();
+    let key = tree.tree().get_data(&tree.tree().roots()[3]).unwrap();
+    tree.tree().set_current(key);
+    assert_eq!(tree.get_current(), None);
+}
+
+#[test]
+fn lazy_tree_selection() {
+    let tree = LazyTree::new(0, 0, 10, Numbers::new(3), 0);
+    tree.expand(&1);
+    tree.expand(&11);
+    let handle = tree.tree().children(&tree.tree().roots()[0])[0].clone();
+    let leaf = tree.tree().get_data(&tree.tree().children(&handle)[1]);
+    tree.tree().set_entry_value(leaf.unwrap(), '*');
+    assert_eq!(&*tree.get_selection(), &[112]);
+}
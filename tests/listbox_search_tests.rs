@@ -0,0 +1,105 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+use newt::widgets::{Entry,Listbox,ListboxSearch,SearchMode};
+use newt::constants::FLAG_MULTIPLE;
+use newt::constants::FlagsSense::Set;
+
+const HOSTS: [&str; 5] = ["alpha", "Bravo", "charlie", "delta", "echo"];
+
+fn listbox(flags: i32) -> Listbox<usize> {
+    let listbox = Listbox::new(-1, -1, 5, flags);
+    for (i, host) in HOSTS.iter().enumerate() {
+        listbox.append_entry(host, i + 1).unwrap();
+    }
+    listbox
+}
+
+fn data(listbox: &Listbox<usize>) -> Vec<usize> {
+    listbox.iter().map(|(_, data)| data).collect()
+}
+
+#[test]
+fn listbox_search_jump() {
+    let entry = Entry::new(-1, -1, None, 10, 0);
+    let listbox = listbox(0);
+    let search = ListboxSearch::new(&entry, &listbox, SearchMode::Jump);
+    search.search("ch");
+    assert_eq!(search.get_current(), Some(3));
+    search.search("BR");
+    assert_eq!(search.get_current(), Some(2));
+    search.search("x");
+    assert_eq!(search.get_current(), Some(2));
+    assert_eq!(listbox.len(), HOSTS.len());
+}
+
+#[test]
+fn listbox_search_filter() {
+    let entry = Entry::new(-1, -1, None, 10, 0);
+    let listbox = listbox(0);
+    let search = ListboxSearch::new(&entry, &listbox, SearchMode::Filter);
+    search.search("LT");
+    assert_eq!(data(&listbox), [4]);
+    assert_eq!(search.get_current(), Some(4));
+
+    search.search("a");
+    assert_eq!(data(&listbox), [1, 2, 3, 4]);
+    search.search("");
+    assert_eq!(data(&listbox), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn listbox_search_filter_current() {
+    let entry = Entry::new(-1, -1, None, 10, 0);
+    let listbox = listbox(0);
+    listbox.set_current(2);
+    let search = ListboxSearch::new(&entry, &listbox, SearchMode::Filter);
+    search.search("e");
+    assert_eq!(search.get_current(), Some(3));
+    search.search("h");
+    assert_eq!(search.get_current(), Some(3));
+}
+
+#[test]
+fn listbox_search_filter_selection() {
+    let entry = Entry::new(-1, -1, None, 10, 0);
+    let listbox = listbox(FLAG_MULTIPLE);
+    listbox.select_item(1, Set);
+    let search = ListboxSearch::new(&entry, &listbox, SearchMode::Filter);
+    search.search("ar");
+    listbox.select_item(3, Set);
+    assert_eq!(*listbox.get_selection(), [3]);
+    assert_eq!(*search.get_selection(), [1, 3]);
+
+    search.search("");
+    assert_eq!(*listbox.get_selection(), [1, 3]);
+}
+
+#[test]
+fn listbox_search_clear() {
+    let entry = Entry::new(-1, -1, Some("ch"), 10, 0);
+    let listbox = listbox(0);
+    let search = ListboxSearch::new(&entry, &listbox, SearchMode::Filter);
+    search.search("ch");
+    assert_eq!(listbox.len(), 2);
+    search.clear();
+    assert_eq!(entry.get_text(), "");
+    assert_eq!(listbox.len(), HOSTS.len());
+}
@@ -18,8 +18,8 @@
 //
 
 extern crate newt;
-use std::{i8,i32,isize};
-use std::{u8,u32,usize};
+use std::{i8,i16,i32,i64,isize};
+use std::{u8,u16,u32,u64,usize};
 use std::ptr;
 
 use newt::Component;
@@ -127,6 +127,100 @@ fn listbox_get_entry() {
     assert!(d == 10);
 }
 
+#[test]
+#[should_panic(expected = "Invalid index number")]
+fn listbox_get_entry_invalid() {
+    let listbox: Listbox = Listbox::new(-1, -1, 5, 0);
+    listbox.append_entry("entry 1", 5).unwrap();
+    listbox.get_entry(1);
+}
+
+#[test]
+fn listbox_len() {
+    let listbox: Listbox = Listbox::new(-1, -1, 5, 0);
+    assert!(listbox.is_empty());
+    listbox.extend(vec![("entry 1", 5), ("entry 2", 10)]).unwrap();
+    assert!(listbox.len() == 2);
+    assert!(!listbox.is_empty());
+}
+
+#[test]
+fn listbox_iter() {
+    let listbox: Listbox = Listbox::new(-1, -1, 5, 0);
+    listbox.extend((1..4).map(|i| (format!("entry {}", i), i))).unwrap();
+    let entries: Vec<(String, isize)> = listbox.iter().collect();
+    assert!(entries.len() == 3);
+    assert!(entries[0] == ("entry 1".to_string(), 1));
+    assert!(entries[2] == ("entry 3".to_string(), 3));
+    assert!((&listbox).into_iter().len() == 3);
+}
+
+#[test]
+fn listbox_position() {
+    let listbox: Listbox = Listbox::new(-1, -1, 5, 0);
+    listbox.extend(vec![("entry 1", 5), ("entry 2", 10)]).unwrap();
+    assert!(listbox.position(10) == Some(1));
+    assert!(listbox.position(15) == None);
+}
+
+#[test]
+fn listbox_sort_by() {
+    let listbox: Listbox = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
+    listbox.extend(vec![("b", 2), ("c", 3), ("a", 1)]).unwrap();
+    listbox.set_current(1);
+    listbox.select_item(2, Set);
+    listbox.sort_by(|(t1, _), (t2, _)| t1.cmp(t2));
+
+    let data: Vec<isize> = listbox.iter().map(|(_, d)| d).collect();
+    assert!(data == [1, 2, 3]);
+    assert!(listbox.get_current() == Some(3));
+    assert!(*listbox.get_selection() == [2]);
+}
+
+#[test]
+fn listbox_retain() {
+    let listbox: Listbox = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
+    listbox.extend((1..6).map(|i| (format!("entry {}", i), i))).unwrap();
+    listbox.set_current(3);
+    listbox.select_items(&[1, 2, 4], Set);
+    listbox.retain(|_, d| d % 2 == 0);
+
+    let data: Vec<isize> = listbox.iter().map(|(_, d)| d).collect();
+    assert!(data == [2, 4]);
+    assert!(listbox.get_current() == Some(4));
+    assert!(*listbox.get_selection() == [2, 4]);
+}
+
+#[test]
+fn listbox_swap() {
+    let listbox: Listbox = Listbox::new(-1, -1, 5, 0);
+    listbox.extend(vec![("a", 1), ("b", 2), ("c", 3)]).unwrap();
+    listbox.swap(0, 2);
+    let data: Vec<isize> = listbox.iter().map(|(_, d)| d).collect();
+    assert!(data == [3, 2, 1]);
+    assert!(listbox.get_current() == Some(1));
+}
+
+#[test]
+fn listbox_move_item() {
+    let listbox: Listbox = Listbox::new(-1, -1, 5, 0);
+    listbox.extend(vec![("a", 1), ("b", 2), ("c", 3)]).unwrap();
+    listbox.move_item(0, 2);
+    let data: Vec<isize> = listbox.iter().map(|(_, d)| d).collect();
+    assert!(data == [2, 3, 1]);
+    listbox.move_item(2, 0);
+    let data: Vec<isize> = listbox.iter().map(|(_, d)| d).collect();
+    assert!(data == [1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid index number")]
+fn listbox_move_item_invalid() {
+    let listbox: Listbox = Listbox::new(-1, -1, 5, 0);
+    listbox.extend(vec![("a", 1), ("b", 2)]).unwrap();
+    listbox.move_item(0, 2);
+}
+
 #[test]
 fn listbox_set_entry() {
     let listbox: Listbox<()> = Listbox::new(-1, -1, 5, 0);
@@ -251,6 +345,19 @@ fn listbox_get_selection_i8() {
     assert!(*result == [i8::MAX, i8::MIN]);
 }
 
+#[test]
+fn listbox_get_selection_i16() {
+    let listbox: Listbox<i16> = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
+    listbox.append_entry("entry1", i16::MAX).unwrap();
+    listbox.append_entry("entry2", 0).unwrap();
+    listbox.append_entry("entry3", i16::MIN).unwrap();
+    listbox.select_item(i16::MAX, Set);
+    listbox.select_item(i16::MIN, Set);
+    let result = listbox.get_selection();
+    assert!(result.len() == 2);
+    assert!(*result == [i16::MAX, i16::MIN]);
+}
+
 #[test]
 fn listbox_get_selection_i32() {
     let listbox: Listbox<i32> = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
@@ -264,6 +371,19 @@ fn listbox_get_selection_i32() {
     assert!(*result == [i32::MAX, i32::MIN]);
 }
 
+#[test]
+fn listbox_get_selection_i64() {
+    let listbox: Listbox<i64> = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
+    listbox.append_entry("entry1", i64::MAX).unwrap();
+    listbox.append_entry("entry2", 0).unwrap();
+    listbox.append_entry("entry3", i64::MIN).unwrap();
+    listbox.select_item(i64::MAX, Set);
+    listbox.select_item(i64::MIN, Set);
+    let result = listbox.get_selection();
+    assert!(result.len() == 2);
+    assert!(*result == [i64::MAX, i64::MIN]);
+}
+
 #[test]
 fn listbox_get_selection_isize() {
     let listbox: Listbox = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
@@ -290,6 +410,19 @@ fn listbox_get_selection_u8() {
     assert!(*result == [u8::MAX, u8::MIN]);
 }
 
+#[test]
+fn listbox_get_selection_u16() {
+    let listbox: Listbox<u16> = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
+    listbox.append_entry("entry1", u16::MAX).unwrap();
+    listbox.append_entry("entry2", 0).unwrap();
+    listbox.append_entry("entry3", u16::MIN).unwrap();
+    listbox.select_item(u16::MAX, Set);
+    listbox.select_item(u16::MIN, Set);
+    let result = listbox.get_selection();
+    assert!(result.len() == 2);
+    assert!(*result == [u16::MAX, u16::MIN]);
+}
+
 #[test]
 fn listbox_get_selection_u32() {
     let listbox: Listbox<u32> = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
@@ -303,6 +436,19 @@ fn listbox_get_selection_u32() {
     assert!(*result == [u32::MAX, u32::MIN]);
 }
 
+#[test]
+fn listbox_get_selection_u64() {
+    let listbox: Listbox<u64> = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
+    listbox.append_entry("entry1", u64::MAX).unwrap();
+    listbox.append_entry("entry2", 0).unwrap();
+    listbox.append_entry("entry3", u64::MIN).unwrap();
+    listbox.select_item(u64::MAX, Set);
+    listbox.select_item(u64::MIN, Set);
+    let result = listbox.get_selection();
+    assert!(result.len() == 2);
+    assert!(*result == [u64::MAX, u64::MIN]);
+}
+
 #[test]
 fn listbox_get_selection_usize() {
     let listbox: Listbox<usize> = Listbox::new(-1, -1, 5, FLAG_MULTIPLE);
@@ -22,9 +22,30 @@
                target_arch = "riscv32", target_arch = "riscv64",
                target_arch = "x86",     target_arch = "x86_64")))]
 extern crate newt;
+use newt::Error;
 use newt::grid::*;
 use newt::prelude::*;
 
+struct Root;
+
+impl TreeSource for Root {
+    type Value = i32;
+
+    fn roots(&self) -> Vec<LazyNode<i32>> {
+        vec![LazyNode::new("Root", 1, false)]
+    }
+
+    fn children(&self, _parent: &i32) -> Vec<LazyNode<i32>> {
+        Vec::new()
+    }
+}
+
+fn destroy(component: &dyn Component) {
+    let mut form = Form::new(None, 0);
+    form.add_component(component).unwrap();
+    assert!(!component.is_destroyed());
+}
+
 #[test]
 #[should_panic]
 fn test_form_nullify() {
@@ -63,3 +84,294 @@ fn test_deep_grid_nullify() {
     }
     button.get_position();
 }
+
+#[test]
+fn test_button_destroyed() {
+    let button = Button::new(0, 0, "Ok");
+    destroy(&button);
+    assert!(button.is_destroyed());
+    assert_eq!(button.check().err(), Some(Error::Destroyed));
+    assert_eq!(button.try_get_position().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_checkbox_destroyed() {
+    let checkbox = Checkbox::new(0, 0, "Check", None, None);
+    destroy(&checkbox);
+    assert!(checkbox.is_destroyed());
+    assert_eq!(checkbox.check().err(), Some(Error::Destroyed));
+    assert_eq!(checkbox.try_get_value().err(), Some(Error::Destroyed));
+    assert_eq!(checkbox.try_set_value('*').err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_checkbox_tree_destroyed() {
+    let checkbox_tree: CheckboxTree<i32> = CheckboxTree::new(0, 0, 5, None, 0);
+    destroy(&checkbox_tree);
+    assert!(checkbox_tree.is_destroyed());
+    assert_eq!(checkbox_tree.check().err(), Some(Error::Destroyed));
+    assert_eq!(checkbox_tree.try_add_root("Root", 1).err(),
+               Some(Error::Destroyed));
+    assert_eq!(checkbox_tree.try_get_current().err(), Some(Error::Destroyed));
+    assert_eq!(checkbox_tree.try_get_selection().err(),
+               Some(Error::Destroyed));
+}
+
+#[test]
+fn test_compact_button_destroyed() {
+    let compact_button = CompactButton::new(0, 0, "Ok");
+    destroy(&compact_button);
+    assert!(compact_button.is_destroyed());
+    assert_eq!(compact_button.check().err(), Some(Error::Destroyed));
+    assert_eq!(compact_button.try_get_size().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_entry_destroyed() {
+    let entry = Entry::new(0, 0, None, 10, 0);
+    destroy(&entry);
+    assert!(entry.is_destroyed());
+    assert_eq!(entry.check().err(), Some(Error::Destroyed));
+    assert_eq!(entry.try_get_text().err(), Some(Error::Destroyed));
+    assert_eq!(entry.try_set_text("text", true).err(),
+               Some(Error::Destroyed));
+}
+
+#[test]
+fn test_label_destroyed() {
+    let label = Label::new(0, 0, "Label");
+    destroy(&label);
+    assert!(label.is_destroyed());
+    assert_eq!(label.check().err(), Some(Error::Destroyed));
+    assert_eq!(label.try_set_text("text").err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_listbox_destroyed() {
+    let listbox: Listbox<i32> = Listbox::new(0, 0, 5, 0);
+    destroy(&listbox);
+    assert!(listbox.is_destroyed());
+    assert_eq!(listbox.check().err(), Some(Error::Destroyed));
+    assert_eq!(listbox.try_append_entry("Item", 1).err(),
+               Some(Error::Destroyed));
+    assert_eq!(listbox.try_get_current().err(), Some(Error::Destroyed));
+    assert_eq!(listbox.try_clear().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_radiobutton_destroyed() {
+    let radiobutton = Radiobutton::new(0, 0, "Radio", true, None);
+    destroy(&radiobutton);
+    assert!(radiobutton.is_destroyed());
+    assert_eq!(radiobutton.check().err(), Some(Error::Destroyed));
+    assert_eq!(radiobutton.try_get_current().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_radiobutton_clone_destroyed() {
+    let radiobutton = Radiobutton::new(0, 0, "Radio", true, None);
+    let current = radiobutton.get_current().unwrap();
+    assert!(!current.is_destroyed());
+    destroy(&radiobutton);
+    assert!(current.is_destroyed());
+    assert_eq!(current.check().err(), Some(Error::Destroyed));
+    assert_eq!(current.try_get_current().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_form_current_destroyed() {
+    let button = Button::new(0, 0, "Ok");
+    let current = {
+        let mut form = Form::new(None, 0);
+        form.add_component(&button).unwrap();
+        let current = form.get_current().unwrap();
+        assert!(!current.is_destroyed());
+        current
+    };
+    assert!(current.is_destroyed());
+}
+
+#[test]
+fn test_scale_destroyed() {
+    let scale = Scale::new(0, 0, 10, 100);
+    destroy(&scale);
+    assert!(scale.is_destroyed());
+    assert_eq!(scale.check().err(), Some(Error::Destroyed));
+    assert_eq!(scale.try_set(50).err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_textbox_destroyed() {
+    let textbox = Textbox::new(0, 0, 10, 5, 0);
+    destroy(&textbox);
+    assert!(textbox.is_destroyed());
+    assert_eq!(textbox.check().err(), Some(Error::Destroyed));
+    assert_eq!(textbox.try_set_text("text").err(), Some(Error::Destroyed));
+    assert_eq!(textbox.try_get_num_lines().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_vertical_scrollbar_destroyed() {
+    let vertical_scrollbar = VerticalScrollbar::new(0, 0, 5, COLORSET_WINDOW,
+                                                   COLORSET_ACTCHECKBOX);
+    destroy(&vertical_scrollbar);
+    assert!(vertical_scrollbar.is_destroyed());
+    assert_eq!(vertical_scrollbar.check().err(), Some(Error::Destroyed));
+    assert_eq!(vertical_scrollbar.try_set(1, 5).err(),
+               Some(Error::Destroyed));
+}
+
+#[test]
+fn test_grid_destroyed() {
+    let button = Button::new(0, 0, "Ok");
+    let grid = HorizontalGrid::new(&[&button]);
+    destroy(&grid);
+    assert!(grid.is_destroyed());
+    assert_eq!(grid.check().err(), Some(Error::Destroyed));
+    assert_eq!(grid.try_place(1, 1).err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_composite_destroyed() {
+    let columns = [Column::new("Name", 8, Align::Left)];
+    let table: Table<i32> = Table::new(0, 0, 5, &columns, 0);
+    destroy(&table);
+    assert!(table.is_destroyed());
+    assert_eq!(table.check().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_owned_listbox_destroyed() {
+    let listbox: OwnedListbox<String> = OwnedListbox::new(0, 0, 5, 0);
+    destroy(&listbox);
+    assert!(listbox.is_destroyed());
+    assert_eq!(listbox.try_append_entry("Item", "item".into()).err(),
+               Some(Error::Destroyed));
+    assert_eq!(listbox.try_get_current().err(), Some(Error::Destroyed));
+    assert_eq!(listbox.try_delete_entry(0).err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_owned_checkbox_tree_destroyed() {
+    let tree: OwnedCheckboxTree<String> =
+        OwnedCheckboxTree::new(0, 0, 5, None, 0);
+    destroy(&tree);
+    assert!(tree.is_destroyed());
+    assert_eq!(tree.try_add_item("Item", "item".into(), 0, None).err(),
+               Some(Error::Destroyed));
+    assert_eq!(tree.try_get_selection().err(), Some(Error::Destroyed));
+    assert_eq!(tree.try_set_current(&"item".into()).err(),
+               Some(Error::Destroyed));
+}
+
+#[test]
+fn test_table_destroyed() {
+    let columns = [Column::new("Name", 8, Align::Left)];
+    let table: Table<i32> = Table::new(0, 0, 5, &columns, 0);
+    destroy(&table);
+    assert_eq!(table.try_append_row(&["Name"], 1).err(),
+               Some(Error::Destroyed));
+    assert_eq!(table.try_get_current().err(), Some(Error::Destroyed));
+    assert_eq!(table.try_sort_by_column(0, true).err(),
+               Some(Error::Destroyed));
+}
+
+#[test]
+fn test_virtual_listbox_destroyed() {
+    let rows = vec!["one", "two", "three"];
+    let list = VirtualListbox::new(0, 0, 10, 2, rows, 0);
+    destroy(&list);
+    assert_eq!(list.try_get_current().err(), Some(Error::Destroyed));
+    assert_eq!(list.try_set_current(1).err(), Some(Error::Destroyed));
+    assert_eq!(list.try_refresh().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_sectioned_listbox_destroyed() {
+    let listbox: SectionedListbox = SectionedListbox::new(0, 0, 5, 0);
+    destroy(&listbox);
+    assert!(listbox.is_destroyed());
+    assert_eq!(listbox.try_add_header("Header").err(),
+               Some(Error::Destroyed));
+    assert_eq!(listbox.try_get_current().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_sectioned_checkbox_tree_destroyed() {
+    let tree: SectionedCheckboxTree =
+        SectionedCheckboxTree::new(0, 0, 5, None, 0);
+    destroy(&tree);
+    assert!(tree.is_destroyed());
+    assert_eq!(tree.try_add_header("Header", None).err(),
+               Some(Error::Destroyed));
+    assert_eq!(tree.try_get_selection().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_lazy_tree_destroyed() {
+    let tree = LazyTree::new(0, 0, 5, Root, 0);
+    destroy(tree.tree());
+    assert_eq!(tree.try_get_current().err(), Some(Error::Destroyed));
+    assert_eq!(tree.try_expand(&1).err(), Some(Error::Destroyed));
+    assert_eq!(tree.try_refresh().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_listbox_search_destroyed() {
+    let entry = Entry::new(0, 0, None, 10, 0);
+    let listbox: Listbox = Listbox::new(0, 1, 5, 0);
+    let search = ListboxSearch::new(&entry, &listbox, SearchMode::Filter);
+    destroy(&listbox);
+    assert_eq!(search.try_search("text").err(), Some(Error::Destroyed));
+    assert_eq!(search.try_get_selection().err(), Some(Error::Destroyed));
+}
+
+#[test]
+fn test_radiobutton_set_destroyed() {
+    let mut radiobuttons = RadiobuttonSet::new();
+    radiobuttons.add_radiobutton(0, 0, "One");
+    radiobuttons.add_radiobutton(0, 1, "Two");
+    {
+        let mut form = Form::new(None, 0);
+        radiobuttons.add_to_form(&mut form).unwrap();
+    }
+    assert_eq!(radiobuttons.try_get_current().err(), Some(Error::Destroyed));
+    assert_eq!(radiobuttons.try_set_current(1).err(), Some(Error::Destroyed));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_date_entry_destroyed() {
+    use chrono::NaiveDate;
+
+    let date = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+    let date_entry = DateEntry::new(0, 0, date, 0);
+    destroy(date_entry.entry());
+    assert_eq!(date_entry.try_get_date().err(), Some(Error::Destroyed));
+    assert_eq!(date_entry.try_set_date(date).err(), Some(Error::Destroyed));
+}
+
+#[test]
+#[should_panic(expected = "Component has already been destroyed")]
+fn test_entry_destroyed_panics() {
+    let entry = Entry::new(0, 0, None, 10, 0);
+    destroy(&entry);
+    entry.get_text();
+}
+
+#[test]
+#[should_panic(expected = "Component has already been destroyed")]
+fn test_listbox_destroyed_panics() {
+    let listbox: Listbox<i32> = Listbox::new(0, 0, 5, 0);
+    destroy(&listbox);
+    listbox.append_entry("Item", 1).ok();
+}
+
+#[test]
+#[should_panic(expected = "Component has already been destroyed")]
+fn test_grid_destroyed_panics() {
+    let button = Button::new(0, 0, "Ok");
+    let grid = HorizontalGrid::new(&[&button]);
+    destroy(&grid);
+    grid.place(1, 1);
+}
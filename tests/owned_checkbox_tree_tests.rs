@@ -0,0 +1,81 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+use std::ptr;
+
+use newt::Component;
+use newt::widgets::OwnedCheckboxTree;
+use newt::constants::ARG_APPEND;
+
+fn tree() -> OwnedCheckboxTree<String> {
+    let tree = OwnedCheckboxTree::new(-1, -1, 10, None, 0);
+    tree.add_item("Editors", String::from("editors"), 0, Some(&[0]));
+    tree.add_item("vim", String::from("vim"), 0, Some(&[0, ARG_APPEND]));
+    tree.add_item("emacs", String::from("emacs"), 0, Some(&[0, ARG_APPEND]));
+    tree
+}
+
+#[test]
+fn owned_checkbox_tree_create() {
+    let tree: OwnedCheckboxTree<String> =
+        OwnedCheckboxTree::new(-1, -1, 10, None, 0);
+    assert!(tree.co() != ptr::null_mut());
+}
+
+#[test]
+fn owned_checkbox_tree_get_current() {
+    let tree = tree();
+    assert_eq!(tree.get_current(), Some(String::from("editors")));
+    tree.set_current(&String::from("emacs"));
+    assert_eq!(tree.get_current(), Some(String::from("emacs")));
+}
+
+#[test]
+fn owned_checkbox_tree_get_current_no_entries() {
+    let tree: OwnedCheckboxTree<String> =
+        OwnedCheckboxTree::new(-1, -1, 10, None, 0);
+    assert_eq!(tree.get_current(), None);
+}
+
+#[test]
+fn owned_checkbox_tree_find_item() {
+    let tree = tree();
+    assert_eq!(&*tree.find_item(&String::from("emacs")), &[0, 1]);
+    assert!(tree.find_item(&String::from("nano")).is_empty());
+}
+
+#[test]
+fn owned_checkbox_tree_entry_value() {
+    let tree = tree();
+    let vim = String::from("vim");
+    assert_eq!(tree.get_entry_value(&vim), Some(' '));
+    tree.set_entry_value(&vim, '*');
+    assert_eq!(tree.get_entry_value(&vim), Some('*'));
+    assert_eq!(tree.get_entry_value(&String::from("nano")), None);
+}
+
+#[test]
+fn owned_checkbox_tree_get_selection() {
+    let tree = tree();
+    tree.set_entry_value(&String::from("vim"), '*');
+    tree.set_entry_value(&String::from("emacs"), '*');
+    let selection = tree.get_selection();
+    assert_eq!(&*selection, &[String::from("vim"), String::from("emacs")]);
+}
@@ -0,0 +1,130 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+use std::path::PathBuf;
+use std::ptr;
+
+use newt::{Component,Error};
+use newt::widgets::OwnedListbox;
+use newt::constants::FLAG_MULTIPLE;
+use newt::constants::FlagsSense::Set;
+
+fn s(text: &str) -> String {
+    String::from(text)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Record {
+    name: String,
+    size: u64
+}
+
+#[test]
+fn owned_listbox_create() {
+    let listbox: OwnedListbox<String> = OwnedListbox::new(-1, -1, 5, 0);
+    assert!(listbox.co() != ptr::null_mut());
+}
+
+#[test]
+fn owned_listbox_append_entry() {
+    let listbox = OwnedListbox::new(-1, -1, 5, 0);
+    listbox.append_entry("passwd", PathBuf::from("/etc/passwd")).unwrap();
+    assert_eq!(listbox.item_count(), 1);
+    assert_eq!(listbox.get_current(), Some(PathBuf::from("/etc/passwd")));
+}
+
+#[test]
+fn owned_listbox_get_current_no_entries() {
+    let listbox: OwnedListbox<String> = OwnedListbox::new(-1, -1, 5, 0);
+    assert_eq!(listbox.get_current(), None);
+}
+
+#[test]
+fn owned_listbox_insert_entry() {
+    let listbox = OwnedListbox::new(-1, -1, 5, 0);
+    listbox.append_entry("entry 2", String::from("two")).unwrap();
+    listbox.insert_entry("entry 1", String::from("one"), 0).unwrap();
+    assert_eq!(listbox.get_entry(0), (s("entry 1"), s("one")));
+    assert_eq!(listbox.get_entry(1), (s("entry 2"), s("two")));
+    listbox.insert_entry("entry 3", String::from("three"), 2).unwrap();
+    assert_eq!(listbox.get_entry(2), (s("entry 3"), s("three")));
+    assert_eq!(listbox.insert_entry("entry 5", String::from("five"), 4),
+               Err(Error::InvalidItem));
+}
+
+#[test]
+fn owned_listbox_get_entry_struct() {
+    let listbox = OwnedListbox::new(-1, -1, 5, 0);
+    let record = Record { name: String::from("file"), size: 1024 };
+    listbox.append_entry("file", record.clone()).unwrap();
+    let (text, value) = listbox.get_entry(0);
+    assert_eq!(text, "file");
+    assert_eq!(value, record);
+}
+
+#[test]
+#[should_panic(expected = "Invalid index number")]
+fn owned_listbox_get_entry_invalid() {
+    let listbox: OwnedListbox<String> = OwnedListbox::new(-1, -1, 5, 0);
+    listbox.get_entry(0);
+}
+
+#[test]
+fn owned_listbox_set_value() {
+    let listbox = OwnedListbox::new(-1, -1, 5, 0);
+    listbox.append_entry("entry", String::from("old")).unwrap();
+    listbox.set_value(0, String::from("new"));
+    assert_eq!(listbox.get_current(), Some(String::from("new")));
+}
+
+#[test]
+fn owned_listbox_delete_entry() {
+    let listbox = OwnedListbox::new(-1, -1, 5, 0);
+    listbox.append_entry("entry 1", String::from("one")).unwrap();
+    listbox.append_entry("entry 2", String::from("two")).unwrap();
+    assert_eq!(listbox.delete_entry(0), Some(String::from("one")));
+    assert_eq!(listbox.item_count(), 1);
+    assert_eq!(listbox.get_current(), Some(String::from("two")));
+    assert_eq!(listbox.delete_entry(5), None);
+}
+
+#[test]
+fn owned_listbox_clear() {
+    let listbox = OwnedListbox::new(-1, -1, 5, 0);
+    listbox.append_entry("entry 1", String::from("one")).unwrap();
+    listbox.clear();
+    assert_eq!(listbox.item_count(), 0);
+    assert_eq!(listbox.get_current(), None);
+}
+
+#[test]
+fn owned_listbox_get_selection() {
+    let listbox = OwnedListbox::new(-1, -1, 5, FLAG_MULTIPLE);
+    listbox.append_entry("entry 1", String::from("one")).unwrap();
+    listbox.append_entry("entry 2", String::from("two")).unwrap();
+    listbox.append_entry("entry 3", String::from("three")).unwrap();
+    listbox.select_item(0, Set);
+    listbox.select_item(2, Set);
+    let selection = listbox.get_selection();
+    assert_eq!(&*selection, &[String::from("one"), String::from("three")]);
+
+    listbox.clear_selection();
+    assert!(listbox.get_selection().is_empty());
+}
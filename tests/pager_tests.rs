@@ -0,0 +1,60 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+extern crate tempfile;
+
+use std::fs;
+
+use newt::dialogs::Pager;
+
+#[test]
+fn pager_lines() {
+    let pager = Pager::new("Pager", "one\ntwo\r\nthree\n");
+    assert_eq!(pager.len(), 3);
+    assert!(!pager.is_empty());
+
+    let pager = Pager::new("Pager", "");
+    assert_eq!(pager.len(), 1);
+    assert!(pager.is_empty());
+}
+
+#[test]
+fn pager_find() {
+    let pager = Pager::new("Pager", "alpha\nBravo\ncharlie\nbravo\n");
+    assert_eq!(pager.find("bravo", 0, true), Some(1));
+    assert_eq!(pager.find("bravo", 2, true), Some(3));
+    assert_eq!(pager.find("bravo", 4, true), Some(1));
+    assert_eq!(pager.find("bravo", 0, false), Some(3));
+    assert_eq!(pager.find("bravo", 2, false), Some(1));
+    assert_eq!(pager.find("delta", 0, true), None);
+    assert_eq!(pager.find("", 0, true), None);
+}
+
+#[test]
+fn pager_open() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("log.txt");
+    fs::write(&path, "first\nsecond\n").unwrap();
+
+    let pager = Pager::open("Log", &path).unwrap();
+    assert_eq!(pager.len(), 2);
+    assert_eq!(pager.find("second", 0, true), Some(1));
+    assert!(Pager::open("Log", dir.path().join("missing")).is_err());
+}
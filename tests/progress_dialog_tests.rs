@@ -0,0 +1,66 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+
+use std::thread;
+use newt::dialogs::{CancelToken,ProgressDialog};
+
+#[test]
+fn progress_dialog_create() {
+    let dialog = ProgressDialog::new("Progress", "Starting");
+    assert_eq!(dialog.progress(), (0, 0));
+    assert_eq!(dialog.message(), "Starting");
+    assert!(!dialog.cancel_token().is_cancelled());
+}
+
+#[test]
+fn progress_dialog_reporter() {
+    let dialog = ProgressDialog::new("Progress", "Starting");
+    let reporter = dialog.reporter();
+    thread::spawn(move || {
+        for i in 0..=10 {
+            reporter.set(i, 10, &format!("Step {}", i));
+        }
+    }).join().unwrap();
+
+    assert_eq!(dialog.progress(), (10, 10));
+    assert_eq!(dialog.message(), "Step 10");
+}
+
+#[test]
+fn progress_dialog_cancel() {
+    let dialog = ProgressDialog::new("Progress", "Starting");
+    let reporter = dialog.reporter();
+    let token = reporter.cancel_token();
+    assert!(!reporter.is_cancelled());
+
+    dialog.cancel_token().cancel();
+    assert!(token.is_cancelled());
+    assert!(reporter.is_cancelled());
+}
+
+#[test]
+fn progress_cancel_token() {
+    let token = CancelToken::new();
+    let clone = token.clone();
+    assert!(!clone.is_cancelled());
+    token.cancel();
+    assert!(clone.is_cancelled());
+}
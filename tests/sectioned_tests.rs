@@ -0,0 +1,148 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+extern crate newt;
+use std::ptr;
+
+use newt::{Component,Error};
+use newt::constants::{CHECKBOXTREE_UNSELECTABLE,FLAG_MULTIPLE,FlagsSense};
+use newt::widgets::{SectionedCheckboxTree,SectionedListbox};
+
+fn listbox(flags: i32) -> SectionedListbox<i32> {
+    let listbox = SectionedListbox::new(0, 0, 10, flags);
+    listbox.add_header("Network").unwrap();
+    listbox.add_item("eth0", 1).unwrap();
+    listbox.add_item("wlan0", 2).unwrap();
+    listbox.add_separator().unwrap();
+    listbox.add_header("Storage").unwrap();
+    listbox.add_item("sda", 3).unwrap();
+    listbox
+}
+
+#[test]
+fn sectioned_listbox_create() {
+    let listbox: SectionedListbox = SectionedListbox::new(0, 0, 10, 0);
+    assert!(listbox.co() != ptr::null_mut());
+    assert_eq!(listbox.get_current(), None);
+}
+
+#[test]
+fn sectioned_listbox_headers() {
+    let listbox = listbox(0);
+    assert_eq!(listbox.item_count(), 6);
+    assert!(listbox.is_header(0));
+    assert!(!listbox.is_header(1));
+    assert!(listbox.is_header(3));
+    assert!(listbox.is_header(4));
+    assert!(!listbox.is_header(5));
+}
+
+#[test]
+fn sectioned_listbox_skip_leading_header() {
+    let listbox = listbox(0);
+    assert_eq!(listbox.get_current(), Some(1));
+}
+
+#[test]
+fn sectioned_listbox_skip_headers() {
+    let listbox = listbox(0);
+    listbox.set_current(3);
+    assert_eq!(listbox.get_current(), Some(3));
+    listbox.set_current(4);
+    assert_eq!(listbox.get_current(), Some(2));
+    listbox.set_current(0);
+    assert_eq!(listbox.get_current(), Some(1));
+}
+
+#[test]
+fn sectioned_listbox_set_current_by_key() {
+    let listbox = listbox(0);
+    listbox.set_current_by_key(3);
+    assert_eq!(listbox.get_current(), Some(3));
+}
+
+#[test]
+fn sectioned_listbox_selection() {
+    let listbox = listbox(FLAG_MULTIPLE);
+    listbox.select_item(1, FlagsSense::Set);
+    listbox.select_item(3, FlagsSense::Set);
+    assert_eq!(&*listbox.get_selection(), &[1, 3]);
+    listbox.clear_selection();
+    assert!(listbox.get_selection().is_empty());
+}
+
+#[test]
+fn sectioned_listbox_clear() {
+    let listbox = listbox(0);
+    listbox.clear();
+    assert_eq!(listbox.item_count(), 0);
+    assert_eq!(listbox.get_current(), None);
+}
+
+fn tree() -> SectionedCheckboxTree<i32> {
+    let tree = SectionedCheckboxTree::new(0, 0, 10, None, 0);
+    tree.add_header("Network", None).unwrap();
+    tree.add_item("sshd", 1, 0, None).unwrap();
+    tree.add_separator(None).unwrap();
+    tree.add_item("Storage", 0, CHECKBOXTREE_UNSELECTABLE, None).unwrap();
+    tree.add_item("nfsd", 2, 0, None).unwrap();
+    tree
+}
+
+#[test]
+fn sectioned_checkbox_tree_create() {
+    let tree: SectionedCheckboxTree =
+        SectionedCheckboxTree::new(0, 0, 10, None, 0);
+    assert!(tree.co() != ptr::null_mut());
+}
+
+#[test]
+fn sectioned_checkbox_tree_current() {
+    let tree = tree();
+    assert_eq!(tree.get_current(), None);
+    tree.set_current(2);
+    assert_eq!(tree.get_current(), Some(2));
+}
+
+#[test]
+fn sectioned_checkbox_tree_selection() {
+    let tree = tree();
+    tree.set_entry_value(1, '*');
+    tree.set_entry_value(2, '*');
+    assert_eq!(&*tree.get_selection(), &[1, 2]);
+    assert_eq!(&*tree.get_multi_selection('*'), &[1, 2]);
+    assert_eq!(tree.get_entry_value(2), Some('*'));
+}
+
+#[test]
+fn sectioned_checkbox_tree_invalid_index() {
+    let tree = tree();
+    assert_eq!(tree.add_item("ftpd", 3, 0, Some(&[9, 0])),
+               Err(Error::InvalidItem));
+    assert_eq!(tree.add_header("Other", Some(&[9, 0])),
+               Err(Error::InvalidItem));
+    assert!(tree.find_item(3).is_empty());
+}
+
+#[test]
+fn sectioned_checkbox_tree_find_item() {
+    let tree = tree();
+    assert_eq!(&*tree.find_item(2), &[4]);
+    assert!(tree.find_item(0).is_empty());
+    assert_eq!(tree.get_entry_value(0), None);
+}
@@ -0,0 +1,128 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+use newt::Error;
+use newt::widgets::{Align,Column,Table};
+use newt::constants::{FLAG_MULTIPLE,KEY_F2,KEY_F3};
+use newt::constants::FlagsSense::Set;
+use newt::form::ExitReason;
+
+fn table(flags: i32) -> Table<usize> {
+    let columns = [Column::new("Name", 8, Align::Left),
+                   Column::new("Status", 6, Align::Center),
+                   Column::new("Size", 5, Align::Right)];
+    let table = Table::new(-1, -1, 5, &columns, flags);
+    table.append_row(&["slang", "ok", "2048"], 1).unwrap();
+    table.append_row(&["newt", "failed", "152"], 2).unwrap();
+    table.append_row(&["libnewt-dev", "ok", "9"], 3).unwrap();
+    table
+}
+
+fn text(table: &Table<usize>, num: i32) -> String {
+    table.listbox().get_entry(num).0
+}
+
+#[test]
+fn table_create() {
+    let table = table(0);
+    assert_eq!(table.len(), 3);
+    assert_eq!(table.columns().len(), 3);
+    assert_eq!(text(&table, 0), "slang      ok    2048");
+    assert_eq!(text(&table, 2), "libnewt\u{2026}   ok       9");
+    assert_eq!(table.get_row(1), (vec!["newt".to_string(),
+                                       "failed".to_string(),
+                                       "152".to_string()], 2));
+}
+
+#[test]
+fn table_append_row_invalid() {
+    let table = table(0);
+    assert_eq!(table.append_row(&["newt"], 4), Err(Error::CellCount));
+    assert_eq!(table.len(), 3);
+}
+
+#[test]
+fn table_sort_by_column() {
+    let table = table(FLAG_MULTIPLE);
+    table.listbox().select_item(2, Set);
+    table.set_current(2);
+    table.sort_by_column(2, true);
+    let data: Vec<usize> = table.listbox().iter().map(|(_, d)| d).collect();
+    assert_eq!(data, [3, 2, 1]);
+    assert_eq!(table.get_current(), Some(3));
+    assert_eq!(*table.get_selection(), [2]);
+
+    table.sort_by_column(0, false);
+    let data: Vec<usize> = table.listbox().iter().map(|(_, d)| d).collect();
+    assert_eq!(data, [1, 2, 3]);
+    assert_eq!(table.sort_column(), Some((0, false)));
+
+    table.append_row(&["zlib", "ok", "80"], 4).unwrap();
+    assert_eq!(table.get_row(0).1, 4);
+}
+
+#[test]
+fn table_handle_hot_key() {
+    let table = table(0);
+    table.set_sort_key(0, KEY_F2);
+    table.set_sort_key(2, KEY_F3);
+    assert!(table.handle_hot_key(&ExitReason::HotKey(KEY_F2)));
+    assert_eq!(table.sort_column(), Some((0, true)));
+    assert!(table.handle_hot_key(&ExitReason::HotKey(KEY_F2)));
+    assert_eq!(table.sort_column(), Some((0, false)));
+    assert!(table.handle_hot_key(&ExitReason::HotKey(KEY_F3)));
+    assert_eq!(table.sort_column(), Some((2, true)));
+    assert!(!table.handle_hot_key(&ExitReason::Timer));
+}
+
+#[test]
+#[should_panic(expected = "Invalid column index")]
+fn table_sort_by_invalid_column() {
+    let table = table(0);
+    table.sort_by_column(3, true);
+}
+
+#[test]
+fn table_set_width() {
+    let table = table(0);
+    table.set_width(24);
+    assert_eq!(text(&table, 0), "slang      ok       2048");
+    table.set_width(18);
+    assert_eq!(text(&table, 0), "slang      ok   2\u{2026}");
+}
+
+#[test]
+fn table_clear() {
+    let table = table(0);
+    table.clear();
+    assert!(table.is_empty());
+    assert!(table.listbox().is_empty());
+}
+
+#[test]
+#[cfg(feature = "asm")]
+fn table_add_to_form() {
+    use newt::form::Form;
+
+    let table = table(0);
+    let mut form = Form::new(None, 0);
+    form.add_component(&table).unwrap();
+    assert!(form.add_component(table.listbox()).is_err());
+}
@@ -0,0 +1,90 @@
+//
+// Copyright (C) 2026 Robert Gill <rtgill82@gmail.com>
+//
+// This file is a part of newt-rs.
+//
+// This library is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License version 2.1 as published by the Free Software Foundation.
+//
+// This library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public
+// License along with this library; if not, write to the Free Software
+// Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301  USA
+//
+
+extern crate newt;
+use newt::widgets::{ListSource,VirtualListbox};
+
+struct Numbers(usize);
+
+impl ListSource for Numbers {
+    fn len(&self) -> usize {
+        self.0
+    }
+
+    fn row(&self, index: usize) -> String {
+        format!("Row {}", index)
+    }
+}
+
+#[test]
+fn virtual_listbox_create() {
+    let list = VirtualListbox::new(-1, -1, 10, 5, Numbers(100_000), 0);
+    assert_eq!(list.len(), 100_000);
+    assert_eq!(list.listbox().len(), 15);
+    assert_eq!(list.get_current(), Some(0));
+}
+
+#[test]
+fn virtual_listbox_small_source() {
+    let rows = vec!["one", "two", "three"];
+    let list = VirtualListbox::new(-1, -1, 10, 5, rows, 0);
+    assert_eq!(list.listbox().len(), 3);
+    list.set_current(10);
+    assert_eq!(list.get_current(), Some(2));
+}
+
+#[test]
+fn virtual_listbox_empty() {
+    let list = VirtualListbox::new(-1, -1, 10, 5, Numbers(0), 0);
+    assert!(list.is_empty());
+    assert_eq!(list.get_current(), None);
+}
+
+#[test]
+fn virtual_listbox_scroll() {
+    let list = VirtualListbox::new(-1, -1, 10, 5, Numbers(1000), 0);
+    list.listbox().set_current(12);
+    assert_eq!(list.get_current(), Some(12));
+    let (text, _) = list.listbox().get_entry(0);
+    assert_eq!(text, "Row 5");
+    assert_eq!(list.listbox().len(), 15);
+}
+
+#[test]
+fn virtual_listbox_set_current() {
+    let list = VirtualListbox::new(-1, -1, 10, 5, Numbers(1000), 0);
+    list.set_current(500);
+    assert_eq!(list.get_current(), Some(500));
+    let (text, key) = list.listbox().get_entry(0);
+    assert_eq!(text, "Row 493");
+    assert_eq!(key, 494);
+
+    list.set_current(999);
+    assert_eq!(list.get_current(), Some(999));
+    assert_eq!(list.listbox().get_entry(14).0, "Row 999");
+}
+
+#[test]
+fn virtual_listbox_set_source() {
+    let list = VirtualListbox::new(-1, -1, 10, 5, Numbers(1000), 0);
+    list.set_current(500);
+    list.set_source(Numbers(100));
+    assert_eq!(list.get_current(), Some(99));
+    assert_eq!(list.source().len(), 100);
+}